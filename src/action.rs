@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crossterm::event::{KeyCode, KeyModifiers};
 
 use crate::app::ActivePane;
@@ -12,13 +14,20 @@ pub enum Action {
     Quit,
     FocusNext,
     FocusPrev,
+    FocusFeeds,
+    FocusArticles,
+    FocusArticleView,
     MoveUp,
     MoveDown,
     Select,
     ToggleRead,
     ToggleStar,
+    ToggleQueue,
     OpenInBrowser,
+    OpenFeedSite,
+    ToggleTimeFormat,
     MarkAllRead,
+    MarkReadToHere,
     ScrollUp,
     ScrollDown,
     ScrollHalfPageUp,
@@ -38,11 +47,88 @@ pub enum Action {
     Cut,
     Paste,
     Edit,
+    NextUnread,
+    CopyEnclosure,
+    OpenImage,
+    FetchFullArticle,
+    CommandPrompt,
+    GrowFeedsPane,
+    ShrinkFeedsPane,
+    ToggleZen,
+    MoveItemUp,
+    MoveItemDown,
+    MoveToGroup,
     Digit(u8),  // 0-9 for vim-style count prefix
+    PendingKey(char),  // Part of a multi-key sequence such as `gg`
+}
+
+/// How long `App` waits for the next key of a multi-key sequence before
+/// treating the keys typed so far as a single key press.
+pub const KEY_SEQUENCE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Multi-key sequences, resolved by `App` from [`Action::PendingKey`]s.
+const KEY_SEQUENCES: &[(&str, Action)] = &[
+    ("gg", Action::JumpToTop),
+    ("ge", Action::JumpToBottom),
+];
+
+/// How far a run of pending keys gets through [`KEY_SEQUENCES`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceMatch {
+    /// The keys form a whole sequence.
+    Complete(Action),
+    /// The keys start at least one sequence.
+    Partial,
+    /// No sequence starts with the keys.
+    Invalid,
+}
+
+/// Match pending keys against the known multi-key sequences.
+pub fn resolve_sequence(keys: &str) -> SequenceMatch {
+    if let Some(&(_, action)) = KEY_SEQUENCES.iter().find(|(seq, _)| *seq == keys) {
+        return SequenceMatch::Complete(action);
+    }
+    if KEY_SEQUENCES.iter().any(|(seq, _)| seq.starts_with(keys)) {
+        SequenceMatch::Partial
+    } else {
+        SequenceMatch::Invalid
+    }
+}
+
+/// The action for `keys` typed as a single key press, once the sequence they
+/// started is abandoned; `None` unless `keys` is one character.
+pub fn lone_key_action(
+    keys: &str,
+    active_pane: ActivePane,
+    keybindings: &config::KeyBindings,
+) -> Option<Action> {
+    let mut chars = keys.chars();
+    let (Some(c), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+    handle_key(KeyCode::Char(c), KeyModifiers::NONE, active_pane, keybindings)
+}
+
+/// Actions for pending `keys` that `next` doesn't continue: the lone-key
+/// action of `keys`, then whatever `next` does by itself (which may start a
+/// new sequence).
+pub fn abandon_sequence(
+    keys: &str,
+    next: char,
+    active_pane: ActivePane,
+    keybindings: &config::KeyBindings,
+) -> Vec<Action> {
+    let next_action = if resolve_sequence(&next.to_string()) == SequenceMatch::Invalid {
+        handle_key(KeyCode::Char(next), KeyModifiers::NONE, active_pane, keybindings)
+    } else {
+        Some(Action::PendingKey(next))
+    };
+    lone_key_action(keys, active_pane, keybindings).into_iter().chain(next_action).collect()
 }
 
 /// Map a raw terminal [`Event`] to an application [`Action`], considering which
-/// pane is currently active and the configured keybindings.
+/// pane is currently active, the configured keybindings and any keys already
+/// typed towards a multi-key sequence.
 ///
 /// Returns `None` for events that have no associated action (e.g. mouse events,
 /// ticks, or unmapped keys).
@@ -50,13 +136,30 @@ pub fn handle_event(
     event: &Event,
     active_pane: ActivePane,
     keybindings: &config::KeyBindings,
+    pending_keys: &str,
 ) -> Option<Action> {
     let Event::Key(key) = event else {
         return None;
     };
 
-    let code = key.code;
-    let mods = key.modifiers;
+    // Plain characters continue a pending sequence or start a new one
+    if let (KeyCode::Char(c), KeyModifiers::NONE) = (key.code, key.modifiers) {
+        let keys = format!("{pending_keys}{c}");
+        if !pending_keys.is_empty() || resolve_sequence(&keys) != SequenceMatch::Invalid {
+            return Some(Action::PendingKey(c));
+        }
+    }
+
+    handle_key(key.code, key.modifiers, active_pane, keybindings)
+}
+
+/// Map a single key press to an [`Action`], ignoring multi-key sequences.
+pub fn handle_key(
+    code: KeyCode,
+    mods: KeyModifiers,
+    active_pane: ActivePane,
+    keybindings: &config::KeyBindings,
+) -> Option<Action> {
 
     // ----- Global bindings (independent of pane) -----
 
@@ -72,35 +175,87 @@ pub fn handle_event(
     if config::matches_any(&keybindings.global.focus_prev, code, mods) {
         return Some(Action::FocusPrev);
     }
+    if config::matches_any(&keybindings.global.focus_feeds, code, mods) {
+        return Some(Action::FocusFeeds);
+    }
+    if config::matches_any(&keybindings.global.focus_articles, code, mods) {
+        return Some(Action::FocusArticles);
+    }
+    if config::matches_any(&keybindings.global.focus_article_view, code, mods) {
+        return Some(Action::FocusArticleView);
+    }
 
     // Refresh
-    if keybindings.global.refresh_current.matches(code, mods) {
+    if config::matches_any(&keybindings.global.refresh_current, code, mods) {
         return Some(Action::RefreshCurrent);
     }
-    if keybindings.global.refresh_all.matches(code, mods) {
+    if config::matches_any(&keybindings.global.refresh_all, code, mods) {
         return Some(Action::RefreshAll);
     }
 
     // Open in browser (all panes)
-    if keybindings.global.open_browser.matches(code, mods) {
+    if config::matches_any(&keybindings.global.open_browser, code, mods) {
         return Some(Action::OpenInBrowser);
     }
 
+    // Open the selected feed's website (all panes)
+    if config::matches_any(&keybindings.global.open_feed_site, code, mods) {
+        return Some(Action::OpenFeedSite);
+    }
+
+    // Switch between 12- and 24-hour times (all panes)
+    if config::matches_any(&keybindings.global.toggle_time_format, code, mods) {
+        return Some(Action::ToggleTimeFormat);
+    }
+
     // Jump to top / bottom (all panes)
-    if keybindings.global.jump_top.matches(code, mods) {
+    if config::matches_any(&keybindings.global.jump_top, code, mods) {
         return Some(Action::JumpToTop);
     }
-    if keybindings.global.jump_bottom.matches(code, mods) {
+    if config::matches_any(&keybindings.global.jump_bottom, code, mods) {
         return Some(Action::JumpToBottom);
     }
 
+    // Next unread article, across feeds (all panes)
+    if config::matches_any(&keybindings.global.next_unread, code, mods) {
+        return Some(Action::NextUnread);
+    }
+
+    // Copy enclosure URL (all panes)
+    if config::matches_any(&keybindings.global.copy_enclosure, code, mods) {
+        return Some(Action::CopyEnclosure);
+    }
+
+    // Open an image from the article (all panes)
+    if config::matches_any(&keybindings.global.open_image, code, mods) {
+        return Some(Action::OpenImage);
+    }
+
+    // Fetch the article's full text from its web page (all panes)
+    if config::matches_any(&keybindings.global.fetch_full_article, code, mods) {
+        return Some(Action::FetchFullArticle);
+    }
+
+    // Resize the feeds pane (all panes)
+    if config::matches_any(&keybindings.global.grow_feeds_pane, code, mods) {
+        return Some(Action::GrowFeedsPane);
+    }
+    if config::matches_any(&keybindings.global.shrink_feeds_pane, code, mods) {
+        return Some(Action::ShrinkFeedsPane);
+    }
+
+    // Command prompt (all panes)
+    if config::matches_any(&keybindings.global.command, code, mods) {
+        return Some(Action::CommandPrompt);
+    }
+
     // Create group (all panes)
-    if keybindings.global.create_group.matches(code, mods) {
+    if config::matches_any(&keybindings.global.create_group, code, mods) {
         return Some(Action::CreateGroup);
     }
 
     // Create feed (all panes)
-    if keybindings.global.create_feed.matches(code, mods) {
+    if config::matches_any(&keybindings.global.create_feed, code, mods) {
         return Some(Action::CreateFeed);
     }
 
@@ -165,7 +320,7 @@ fn handle_feeds_key(
     if config::matches_any(&kb.move_up, code, mods) {
         return Some(Action::MoveUp);
     }
-    if kb.select.matches(code, mods) {
+    if config::matches_any(&kb.select, code, mods) {
         return Some(Action::Select);
     }
 
@@ -174,7 +329,7 @@ fn handle_feeds_key(
         return Some(Action::ToggleCollapseRecursive);
     }
 
-     if kb.toggle_collapse.matches(code, mods) {
+     if config::matches_any(&kb.toggle_collapse, code, mods) {
          return Some(Action::ToggleCollapse);
      }
 
@@ -200,6 +355,16 @@ fn handle_feeds_key(
         return Some(Action::ScrollHalfPageUp);
     }
 
+    if config::matches_any(&kb.move_item_up, code, mods) {
+        return Some(Action::MoveItemUp);
+    }
+    if config::matches_any(&kb.move_item_down, code, mods) {
+        return Some(Action::MoveItemDown);
+    }
+    if config::matches_any(&kb.move_to_group, code, mods) {
+        return Some(Action::MoveToGroup);
+    }
+
     None
 }
 
@@ -217,18 +382,24 @@ fn handle_articles_key(
     if config::matches_any(&kb.move_up, code, mods) {
         return Some(Action::MoveUp);
     }
-    if kb.select.matches(code, mods) {
+    if config::matches_any(&kb.select, code, mods) {
         return Some(Action::Select);
     }
-    if kb.toggle_read.matches(code, mods) {
+    if config::matches_any(&kb.toggle_read, code, mods) {
         return Some(Action::ToggleRead);
     }
-    if kb.toggle_star.matches(code, mods) {
+    if config::matches_any(&kb.toggle_star, code, mods) {
         return Some(Action::ToggleStar);
     }
-    if kb.mark_all_read.matches(code, mods) {
+    if config::matches_any(&kb.toggle_queue, code, mods) {
+        return Some(Action::ToggleQueue);
+    }
+    if config::matches_any(&kb.mark_all_read, code, mods) {
         return Some(Action::MarkAllRead);
     }
+    if config::matches_any(&kb.mark_read_to_here, code, mods) {
+        return Some(Action::MarkReadToHere);
+    }
     if config::matches_any(&kb.scroll_half_page_down, code, mods) {
         return Some(Action::ScrollHalfPageDown);
     }
@@ -261,6 +432,9 @@ fn handle_article_view_key(
     if config::matches_any(&kb.scroll_half_page_up, code, mods) {
         return Some(Action::ScrollHalfPageUp);
     }
+    if config::matches_any(&kb.toggle_zen, code, mods) {
+        return Some(Action::ToggleZen);
+    }
 
     None
 }
@@ -289,7 +463,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
         assert_eq!(action, Some(Action::Quit));
     }
 
@@ -302,7 +476,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
         assert_eq!(action, Some(Action::Quit));
     }
 
@@ -315,7 +489,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
         assert_eq!(action, Some(Action::MoveDown));
     }
 
@@ -328,7 +502,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
         assert_eq!(action, Some(Action::ToggleCollapse));
     }
 
@@ -341,10 +515,23 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Articles, &kb);
+        let action = handle_event(&event, ActivePane::Articles, &kb, "");
         assert_eq!(action, Some(Action::ToggleRead));
     }
 
+    #[test]
+    fn articles_pane_mark_read_to_here_on_ctrl_r() {
+        let kb = KeyBindings::default();
+        let event = Event::Key(crossterm::event::KeyEvent {
+            code: KeyCode::Char('r'),
+            modifiers: KeyModifiers::CONTROL,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        let action = handle_event(&event, ActivePane::Articles, &kb, "");
+        assert_eq!(action, Some(Action::MarkReadToHere));
+    }
+
     #[test]
     fn article_view_scroll_down_on_j() {
         let kb = KeyBindings::default();
@@ -354,14 +541,14 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::ArticleView, &kb);
+        let action = handle_event(&event, ActivePane::ArticleView, &kb, "");
         assert_eq!(action, Some(Action::ScrollDown));
     }
 
     #[test]
     fn format_single_binding() {
         let kb = KeyBindings::default();
-        assert_eq!(kb.feeds.select.display(), "Enter");
+        assert_eq!(format_bindings(&kb.feeds.select), "Enter");
     }
 
     #[test]
@@ -379,10 +566,51 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        // `g` starts a sequence; on its own it falls back to its binding
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
+        assert_eq!(action, Some(Action::PendingKey('g')));
+        let action = handle_key(KeyCode::Char('g'), KeyModifiers::NONE, ActivePane::Feeds, &kb);
         assert_eq!(action, Some(Action::JumpToTop));
     }
 
+    #[test]
+    fn pending_sequence_captures_next_char() {
+        let kb = KeyBindings::default();
+        let event = Event::Key(crossterm::event::KeyEvent {
+            code: KeyCode::Char('e'),
+            modifiers: KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        // `e` expands groups on its own, but continues a pending `g`
+        assert_eq!(handle_event(&event, ActivePane::Feeds, &kb, ""), Some(Action::ExpandAllGroups));
+        assert_eq!(handle_event(&event, ActivePane::Feeds, &kb, "g"), Some(Action::PendingKey('e')));
+    }
+
+    #[test]
+    fn abandoned_sequence_keeps_the_next_key() {
+        let kb = KeyBindings::default();
+        // `g` then `j`: the lone `g` still runs, and `j` still moves down
+        assert_eq!(
+            abandon_sequence("g", 'j', ActivePane::Articles, &kb),
+            vec![Action::JumpToTop, Action::MoveDown]
+        );
+        // A key that starts a sequence of its own stays pending
+        assert_eq!(
+            abandon_sequence("x", 'g', ActivePane::Articles, &kb).last(),
+            Some(&Action::PendingKey('g'))
+        );
+    }
+
+    #[test]
+    fn resolve_sequence_matches() {
+        assert_eq!(resolve_sequence("g"), SequenceMatch::Partial);
+        assert_eq!(resolve_sequence("gg"), SequenceMatch::Complete(Action::JumpToTop));
+        assert_eq!(resolve_sequence("ge"), SequenceMatch::Complete(Action::JumpToBottom));
+        assert_eq!(resolve_sequence("gx"), SequenceMatch::Invalid);
+        assert_eq!(resolve_sequence("j"), SequenceMatch::Invalid);
+    }
+
     #[test]
     fn jump_to_bottom_on_g() {
         let kb = KeyBindings::default();
@@ -392,20 +620,14 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
         assert_eq!(action, Some(Action::JumpToBottom));
     }
 
     #[test]
     fn jump_works_in_articles_pane() {
         let kb = KeyBindings::default();
-        let event = Event::Key(crossterm::event::KeyEvent {
-            code: KeyCode::Char('g'),
-            modifiers: KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        let action = handle_event(&event, ActivePane::Articles, &kb);
+        let action = handle_key(KeyCode::Char('g'), KeyModifiers::NONE, ActivePane::Articles, &kb);
         assert_eq!(action, Some(Action::JumpToTop));
     }
 
@@ -418,7 +640,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::ArticleView, &kb);
+        let action = handle_event(&event, ActivePane::ArticleView, &kb, "");
         assert_eq!(action, Some(Action::JumpToBottom));
     }
 
@@ -431,10 +653,46 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Articles, &kb);
+        let action = handle_event(&event, ActivePane::Articles, &kb, "");
         assert_eq!(action, Some(Action::FocusPrev));
     }
 
+    #[test]
+    fn move_item_in_feeds_pane() {
+        let kb = KeyBindings::default();
+        let key = |code, modifiers| Event::Key(crossterm::event::KeyEvent {
+            code,
+            modifiers,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        let shift_k = key(KeyCode::Char('K'), KeyModifiers::SHIFT);
+        let alt_down = key(KeyCode::Down, KeyModifiers::ALT);
+        assert_eq!(handle_event(&shift_k, ActivePane::Feeds, &kb, ""), Some(Action::MoveItemUp));
+        assert_eq!(handle_event(&alt_down, ActivePane::Feeds, &kb, ""), Some(Action::MoveItemDown));
+        assert_eq!(handle_event(&shift_k, ActivePane::Articles, &kb, ""), None);
+    }
+
+    #[test]
+    fn move_to_group_on_m_in_feeds_pane() {
+        let kb = KeyBindings::default();
+        assert_eq!(handle_key(KeyCode::Char('m'), KeyModifiers::NONE, ActivePane::Feeds, &kb), Some(Action::MoveToGroup));
+        // "m" toggles read state in the articles pane
+        assert_ne!(handle_key(KeyCode::Char('m'), KeyModifiers::NONE, ActivePane::Articles, &kb), Some(Action::MoveToGroup));
+    }
+
+    #[test]
+    fn command_prompt_on_colon() {
+        let kb = KeyBindings::default();
+        let event = Event::Key(crossterm::event::KeyEvent {
+            code: KeyCode::Char(':'),
+            modifiers: KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(handle_event(&event, ActivePane::Articles, &kb, ""), Some(Action::CommandPrompt));
+    }
+
     #[test]
     fn create_group_on_ctrl_g() {
         let kb = KeyBindings::default();
@@ -444,7 +702,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
         assert_eq!(action, Some(Action::CreateGroup));
     }
 
@@ -457,10 +715,130 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
         assert_eq!(action, Some(Action::CreateFeed));
     }
 
+    #[test]
+    fn next_unread_on_n_in_any_pane() {
+        let kb = KeyBindings::default();
+        let event = Event::Key(crossterm::event::KeyEvent {
+            code: KeyCode::Char('n'),
+            modifiers: KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        for pane in [ActivePane::Feeds, ActivePane::Articles, ActivePane::ArticleView] {
+            assert_eq!(handle_event(&event, pane, &kb, ""), Some(Action::NextUnread));
+        }
+    }
+
+    #[test]
+    fn copy_enclosure_on_y() {
+        let kb = KeyBindings::default();
+        let event = Event::Key(crossterm::event::KeyEvent {
+            code: KeyCode::Char('y'),
+            modifiers: KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(handle_event(&event, ActivePane::Articles, &kb, ""), Some(Action::CopyEnclosure));
+    }
+
+    #[test]
+    fn open_image_on_i() {
+        let kb = KeyBindings::default();
+        let event = Event::Key(crossterm::event::KeyEvent {
+            code: KeyCode::Char('i'),
+            modifiers: KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(handle_event(&event, ActivePane::ArticleView, &kb, ""), Some(Action::OpenImage));
+    }
+
+    #[test]
+    fn focus_pane_on_alt_digit() {
+        let kb = KeyBindings::default();
+        assert_eq!(
+            handle_key(KeyCode::Char('3'), KeyModifiers::ALT, ActivePane::Feeds, &kb),
+            Some(Action::FocusArticleView)
+        );
+        assert_eq!(
+            handle_key(KeyCode::Char('1'), KeyModifiers::ALT, ActivePane::ArticleView, &kb),
+            Some(Action::FocusFeeds)
+        );
+        // Plain digits stay count prefixes
+        assert_eq!(
+            handle_key(KeyCode::Char('2'), KeyModifiers::NONE, ActivePane::Feeds, &kb),
+            Some(Action::Digit(2))
+        );
+    }
+
+    #[test]
+    fn open_feed_site_on_shift_o() {
+        let kb = KeyBindings::default();
+        assert_eq!(
+            handle_key(KeyCode::Char('O'), KeyModifiers::SHIFT, ActivePane::Feeds, &kb),
+            Some(Action::OpenFeedSite)
+        );
+        assert_eq!(
+            handle_key(KeyCode::Char('o'), KeyModifiers::NONE, ActivePane::Articles, &kb),
+            Some(Action::OpenInBrowser)
+        );
+    }
+
+    #[test]
+    fn fetch_full_article_on_shift_f() {
+        let kb = KeyBindings::default();
+        assert_eq!(
+            handle_key(KeyCode::Char('F'), KeyModifiers::SHIFT, ActivePane::Articles, &kb),
+            Some(Action::FetchFullArticle)
+        );
+    }
+
+    #[test]
+    fn resize_feeds_pane_on_alt_h_and_l() {
+        let kb = KeyBindings::default();
+        assert_eq!(
+            handle_key(KeyCode::Char('l'), KeyModifiers::ALT, ActivePane::ArticleView, &kb),
+            Some(Action::GrowFeedsPane)
+        );
+        assert_eq!(
+            handle_key(KeyCode::Char('h'), KeyModifiers::ALT, ActivePane::Feeds, &kb),
+            Some(Action::ShrinkFeedsPane)
+        );
+    }
+
+    #[test]
+    fn toggle_zen_on_z_in_article_view() {
+        let kb = KeyBindings::default();
+        assert_eq!(
+            handle_key(KeyCode::Char('z'), KeyModifiers::NONE, ActivePane::ArticleView, &kb),
+            Some(Action::ToggleZen)
+        );
+        assert_eq!(handle_key(KeyCode::Char('z'), KeyModifiers::NONE, ActivePane::Articles, &kb), None);
+    }
+
+    #[test]
+    fn toggle_queue_on_l_in_articles_pane() {
+        let kb = KeyBindings::default();
+        assert_eq!(
+            handle_key(KeyCode::Char('l'), KeyModifiers::NONE, ActivePane::Articles, &kb),
+            Some(Action::ToggleQueue)
+        );
+        assert_eq!(handle_key(KeyCode::Char('l'), KeyModifiers::NONE, ActivePane::Feeds, &kb), None);
+    }
+
+    #[test]
+    fn toggle_time_format_on_shift_t() {
+        let kb = KeyBindings::default();
+        assert_eq!(
+            handle_key(KeyCode::Char('T'), KeyModifiers::SHIFT, ActivePane::ArticleView, &kb),
+            Some(Action::ToggleTimeFormat)
+        );
+    }
+
     #[test]
     fn delete_on_shift_d_in_feeds_pane() {
         let kb = KeyBindings::default();
@@ -470,7 +848,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
         assert_eq!(action, Some(Action::Delete));
     }
 
@@ -483,7 +861,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
         assert_eq!(action, Some(Action::Delete));
     }
 
@@ -496,7 +874,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Articles, &kb);
+        let action = handle_event(&event, ActivePane::Articles, &kb, "");
         assert_ne!(action, Some(Action::Delete));
     }
 
@@ -509,7 +887,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
         assert_ne!(action, Some(Action::Delete));
     }
 
@@ -522,7 +900,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
         assert_eq!(action, Some(Action::Cut));
     }
 
@@ -535,7 +913,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Articles, &kb);
+        let action = handle_event(&event, ActivePane::Articles, &kb, "");
         assert_ne!(action, Some(Action::Cut));
     }
 
@@ -548,7 +926,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
         assert_eq!(action, Some(Action::Paste));
     }
 
@@ -561,7 +939,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Articles, &kb);
+        let action = handle_event(&event, ActivePane::Articles, &kb, "");
         assert_ne!(action, Some(Action::Paste));
     }
 
@@ -574,7 +952,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
         assert_eq!(action, Some(Action::Edit));
     }
 
@@ -587,7 +965,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Articles, &kb);
+        let action = handle_event(&event, ActivePane::Articles, &kb, "");
         assert_ne!(action, Some(Action::Edit));
     }
 
@@ -600,7 +978,7 @@ mod tests {
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::NONE,
         });
-        let action = handle_event(&event, ActivePane::Feeds, &kb);
+        let action = handle_event(&event, ActivePane::Feeds, &kb, "");
         assert_ne!(action, Some(Action::Edit));
     }
 }
@@ -1,14 +1,25 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::marker::PhantomData;
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
+use chrono::{DateTime, Utc};
 use ratatui::widgets::ListState;
+use ratatui_image::picker::Picker;
 use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 
-use crate::action::Action;
-use crate::config::{Config, FeedConfigItem, FeedGroup, FeedSource};
+use crate::action::{self, Action, SequenceMatch};
+use crate::command::{self, Command};
+use crate::config::{parse_mark_read_on, Config, FeedConfigItem, FeedGroup, FeedSource, MarkReadOn};
 use crate::db;
 use crate::db_async::AsyncDb;
 use crate::feed::{self, FeedUpdateResult};
+use crate::graphics::HeroImage;
+use crate::ui::popup::ConfirmAction;
+use crate::ui_state::SelectedSource;
 
 /// Convert human-friendly date format to strftime format.
 ///
@@ -25,30 +36,110 @@ use crate::feed::{self, FeedUpdateResult};
 /// - MMMM -> full month name -> %B
 /// - YY -> 2-digit year -> %y
 /// - YYYY -> 4-digit year -> %Y
+/// - H / h -> unpadded hour -> %-H / %-I
+/// - HH / hh -> zero-padded hour -> %H / %I
+/// - mm -> minutes -> %M
+/// - a -> AM/PM -> %p
+///
+/// `hours` (the `format.time` setting) is the single source of truth for the
+/// clock: on 24 every hour token gives a 24-hour hour and `a` is dropped;
+/// otherwise every hour token gives a 12-hour hour, and AM/PM is added after
+/// the time if the format has no `a`. The case of `H`/`h` only picks the
+/// padding, so toggling the clock changes formats that contain a time.
+///
+/// A word is only converted if it consists entirely of tokens, so literal
+/// text such as "at" in "D MMM at h:mm a" is kept as written.
 ///
 /// Returns the strftime format and whether the day should be non-zero-padded.
-pub fn to_strftime_format(format: &str) -> (String, bool) {
-    // Check if using "D" (single) which means non-zero-padded day
-    let has_single_d = format.contains("D") && !format.contains("DD");
-
-    let strftime_format = format
-        .replace("dddd", "%A")
-        .replace("ddd", "%a")
-        .replace("YYYY", "%Y")
-        .replace("YY", "%y")
-        .replace("MMMM", "%B")
-        .replace("MMM", "%b")
-        .replace("DD", "%d")
-        .replace("D", "%d");
-
-    (strftime_format, has_single_d)
+pub fn to_strftime_format(format: &str, hours: u8) -> (String, bool) {
+    let twelve_hour = hours != 24;
+    fn token(run: &str) -> Option<&'static str> {
+        Some(match run {
+            "dddd" => "%A",
+            "ddd" => "%a",
+            "YYYY" => "%Y",
+            "YY" => "%y",
+            "MMMM" => "%B",
+            "MMM" => "%b",
+            "DD" | "D" => "%d",
+            "HH" | "hh" => "%H",
+            "H" | "h" => "%-H",
+            "mm" => "%M",
+            "a" => "%p",
+            _ => return None,
+        })
+    }
+
+    let mut strftime_format = String::with_capacity(format.len() * 2);
+    let (mut single_d, mut double_d) = (false, false);
+    // Where the last hour or minute ends, and whether an `a` was seen
+    let (mut clock_end, mut has_am_pm) = (None, false);
+    let mut rest = format;
+    while let Some(c) = rest.chars().next() {
+        if !c.is_ascii_alphabetic() {
+            match c {
+                '%' => strftime_format.push_str("%%"),
+                c => strftime_format.push(c),
+            }
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+
+        // Split the word into runs of one repeated letter ("MMM", "YYYY")
+        let word_len = rest.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(rest.len());
+        let (word, tail) = rest.split_at(word_len);
+        let mut runs = Vec::new();
+        let mut run_rest = word;
+        while let Some(first) = run_rest.chars().next() {
+            let len = run_rest.find(|c| c != first).unwrap_or(run_rest.len());
+            runs.push(&run_rest[..len]);
+            run_rest = &run_rest[len..];
+        }
+
+        match runs.iter().map(|run| token(run)).collect::<Option<Vec<_>>>() {
+            Some(converted) => {
+                single_d |= runs.contains(&"D");
+                double_d |= runs.contains(&"DD");
+                for spec in converted {
+                    match spec {
+                        "%p" if !twelve_hour => {
+                            strftime_format.truncate(strftime_format.trim_end().len());
+                        }
+                        "%p" => {
+                            has_am_pm = true;
+                            strftime_format.push_str(spec);
+                        }
+                        "%H" | "%-H" if twelve_hour => strftime_format.push_str(&spec.replace('H', "I")),
+                        _ => strftime_format.push_str(spec),
+                    }
+                    if matches!(spec, "%H" | "%-H" | "%M") {
+                        clock_end = Some(strftime_format.len());
+                    }
+                }
+            }
+            None => strftime_format.push_str(word),
+        }
+        rest = tail;
+    }
+
+    if let Some(end) = clock_end.filter(|_| twelve_hour && !has_am_pm) {
+        strftime_format.insert_str(end, " %p");
+    }
+    (strftime_format, single_d && !double_d)
+}
+
+/// strftime format for a time of day on a 12- or 24-hour clock (the
+/// `format.time` setting); anything but 24 means a 12-hour clock, as in
+/// [`to_strftime_format`].
+pub fn time_strftime_format(hours: u8) -> &'static str {
+    if hours == 24 { "%H:%M" } else { "%I:%M %p" }
 }
 
 /// Remove leading zero from the day portion of a formatted date string.
 ///
 /// This handles cases where the format uses "D" (non-zero-padded day)
 /// but chrono only provides "%d" (zero-padded). We strip the leading zero
-/// from patterns like " 03" or leading "03".
+/// from patterns like " 03" or leading "03", but not from hours ("09:30").
 pub fn strip_day_leading_zero(formatted: &str) -> String {
     // Match " 0X" or leading "0X" where X is a digit, replace with " X" or "X"
     // We need to be careful to only match the day, not other parts of the string
@@ -62,7 +153,8 @@ pub fn strip_day_leading_zero(formatted: &str) -> String {
                 let mut temp = chars.clone();
                 temp.next();
                 if let Some(&next_char) = temp.peek() {
-                    if next_char.is_ascii_digit() {
+                    temp.next();
+                    if next_char.is_ascii_digit() && temp.peek() != Some(&':') {
                         // Skip the zero, keep the space
                         result.push(c);
                         chars.next(); // consume '0'
@@ -74,7 +166,9 @@ pub fn strip_day_leading_zero(formatted: &str) -> String {
         } else if c == '0' && result.is_empty() {
             // Check if this is a leading "0X" pattern
             if let Some(&next_char) = chars.peek() {
-                if next_char.is_ascii_digit() {
+                let mut temp = chars.clone();
+                temp.next();
+                if next_char.is_ascii_digit() && temp.peek() != Some(&':') {
                     // Skip the zero
                     continue;
                 }
@@ -88,6 +182,20 @@ pub fn strip_day_leading_zero(formatted: &str) -> String {
     result
 }
 
+/// Format `date` in local time with a strftime format from
+/// [`to_strftime_format`], dropping the day's leading zero if asked to.
+///
+/// Shared by the articles list (`format.date`) and the article view header
+/// (`format.date_detail`).
+pub fn format_local_date(date: DateTime<Utc>, strftime_format: &str, strip_day_zero: bool) -> String {
+    let formatted = date.with_timezone(&chrono::Local).format(strftime_format).to_string();
+    if strip_day_zero {
+        strip_day_leading_zero(&formatted)
+    } else {
+        formatted
+    }
+}
+
 /// Which pane currently has focus in the TUI.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ActivePane {
@@ -101,37 +209,303 @@ pub enum ActivePane {
 #[derive(Debug)]
 pub enum FeedListItem {
     /// Shows all articles from all feeds.
-    All { unread_count: u32 },
+    All { unread_count: u32, total_count: u32 },
+    /// Shows starred articles from all feeds.
+    Starred { count: u32 },
+    /// Shows the "read later" queue from all feeds.
+    Queue { count: u32 },
+    /// Shows articles from all feeds published in the last `hours` hours.
+    Recent { hours: u64 },
     /// A collapsible group header.
-    GroupHeader { title: String, full_path: String, collapsed: bool, unread_count: u32, depth: u8 },
+    GroupHeader { title: String, full_path: String, collapsed: bool, unread_count: u32, total_count: u32, depth: u8 },
     /// An individual feed.
     Feed { feed: db::Feed, depth: u8 },
 }
 
+/// Percentage points the feeds pane grows or shrinks by per key press.
+const FEEDS_PANE_RESIZE_STEP: i16 = 5;
+
+/// Longest an idle screen goes without being redrawn.
+const IDLE_REDRAW_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Status shown while a requested full article is being fetched.
+const FETCHING_FULL_ARTICLE: &str = "Fetching full article\u{2026}";
+
+/// Article body shown when a feed provides neither content nor a summary.
+fn no_content_body(url: Option<&str>, open_key: &str) -> String {
+    match url {
+        Some(url) if open_key.is_empty() => {
+            format!("This feed doesn't include the article text.\n\n{url}\n")
+        }
+        Some(url) => format!(
+            "This feed doesn't include the article text.\n\n{url}\n\nPress {open_key} to open in browser\n"
+        ),
+        None => "(No content available)\n".to_string(),
+    }
+}
+
+/// Where a feed lives, e.g. "Tech > Rust > Rust Blog", for the article
+/// header. Standalone feeds are just their title.
+fn feed_breadcrumb(feed: &db::Feed) -> String {
+    if feed.group_title.is_empty() {
+        feed.title.clone()
+    } else {
+        format!("{} > {}", feed.group_title, feed.title)
+    }
+}
+
+/// Index of the feeds-pane entry named `name` (a feed title, group title or
+/// path, or a built-in entry). Case-insensitive; an exact match wins over
+/// the first entry containing `name`.
+fn find_source(items: &[FeedListItem], name: &str) -> Option<usize> {
+    let needle = name.to_lowercase();
+    let names = |item: &FeedListItem| -> Vec<String> {
+        match item {
+            FeedListItem::Feed { feed, .. } => vec![feed.title.to_lowercase()],
+            FeedListItem::GroupHeader { title, full_path, .. } => {
+                vec![title.to_lowercase(), full_path.to_lowercase()]
+            }
+            _ => item.virtual_name().map(str::to_lowercase).into_iter().collect(),
+        }
+    };
+
+    items
+        .iter()
+        .position(|item| names(item).contains(&needle))
+        .or_else(|| items.iter().position(|item| names(item).iter().any(|n| n.contains(&needle))))
+}
+
+/// Largest scroll offset for `content_lines` of text in a view `visible_height`
+/// rows tall, so the last page stays full. Before the first render (height
+/// 0) the last line may scroll to the top.
+fn max_scroll(content_lines: u16, visible_height: u16) -> u16 {
+    content_lines.saturating_sub(visible_height.max(1))
+}
+
+/// Whether an article view scrolled to `scroll` has been read under the
+/// "scroll" policy: past halfway, at the end, or short enough that there
+/// is nothing to scroll.
+fn scrolled_to_read(scroll: u16, content_lines: u16, visible_height: u16) -> bool {
+    let max = max_scroll(content_lines, visible_height);
+    max == 0 || scroll >= max || scroll.saturating_mul(2) >= content_lines
+}
+
+/// Rows moved by a half-page scroll in a pane `height` rows tall; 10 before
+/// the pane has been rendered.
+fn half_page(height: u16) -> i32 {
+    if height == 0 {
+        10
+    } else {
+        (height / 2).max(1) as i32
+    }
+}
+
+/// Index reached by moving `delta` rows from `current` in a list of `len`
+/// (non-zero) rows, either wrapping around the ends or stopping at them.
+fn step_index(current: usize, delta: i32, len: usize, wrap: bool) -> usize {
+    let magnitude = delta.unsigned_abs() as usize;
+    match (wrap, delta >= 0) {
+        (true, true) => (current + magnitude % len) % len,
+        (true, false) => (current + len - magnitude % len) % len,
+        (false, true) => current.saturating_add(magnitude).min(len - 1),
+        (false, false) => current.saturating_sub(magnitude),
+    }
+}
+
+/// Load feeds and the starred and queued article counts as a `FeedsLoaded`
+/// result. Per-feed article totals are only counted when `with_totals` is set,
+/// and unread same-URL copies are only listed when `dedup` is.
+async fn load_feeds(db: &AsyncDb, with_totals: bool, dedup: bool) -> anyhow::Result<DbResult> {
+    let feeds = db.get_all_feeds(with_totals).await?;
+    let starred_count = db.count_starred().await?;
+    let queued_count = db.count_queued().await?;
+    let unread_duplicates = if dedup { db.unread_url_duplicates().await? } else { Vec::new() };
+    Ok(DbResult::FeedsLoaded { feeds, starred_count, queued_count, unread_duplicates })
+}
+
+/// How many unread articles in feeds matching `in_scope` a URL-deduplicated
+/// list hides: every copy of a URL in `duplicates` (see
+/// [`db::unread_url_duplicates`]) beyond the first.
+fn hidden_unread_duplicates(duplicates: &[(i64, String)], in_scope: impl Fn(i64) -> bool) -> u32 {
+    let mut urls = HashSet::new();
+    let mut copies = 0;
+    for (_, url) in duplicates.iter().filter(|(feed_id, _)| in_scope(*feed_id)) {
+        copies += 1;
+        urls.insert(url.as_str());
+    }
+    copies - urls.len() as u32
+}
+
+/// Report a failed background database operation, e.g. `action` "save the
+/// star", as a [`DbResult::Error`].
+fn send_db_error(tx: &UnboundedSender<DbResult>, action: &str, err: anyhow::Error) {
+    let _ = tx.send(DbResult::Error(format!("Couldn't {action}: {err:#}")));
+}
+
+/// Decrements [`App`]'s count of running database tasks when dropped.
+struct DbTaskGuard(Arc<AtomicUsize>);
+
+impl Drop for DbTaskGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Human-readable label for a feeds-pane entry.
+fn source_label(item: &FeedListItem) -> String {
+    match item {
+        FeedListItem::All { .. } => "All Feeds".to_string(),
+        FeedListItem::Starred { .. } => "Starred".to_string(),
+        FeedListItem::Queue { .. } => "Queue".to_string(),
+        FeedListItem::Recent { hours } => format!("Recent ({}h)", hours),
+        FeedListItem::GroupHeader { title, .. } => format!("{} (group)", title),
+        FeedListItem::Feed { feed, .. } => feed.title.clone(),
+    }
+}
+
+impl FeedListItem {
+    /// Name of a built-in entry ("All", "Starred", "Queue", "Recent") that isn't
+    /// backed by the config, or `None` for feeds and groups.
+    pub fn virtual_name(&self) -> Option<&'static str> {
+        match self {
+            FeedListItem::All { .. } => Some("All"),
+            FeedListItem::Starred { .. } => Some("Starred"),
+            FeedListItem::Queue { .. } => Some("Queue"),
+            FeedListItem::Recent { .. } => Some("Recent"),
+            FeedListItem::GroupHeader { .. } | FeedListItem::Feed { .. } => None,
+        }
+    }
+
+    /// Identifies the article list this entry shows, to tell a reload of
+    /// the same list from a switch to another.
+    fn source_key(&self) -> String {
+        match self {
+            FeedListItem::Feed { feed, .. } => format!("feed:{}", feed.id),
+            FeedListItem::GroupHeader { full_path, .. } => format!("group:{full_path}"),
+            item => item.virtual_name().unwrap_or_default().to_string(),
+        }
+    }
+}
+
 /// Result of an async database operation.
 #[derive(Debug)]
 pub enum DbResult {
     /// All feeds have been loaded.
-    FeedsLoaded(Vec<db::Feed>),
+    FeedsLoaded {
+        feeds: Vec<db::Feed>,
+        starred_count: u32,
+        queued_count: u32,
+        unread_duplicates: Vec<(i64, String)>,
+    },
     /// Articles for a feed have been loaded.
     ArticlesLoaded { feed_id: i64, articles: Vec<db::Article> },
     /// Articles for a group have been loaded.
     GroupArticlesLoaded { group_title: String, articles: Vec<db::Article> },
     /// All articles have been loaded.
     AllArticlesLoaded(Vec<db::Article>),
-    /// An article's read status was toggled.
-    ReadToggled { article_id: i64, new_value: bool },
-    /// An article's starred status was toggled.
-    StarToggled { article_id: i64, new_value: bool },
+    /// Starred articles from all feeds have been loaded.
+    StarredLoaded(Vec<db::Article>),
+    /// Queued articles from all feeds have been loaded.
+    QueueLoaded(Vec<db::Article>),
+    /// Recently published articles from all feeds have been loaded.
+    RecentLoaded(Vec<db::Article>),
+    /// An article's read status was set. `changed_feed_ids` holds the feed
+    /// of every article that changed, same-URL copies included.
+    ReadToggled { article_id: i64, new_value: bool, changed_feed_ids: Vec<i64> },
+    /// A feed's unread count was recounted after a read toggle.
+    UnreadCountUpdated { feed_id: i64, count: u32 },
+    /// Unread same-URL copies were listed again after a read toggle.
+    UnreadDuplicatesLoaded(Vec<(i64, String)>),
+    /// An article's starred status was set; see `ReadToggled`.
+    StarToggled { article_id: i64, new_value: bool, changed_feed_ids: Vec<i64> },
+    /// An article was added to or removed from the "read later" queue; see
+    /// `ReadToggled`.
+    QueueToggled { article_id: i64, new_value: bool, changed_feed_ids: Vec<i64> },
     /// Articles were marked as read.
     MarkedRead { feed_id: Option<i64> },
+    /// Fetched articles for a feed were stored; `inserted` counts new ones
+    /// (the newest titled `newest_title`) and `updated` stored ones that
+    /// changed.
+    ArticlesUpserted { feed_id: i64, inserted: usize, updated: usize, newest_title: Option<String> },
+    /// A background database operation failed; the message says which.
+    Error(String),
 }
 
-/// Result of async article content rendering.
+/// Result of a background network request other than a feed refresh.
 #[derive(Debug)]
+pub enum NetResult {
+    /// Feed autodiscovery for a new feed has finished.
+    FeedDiscovered {
+        title: String,
+        url: String,
+        parent_group: Option<String>,
+        result: Result<feed::Discovery, String>,
+    },
+    /// The hero image download for an article has finished; `None` when it
+    /// failed or the image can't be shown inline.
+    HeroImageLoaded { article_id: i64, image: Option<HeroImage> },
+    /// An article's hero image was downloaded but isn't in a format shown
+    /// inline (PNG, JPEG, GIF or WebP).
+    HeroImageUnsupported { article_id: i64 },
+    /// An article's web page has been fetched and its text extracted; on
+    /// success the text has already been stored.
+    FullContentFetched { article_id: i64, result: Result<String, String> },
+}
+
+/// Result of async article content rendering.
+#[derive(Debug, Clone)]
 pub struct RenderResult {
+    /// ID of the article that was rendered.
+    pub article_id: i64,
+    /// Render request this answers; `None` for a neighbour prefetch.
+    pub generation: Option<u64>,
+    /// Hash of the article fields the render was built from.
+    pub source_hash: u64,
     /// The rendered plain text content.
     pub content: String,
+    /// Line ranges of `content` that hold code blocks.
+    pub code_lines: Vec<Range<usize>>,
+    /// Images referenced by the article.
+    pub images: Vec<crate::render::ArticleImage>,
+}
+
+/// Recently rendered articles, so reopening one skips the HTML conversion.
+///
+/// Entries are keyed by article ID and carry the hash of the fields they
+/// were rendered from; a refresh that changes the article misses the cache.
+#[derive(Debug, Default)]
+struct RenderCache {
+    /// (article ID, source hash, render), least recently used first.
+    entries: VecDeque<(i64, u64, RenderResult)>,
+}
+
+impl RenderCache {
+    /// Most renders kept at once.
+    const CAPACITY: usize = 64;
+
+    /// The cached render of `article_id`, if it was built from `source_hash`.
+    /// Marks the entry as recently used.
+    fn get(&mut self, article_id: i64, source_hash: u64) -> Option<&RenderResult> {
+        let pos = self.entries.iter().position(|(id, hash, _)| *id == article_id && *hash == source_hash)?;
+        let entry = self.entries.remove(pos)?;
+        self.entries.push_back(entry);
+        self.entries.back().map(|(_, _, render)| render)
+    }
+
+    /// Whether an up-to-date render of `article_id` is cached.
+    fn contains(&self, article_id: i64, source_hash: u64) -> bool {
+        self.entries.iter().any(|(id, hash, _)| *id == article_id && *hash == source_hash)
+    }
+
+    /// Store a render, replacing any older one of the same article and
+    /// evicting the least recently used entry when full.
+    fn insert(&mut self, render: RenderResult) {
+        self.entries.retain(|(id, ..)| *id != render.article_id);
+        if self.entries.len() >= Self::CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((render.article_id, render.source_hash, render));
+    }
 }
 
 /// Clipboard item for cut/paste operations.
@@ -170,26 +544,73 @@ pub struct App {
     pub articles: Vec<db::Article>,
     /// Pre-rendered plain-text content of the selected article.
     pub article_content: String,
+    /// Line ranges of `article_content` that hold code blocks.
+    pub article_code_lines: Vec<Range<usize>>,
+    /// Images in the selected article, targeted by [`Action::OpenImage`].
+    pub article_images: Vec<crate::render::ArticleImage>,
+    /// Lead image of the selected article, shown inline when enabled.
+    pub hero_image: Option<HeroImage>,
+    /// The terminal's graphics protocol, detected at startup when
+    /// `display.inline_images` is on; `None` if it has none.
+    pub image_picker: Option<Picker>,
     /// ID of the currently selected article (for preserving selection across refreshes).
     selected_article_id: Option<i64>,
+    /// [`FeedListItem::source_key`] of the entry `articles` was loaded for.
+    articles_source: Option<String>,
+    /// ID of the article whose content is shown in the article view. Lags
+    /// behind the selection until the async render lands.
+    pub opened_article_id: Option<i64>,
     /// Selection state for the feeds list widget.
     pub feeds_state: ListState,
     /// Selection state for the articles list widget.
     pub articles_state: ListState,
     /// Vertical scroll offset for the article view pane.
     pub article_scroll: u16,
-    /// Number of lines in the current article content.
+    /// Article that `article_scroll` belongs to.
+    scroll_article_id: Option<i64>,
+    /// Scroll offsets of articles left partway through, restored when one
+    /// is reopened. Only articles in the current list are kept.
+    article_scrolls: HashMap<i64, u16>,
+    /// Rows the current article content takes in the article view once
+    /// wrapped, as counted in the last frame (its line count before that).
     pub article_content_lines: u16,
+    /// Inner height of the feeds pane in the last frame (0 before the first).
+    pub feeds_pane_height: u16,
+    /// Inner height of the articles pane in the last frame (0 before the first).
+    pub articles_pane_height: u16,
+    /// Lines taken by an article row with a full-length title in the last
+    /// frame (1 before the first).
+    pub articles_row_height: u16,
+    /// Inner height of the article view in the last frame (0 before the first).
+    pub article_view_height: u16,
     /// User configuration (column widths, refresh interval, etc.).
     pub config: Config,
     /// Whether a background feed refresh is currently in progress.
     pub is_refreshing: bool,
     /// An optional status message to display in the status bar.
     pub status_message: Option<String>,
+    /// Render in plain monochrome (`--no-color` or `NO_COLOR`), marking the
+    /// selected row with a glyph instead of a highlight background.
+    pub no_color: bool,
+    /// Current frame of the refresh spinner (advanced on every tick).
+    pub spinner_frame: u8,
+    /// Zen reading mode: the article view fills the screen while focused.
+    pub zen: bool,
+    /// Whether anything on screen may have changed since the last frame.
+    /// The event loop skips drawing while this is false.
+    pub needs_redraw: bool,
+    /// When the last frame was drawn.
+    last_frame: Instant,
 
     /// Pending count prefix for vim-style navigation (e.g., "10j" moves down 10).
     pub pending_count: Option<u32>,
 
+    /// Keys typed so far towards a multi-key sequence (e.g. the first `g`
+    /// of `gg`).
+    pub pending_keys: String,
+    /// When the last key of `pending_keys` was typed.
+    pending_keys_since: Option<Instant>,
+
     /// Optional active popup modal.
     pub popup: Option<crate::ui::popup::Popup>,
 
@@ -199,24 +620,59 @@ pub struct App {
     // -- Private fields --
     /// Async database wrapper.
     db: AsyncDb,
+    /// Background database tasks still running, awaited on quit so a
+    /// last-moment star or read toggle isn't lost.
+    db_tasks: Arc<AtomicUsize>,
     /// All feeds loaded from the database.
     feeds: Vec<db::Feed>,
     /// Group titles whose feed entries are currently hidden.
     collapsed_groups: HashSet<String>,
+    /// Number of starred articles, shown on the "Starred" entry.
+    starred_count: u32,
+    /// Number of queued articles, shown on the "Queue" entry.
+    queued_count: u32,
+    /// Unread articles sharing a normalized URL, as `(feed_id, url)` pairs;
+    /// only loaded when `display.dedup_by_url` is on.
+    unread_duplicates: Vec<(i64, String)>,
+    /// Feeds-pane selection from the last run, restored once feeds load.
+    pending_restore_source: Option<SelectedSource>,
+    /// Article GUID from the last run, restored once articles load.
+    pending_restore_article: Option<String>,
     /// Empty groups (groups with no feeds) that should still be displayed.
     empty_groups: Vec<String>,
     /// Sender half of the channel used by background feed-fetch tasks.
     feed_update_tx: UnboundedSender<FeedUpdateResult>,
     /// Sender half of the channel for async database results.
     db_result_tx: UnboundedSender<DbResult>,
+    /// Sender half of the channel for async network results.
+    net_result_tx: UnboundedSender<NetResult>,
     /// Sender half of the channel for async render results.
     render_tx: UnboundedSender<RenderResult>,
+    /// Bumped for every render request; older results are stale.
+    render_generation: u64,
+    /// Recently finished renders, reused when an article is reopened.
+    render_cache: RenderCache,
+    /// Articles whose web page has been fetched (or tried) this session.
+    full_content_requested: HashSet<i64>,
+    /// Articles whose web page is being fetched right now.
+    full_content_in_flight: HashSet<i64>,
     /// Number of outstanding background feed-fetch tasks.
     pending_refreshes: usize,
-    /// When true, skip reloading articles after feeds load (used for count-only updates).
-    skip_articles_reload_after_feeds_load: bool,
+    /// Number of feed-fetch tasks started in the current refresh batch.
+    refresh_total: usize,
+    /// Number of new articles stored during the current refresh batch.
+    refresh_new_articles: usize,
+    /// Number of stored articles changed by the current refresh batch.
+    refresh_updated_articles: usize,
+    /// Notify-enabled feeds fetched in this batch.
+    notify_candidates: HashSet<i64>,
+    /// New articles to notify about once the refresh batch finishes.
+    pending_news: Vec<crate::notify::FeedNews>,
     /// Whether to trigger refresh after initial feeds are loaded.
     refresh_on_startup_pending: bool,
+    /// When true, select the first unread article once the next article list
+    /// arrives (set by `goto_next_unread_feed`).
+    pending_first_unread: bool,
     /// Phantom data to make the struct Send + Sync despite having UnboundedSender
     _phantom: PhantomData<*const ()>,
 }
@@ -234,6 +690,9 @@ struct GroupNode {
     full_path: String,
     /// Total unread count including all nested children.
     unread_count: u32,
+    /// Total article count including all nested children (0 unless feeds
+    /// were loaded with totals).
+    total_count: u32,
     /// Direct feeds in this group (not in nested subgroups).
     feeds: Vec<db::Feed>,
     /// Nested child groups.
@@ -248,28 +707,102 @@ impl GroupNode {
         direct_feeds_count + children_count
     }
 
-    /// Update unread counts for this node and all children.
-    fn update_unread_counts(&mut self) {
+    /// Calculate total article count recursively.
+    fn calculate_total_count(&self) -> u32 {
+        let direct_feeds_count: u32 = self.feeds.iter().map(|f| f.total_count).sum();
+        let children_count: u32 = self.children.iter().map(|c| c.calculate_total_count()).sum();
+        direct_feeds_count + children_count
+    }
+
+    /// Update unread and total counts for this node and all children.
+    fn update_counts(&mut self) {
         self.unread_count = self.calculate_unread_count();
+        self.total_count = self.calculate_total_count();
         for child in &mut self.children {
-            child.update_unread_counts();
+            child.update_counts();
         }
     }
 }
 
+/// Map `path` into a renamed subtree: `old_path` itself and every path nested
+/// below it move under `new_path`; other paths are returned unchanged.
+fn rename_group_path(path: &str, old_path: &str, new_path: &str) -> String {
+    if path == old_path {
+        return new_path.to_string();
+    }
+    match path.strip_prefix(old_path).and_then(|rest| rest.strip_prefix(" > ")) {
+        Some(rest) => format!("{} > {}", new_path, rest),
+        None => path.to_string(),
+    }
+}
+
+/// Row to select after the article list changes from `old_ids` to `articles`.
+///
+/// Returns the new row of the previously selected article if it survived,
+/// otherwise the first surviving article below it (then above it), or `None`
+/// if nothing from the old list survived or nothing was selected. A list
+/// for another source (`same_source` false) restores nothing.
+fn restored_article_index(
+    old_ids: &[i64],
+    articles: &[db::Article],
+    prev_selected: Option<usize>,
+    same_source: bool,
+) -> Option<usize> {
+    let prev = prev_selected.filter(|&idx| same_source && idx < old_ids.len())?;
+    let position = |id: i64| articles.iter().position(|a| a.id == id);
+
+    old_ids[prev..]
+        .iter()
+        .find_map(|&id| position(id))
+        .or_else(|| old_ids[..prev].iter().rev().find_map(|&id| position(id)))
+}
+
+/// Key for sorting feed titles the way a reader expects: case-insensitive,
+/// with common Latin diacritics folded ("Élan" sorts with "elan").
+fn title_sort_key(title: &str) -> String {
+    title
+        .chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => 'a',
+            'ç' | 'ć' | 'č' => 'c',
+            'ď' | 'đ' => 'd',
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => 'e',
+            'ğ' => 'g',
+            'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => 'i',
+            'ł' | 'ľ' => 'l',
+            'ñ' | 'ń' | 'ň' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => 'o',
+            'ř' => 'r',
+            'ś' | 'š' | 'ş' => 's',
+            'ť' | 'ţ' => 't',
+            'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => 'u',
+            'ý' | 'ÿ' => 'y',
+            'ź' | 'ż' | 'ž' => 'z',
+            c => c,
+        })
+        .collect()
+}
+
 /// Build a hierarchical group tree from flat feed data and empty groups.
 ///
 /// Parses group paths (e.g., "News (World) > Domestic") and builds
 /// a tree structure with proper nesting and unread counts.
 ///
 /// Includes empty groups (groups with no feeds) in the tree structure.
-fn build_group_tree(feeds: &[db::Feed], empty_groups: &[String]) -> Vec<GroupNode> {
+/// Groups appear in `group_order` (config order); any others follow in the
+/// order they are first seen in `feeds`, then `empty_groups`.
+fn build_group_tree(feeds: &[db::Feed], empty_groups: &[String], group_order: &[String]) -> Vec<GroupNode> {
     use std::collections::HashMap;
 
-    // Group feeds by their full group path
+    // Group feeds by their full group path, remembering first-seen order
     let mut path_to_feeds: HashMap<String, Vec<db::Feed>> = HashMap::new();
+    let mut all_paths: Vec<String> = Vec::new();
     for feed in feeds {
         if !feed.group_title.is_empty() {
+            if !path_to_feeds.contains_key(&feed.group_title) {
+                all_paths.push(feed.group_title.clone());
+            }
             path_to_feeds
                 .entry(feed.group_title.clone())
                 .or_insert_with(Vec::new)
@@ -277,9 +810,6 @@ fn build_group_tree(feeds: &[db::Feed], empty_groups: &[String]) -> Vec<GroupNod
         }
     }
 
-    // Collect all unique paths and their components
-    let mut all_paths: Vec<String> = path_to_feeds.keys().cloned().collect();
-
     // Add empty groups to the paths
     for empty_group in empty_groups {
         if !all_paths.contains(empty_group) {
@@ -287,7 +817,8 @@ fn build_group_tree(feeds: &[db::Feed], empty_groups: &[String]) -> Vec<GroupNod
         }
     }
 
-    all_paths.sort();
+    // Stable sort, so paths missing from `group_order` keep their order
+    all_paths.sort_by_key(|path| group_order.iter().position(|p| p == path).unwrap_or(usize::MAX));
 
     if all_paths.is_empty() {
         return Vec::new();
@@ -305,14 +836,32 @@ fn build_group_tree(feeds: &[db::Feed], empty_groups: &[String]) -> Vec<GroupNod
         insert_into_tree(&mut root_nodes, &components, path, feeds, current_depth);
     }
 
-    // Calculate unread counts recursively
+    // Calculate unread and total counts recursively
     for node in &mut root_nodes {
-        node.update_unread_counts();
+        node.update_counts();
     }
 
     root_nodes
 }
 
+/// Count the groups [`build_group_tree`] would build: each group holding
+/// feeds, each empty group and each parent of a nested group.
+fn count_groups(feeds: &[db::Feed], empty_groups: &[String]) -> usize {
+    let paths = feeds
+        .iter()
+        .map(|f| f.group_title.as_str())
+        .chain(empty_groups.iter().map(String::as_str))
+        .filter(|path| !path.is_empty());
+
+    let mut groups = HashSet::new();
+    for path in paths {
+        // "Tech > Rust" also implies a "Tech" group
+        groups.extend(path.match_indices(" > ").map(|(i, _)| &path[..i]));
+        groups.insert(path);
+    }
+    groups.len()
+}
+
 /// Insert a path into the tree, creating nodes as needed.
 fn insert_into_tree(
     nodes: &mut Vec<GroupNode>,
@@ -348,6 +897,7 @@ fn insert_into_tree(
                 title: current_title,
                 full_path: full_path.to_string(),
                 unread_count: 0, // Will be calculated later
+                total_count: 0,
                 feeds,
                 children: Vec::new(),
             };
@@ -359,6 +909,7 @@ fn insert_into_tree(
                 title: current_title,
                 full_path: partial_path,
                 unread_count: 0, // Will be calculated later
+                total_count: 0,
                 feeds: Vec::new(),
                 children: Vec::new(),
             };
@@ -375,16 +926,18 @@ impl App {
     /// This version returns the receivers separately to avoid borrow checker
     /// issues in the main event loop.
     pub fn new_with_receivers(
-        config: Config,
+        mut config: Config,
         db: AsyncDb,
     ) -> (
         Self,
         UnboundedReceiver<FeedUpdateResult>,
         UnboundedReceiver<DbResult>,
+        UnboundedReceiver<NetResult>,
         UnboundedReceiver<RenderResult>,
     ) {
         let (feed_update_tx, feed_update_rx) = mpsc::unbounded_channel();
         let (db_result_tx, db_result_rx) = mpsc::unbounded_channel();
+        let (net_result_tx, net_result_rx) = mpsc::unbounded_channel();
         let (render_tx, render_rx) = mpsc::unbounded_channel();
 
         // Extract refresh_on_start before config is moved into app
@@ -393,33 +946,83 @@ impl App {
         // Initialize empty groups from config
         let empty_groups = crate::config::collect_empty_groups_from_config(&config);
 
+        // Restore collapsed groups from the last run, forgetting groups that
+        // have since been removed from the config
+        let mut ui_state = crate::ui_state::load().unwrap_or_default();
+        ui_state.prune_collapsed_groups(&crate::config::collect_group_paths_from_config(&config));
+
+        // An unknown pane name falls back to the articles pane
+        let (active_pane, pane_error) = match crate::config::parse_pane(&config.display.initial_pane) {
+            Ok(pane) => (pane, None),
+            Err(e) => (ActivePane::Articles, Some(e)),
+        };
+        // Glyphs that would break list alignment fall back to the defaults
+        let glyph_error = config.display.glyphs.reset_invalid().err();
+        // An unknown layout is drawn side by side
+        let layout_error = crate::config::parse_layout(&config.display.layout).err();
+
         let mut app = Self {
             should_quit: false,
-            active_pane: ActivePane::Articles,
+            active_pane,
             feed_list_items: Vec::new(),
             articles: Vec::new(),
             article_content: String::new(),
+            article_code_lines: Vec::new(),
+            article_images: Vec::new(),
+            hero_image: None,
+            image_picker: None,
             selected_article_id: None,
+            articles_source: None,
+            opened_article_id: None,
             feeds_state: ListState::default(),
             articles_state: ListState::default(),
             article_scroll: 0,
+            scroll_article_id: None,
+            article_scrolls: HashMap::new(),
             article_content_lines: 0,
+            feeds_pane_height: 0,
+            articles_pane_height: 0,
+            articles_row_height: 1,
+            article_view_height: 0,
             config,
             is_refreshing: refresh_on_startup_pending, // Show "Refreshing..." on start if configured
-            status_message: None,
+            status_message: pane_error.or(glyph_error).or(layout_error),
+            no_color: false,
+            spinner_frame: 0,
+            zen: false,
+            needs_redraw: true,
+            last_frame: Instant::now(),
             pending_count: None,
+            pending_keys: String::new(),
+            pending_keys_since: None,
             popup: None,
             clipboard: None,
             db,
+            db_tasks: Arc::new(AtomicUsize::new(0)),
             feeds: Vec::new(),
-            collapsed_groups: HashSet::new(),
+            collapsed_groups: ui_state.collapsed_groups.into_iter().collect(),
+            starred_count: 0,
+            queued_count: 0,
+            unread_duplicates: Vec::new(),
+            pending_restore_source: ui_state.selected_source,
+            pending_restore_article: ui_state.selected_article_guid,
             empty_groups,
             feed_update_tx,
             db_result_tx,
+            net_result_tx,
             render_tx,
+            render_generation: 0,
+            render_cache: RenderCache::default(),
+            full_content_requested: HashSet::new(),
+            full_content_in_flight: HashSet::new(),
             pending_refreshes: 0,
-            skip_articles_reload_after_feeds_load: false,
+            refresh_total: 0,
+            refresh_new_articles: 0,
+            refresh_updated_articles: 0,
+            notify_candidates: HashSet::new(),
+            pending_news: Vec::new(),
             refresh_on_startup_pending,
+            pending_first_unread: false,
             _phantom: PhantomData,
         };
 
@@ -427,12 +1030,12 @@ impl App {
         app.start_reload_feeds();
 
         // Set up initial placeholder state
-        app.feed_list_items.push(FeedListItem::All { unread_count: 0 });
+        app.feed_list_items.push(FeedListItem::All { unread_count: 0, total_count: 0 });
         app.feeds_state.select(Some(0));
         app.articles = Vec::new();
         app.articles_state.select(None);
 
-        (app, feed_update_rx, db_result_rx, render_rx)
+        (app, feed_update_rx, db_result_rx, net_result_rx, render_rx)
     }
 
     // ---------------------------------------------------------------------
@@ -451,61 +1054,24 @@ impl App {
     /// Process a completed async database operation result.
     pub fn handle_db_result(&mut self, result: DbResult) {
         match result {
-            DbResult::FeedsLoaded(feeds) => {
+            DbResult::FeedsLoaded { feeds, starred_count, queued_count, unread_duplicates } => {
                 self.feeds = feeds;
+                self.starred_count = starred_count;
+                self.queued_count = queued_count;
+                self.unread_duplicates = unread_duplicates;
                 self.build_feed_list_items();
+                self.restore_pending_source();
                 // Check if we should trigger startup refresh after feeds are loaded
                 if self.refresh_on_startup_pending {
                     self.refresh_on_startup_pending = false;
-                    self.start_refresh_all();
-                }
-                // Reload articles for the current selection if needed
-                // Skip if we're just updating feed counts (e.g., after marking article as read)
-                if self.skip_articles_reload_after_feeds_load {
-                    self.skip_articles_reload_after_feeds_load = false;
-                } else {
-                    self.load_articles_for_current_selection();
+                    self.start_auto_refresh();
                 }
+                self.load_articles_for_current_selection();
             }
             DbResult::ArticlesLoaded { feed_id, articles } => {
                 // Only update if we're still viewing this feed
                 if self.selected_feed().map(|f| f.id) == Some(feed_id) {
-                    // Save the current selected article ID to restore it after refresh
-                    let prev_selected_id = self.articles_state.selected()
-                        .and_then(|idx| self.articles.get(idx))
-                        .map(|a| a.id);
-
-                    self.articles = articles;
-
-                    // Try to restore the previous selection
-                    let restored_idx = prev_selected_id
-                        .and_then(|id| self.articles.iter().position(|a| a.id == id));
-
-                    if self.articles.is_empty() {
-                        self.articles_state.select(None);
-                        self.selected_article_id = None;
-                    } else if let Some(idx) = restored_idx {
-                        self.articles_state.select(Some(idx));
-                        self.selected_article_id = prev_selected_id;
-                        // Always render when restoring selection
-                        self.article_scroll = 0;
-                        self.start_render_article_content();
-                    } else {
-                        // Previous article not found (or first load), select first
-                        self.articles_state.select(Some(0));
-                        let new_article_id = self.articles.first().map(|a| a.id);
-                        // Render if this is a new feed (prev_selected_id was None) or different article
-                        if prev_selected_id.is_none() || prev_selected_id != new_article_id {
-                            if let Some(article) = self.articles.first() {
-                                self.selected_article_id = new_article_id;
-                                if !article.is_read {
-                                    self.start_toggle_read(article.id);
-                                }
-                            }
-                            self.article_scroll = 0;
-                            self.start_render_article_content();
-                        }
-                    }
+                    self.replace_articles(articles);
                 }
             }
             DbResult::GroupArticlesLoaded { group_title, articles } => {
@@ -516,42 +1082,7 @@ impl App {
                     .unwrap_or(false);
 
                 if still_viewing {
-                    // Save the current selected article ID to restore it after refresh
-                    let prev_selected_id = self.articles_state.selected()
-                        .and_then(|idx| self.articles.get(idx))
-                        .map(|a| a.id);
-
-                    self.articles = articles;
-
-                    // Try to restore the previous selection
-                    let restored_idx = prev_selected_id
-                        .and_then(|id| self.articles.iter().position(|a| a.id == id));
-
-                    if self.articles.is_empty() {
-                        self.articles_state.select(None);
-                        self.selected_article_id = None;
-                    } else if let Some(idx) = restored_idx {
-                        self.articles_state.select(Some(idx));
-                        self.selected_article_id = prev_selected_id;
-                        // Always render when restoring selection
-                        self.article_scroll = 0;
-                        self.start_render_article_content();
-                    } else {
-                        // Previous article not found (or first load), select first
-                        self.articles_state.select(Some(0));
-                        let new_article_id = self.articles.first().map(|a| a.id);
-                        // Render if this is a new feed (prev_selected_id was None) or different article
-                        if prev_selected_id.is_none() || prev_selected_id != new_article_id {
-                            if let Some(article) = self.articles.first() {
-                                self.selected_article_id = new_article_id;
-                                if !article.is_read {
-                                    self.start_toggle_read(article.id);
-                                }
-                            }
-                            self.article_scroll = 0;
-                            self.start_render_article_content();
-                        }
-                    }
+                    self.replace_articles(articles);
                 }
             }
             DbResult::AllArticlesLoaded(articles) => {
@@ -562,56 +1093,93 @@ impl App {
                     .unwrap_or(false);
 
                 if still_viewing_all {
-                    // Save the current selected article ID to restore it after refresh
-                    let prev_selected_id = self.articles_state.selected()
-                        .and_then(|idx| self.articles.get(idx))
-                        .map(|a| a.id);
-
-                    self.articles = articles;
-
-                    // Try to restore the previous selection
-                    let restored_idx = prev_selected_id
-                        .and_then(|id| self.articles.iter().position(|a| a.id == id));
-
-                    if self.articles.is_empty() {
-                        self.articles_state.select(None);
-                        self.selected_article_id = None;
-                    } else if let Some(idx) = restored_idx {
-                        self.articles_state.select(Some(idx));
-                        self.selected_article_id = prev_selected_id;
-                        // Always render when restoring selection
-                        self.article_scroll = 0;
-                        self.start_render_article_content();
-                    } else {
-                        // Previous article not found (or first load), select first
-                        self.articles_state.select(Some(0));
-                        let new_article_id = self.articles.first().map(|a| a.id);
-                        // Render if this is a new feed (prev_selected_id was None) or different article
-                        if prev_selected_id.is_none() || prev_selected_id != new_article_id {
-                            if let Some(article) = self.articles.first() {
-                                self.selected_article_id = new_article_id;
-                                if !article.is_read {
-                                    self.start_toggle_read(article.id);
-                                }
-                            }
-                            self.article_scroll = 0;
-                            self.start_render_article_content();
-                        }
-                    }
+                    self.replace_articles(articles);
+                }
+            }
+            DbResult::StarredLoaded(articles) => {
+                // Only update if we're still viewing "Starred"
+                let still_viewing_starred = self.feeds_state.selected()
+                    .and_then(|idx| self.feed_list_items.get(idx))
+                    .map(|item| matches!(item, FeedListItem::Starred { .. }))
+                    .unwrap_or(false);
+
+                if still_viewing_starred {
+                    self.replace_articles(articles);
+                }
+            }
+            DbResult::QueueLoaded(articles) => {
+                // Only update if we're still viewing "Queue"
+                let still_viewing_queue = self.feeds_state.selected()
+                    .and_then(|idx| self.feed_list_items.get(idx))
+                    .map(|item| matches!(item, FeedListItem::Queue { .. }))
+                    .unwrap_or(false);
+
+                if still_viewing_queue {
+                    self.replace_articles(articles);
+                }
+            }
+            DbResult::RecentLoaded(articles) => {
+                // Only update if we're still viewing "Recent"
+                let still_viewing_recent = self.feeds_state.selected()
+                    .and_then(|idx| self.feed_list_items.get(idx))
+                    .map(|item| matches!(item, FeedListItem::Recent { .. }))
+                    .unwrap_or(false);
+
+                if still_viewing_recent {
+                    self.replace_articles(articles);
                 }
             }
-            DbResult::ReadToggled { article_id, new_value } => {
+            DbResult::ReadToggled { article_id, new_value, mut changed_feed_ids } => {
                 if let Some(article) = self.articles.iter_mut().find(|a| a.id == article_id) {
                     article.is_read = new_value;
                 }
-                // Reload feeds to update unread counts, but don't reload articles
-                self.skip_articles_reload_after_feeds_load = true;
-                self.start_reload_feeds();
+                self.sync_article_selection();
+                // Recount only the changed feeds rather than reloading them all
+                changed_feed_ids.sort_unstable();
+                changed_feed_ids.dedup();
+                for feed_id in changed_feed_ids {
+                    self.start_update_unread_count(feed_id);
+                }
+                if self.config.display.dedup_by_url {
+                    self.start_load_unread_duplicates();
+                }
+            }
+            DbResult::UnreadCountUpdated { feed_id, count } => {
+                if let Some(feed) = self.feeds.iter_mut().find(|f| f.id == feed_id) {
+                    feed.unread_count = count;
+                }
+                // Group and "All" counts are summed from the feeds
+                self.build_feed_list_items();
+            }
+            DbResult::UnreadDuplicatesLoaded(unread_duplicates) => {
+                self.unread_duplicates = unread_duplicates;
+                self.build_feed_list_items();
             }
-            DbResult::StarToggled { article_id, new_value } => {
+            DbResult::StarToggled { article_id, new_value, changed_feed_ids } => {
                 if let Some(article) = self.articles.iter_mut().find(|a| a.id == article_id) {
                     article.is_starred = new_value;
                 }
+                // Keep the "Starred" count current without a full reload
+                let changed = changed_feed_ids.len() as u32;
+                self.starred_count = if new_value {
+                    self.starred_count + changed
+                } else {
+                    self.starred_count.saturating_sub(changed)
+                };
+                self.build_feed_list_items();
+            }
+            DbResult::QueueToggled { article_id, new_value, changed_feed_ids } => {
+                if let Some(article) = self.articles.iter_mut().find(|a| a.id == article_id) {
+                    article.is_queued = new_value;
+                }
+                // Keep the "Queue" count current without a full reload
+                let changed = changed_feed_ids.len() as u32;
+                self.queued_count = if new_value {
+                    self.queued_count + changed
+                } else {
+                    self.queued_count.saturating_sub(changed)
+                };
+                self.build_feed_list_items();
             }
             DbResult::MarkedRead { feed_id } => {
                 // Reload the current article list
@@ -622,41 +1190,169 @@ impl App {
                         }
                     }
                     None => {
-                        // "All" was selected
-                        self.start_load_all_articles();
+                        // Several feeds at once ("All", a group or a range
+                        // of articles): reload whatever is shown
+                        self.load_articles_for_current_selection();
                     }
                 }
                 // Reload feeds to update unread counts
                 self.start_reload_feeds();
             }
+
+            DbResult::ArticlesUpserted { feed_id, inserted, updated, newest_title } => {
+                self.refresh_new_articles += inserted;
+                self.refresh_updated_articles += updated;
+
+                if self.notify_candidates.remove(&feed_id) && inserted > 0 {
+                    self.pending_news.push(crate::notify::FeedNews {
+                        feed_title: self.feed_title(feed_id).unwrap_or_default().to_string(),
+                        count: inserted,
+                        newest_title,
+                    });
+                }
+
+                // Track outstanding refreshes.
+                self.pending_refreshes = self.pending_refreshes.saturating_sub(1);
+                if self.pending_refreshes == 0 {
+                    self.is_refreshing = false;
+                    self.send_notifications();
+                    // Keep fetch errors visible rather than replacing them.
+                    if self.status_message.is_none() {
+                        let mut message = format!(
+                            "Refreshed {} feed(s): {} new article(s)",
+                            self.refresh_total, self.refresh_new_articles
+                        );
+                        if self.refresh_updated_articles > 0 {
+                            message.push_str(&format!(", {} updated", self.refresh_updated_articles));
+                        }
+                        self.status_message = Some(message);
+                    }
+                }
+            }
+
+            DbResult::Error(message) => {
+                tracing::error!("{message}");
+                self.status_message = Some(message);
+            }
+        }
+    }
+
+    /// Process a completed background network request.
+    pub fn handle_net_result(&mut self, result: NetResult) {
+        match result {
+            NetResult::FeedDiscovered { title, url, parent_group, result } => match result {
+                Ok(feed::Discovery::Feed) => {
+                    self.add_feed(title, url, None, parent_group);
+                }
+                Ok(feed::Discovery::Found(feed_url)) => {
+                    self.add_feed(title, url, Some(feed_url), parent_group);
+                }
+                Ok(feed::Discovery::NotFound) => {
+                    self.status_message = Some(format!("No feed found at {}", url));
+                }
+                Err(err) => {
+                    self.status_message = Some(format!("Could not fetch {}: {}", url, err));
+                }
+            },
+
+            NetResult::HeroImageLoaded { article_id, image } => {
+                // Ignore downloads for an article that is no longer shown
+                if self.selected_article().is_some_and(|a| a.id == article_id) {
+                    self.hero_image = image;
+                }
+            }
+
+            NetResult::HeroImageUnsupported { article_id } => {
+                if self.selected_article().is_some_and(|a| a.id == article_id) {
+                    self.hero_image = None;
+                    self.status_message = Some("Lead image isn't a PNG, JPEG, GIF or WebP image".to_string());
+                }
+            }
+
+            NetResult::FullContentFetched { article_id, result } => {
+                self.full_content_in_flight.remove(&article_id);
+                let selected = self.selected_article().is_some_and(|a| a.id == article_id);
+                if self.status_message.as_deref() == Some(FETCHING_FULL_ARTICLE) {
+                    self.status_message = None;
+                }
+                match result {
+                    Ok(content) => {
+                        for article in self.articles.iter_mut().filter(|a| a.id == article_id) {
+                            article.content = Some(content.clone());
+                            article.has_full_content = true;
+                        }
+                        if selected {
+                            self.start_render_article_content();
+                        }
+                    }
+                    Err(err) if selected => {
+                        self.status_message = Some(format!("Couldn't fetch full content: {}", err));
+                    }
+                    Err(_) => {}
+                }
+            }
         }
     }
 
     /// Process a completed async render result.
     pub fn handle_render_result(&mut self, result: RenderResult) {
+        self.render_cache.insert(result.clone());
+
+        // Renders can finish out of order when moving quickly; only the
+        // latest request for the selected article may land
+        if result.generation == Some(self.render_generation)
+            && self.selected_article().is_some_and(|a| a.id == result.article_id)
+        {
+            self.show_render(result);
+        }
+    }
+
+    /// Show a finished render in the article view.
+    fn show_render(&mut self, result: RenderResult) {
+        self.opened_article_id = Some(result.article_id);
         self.article_content = result.content;
-        // Count the number of lines in the rendered content
+        self.article_code_lines = result.code_lines;
+        self.article_images = result.images;
+        self.start_load_hero_image();
+        // Count the lines until the next frame counts the wrapped rows
         self.article_content_lines = self.article_content.lines().count() as u16;
         // Reset scroll position if needed (content may have changed)
-        if self.article_scroll > 0 && self.article_scroll >= self.article_content_lines.saturating_sub(1) {
-            self.article_scroll = self.article_content_lines.saturating_sub(1);
-        }
+        self.article_scroll = self.article_scroll.min(self.max_article_scroll());
     }
 
     // ---------------------------------------------------------------------
     // Action dispatch
     // ---------------------------------------------------------------------
 
+    /// Move the selection in the focused list by `delta` rows, as `delta`
+    /// presses of MoveDown (MoveUp when negative) would.
+    pub fn move_selection(&mut self, delta: i32) {
+        if delta == 0 {
+            return;
+        }
+        self.pending_count = Some(delta.unsigned_abs());
+        self.update(if delta > 0 { Action::MoveDown } else { Action::MoveUp });
+    }
+
     /// Process a single user action, updating all relevant application state.
     pub fn update(&mut self, action: Action) {
         // Clear any transient status message on the next user action.
         self.status_message = None;
 
+        // Any other key abandons a multi-key sequence
+        if !matches!(action, Action::PendingKey(_)) {
+            self.pending_keys.clear();
+            self.pending_keys_since = None;
+        }
+
         match action {
             Action::Quit => {
+                self.save_ui_state();
                 self.should_quit = true;
             }
 
+            Action::PendingKey(c) => self.push_pending_key(c),
+
             Action::Digit(digit) => {
                 // Accumulate digit for vim-style count prefix
                 let new_count = self.pending_count.unwrap_or(0) * 10 + digit as u32;
@@ -680,6 +1376,10 @@ impl App {
                 };
             }
 
+            Action::FocusFeeds => self.active_pane = ActivePane::Feeds,
+            Action::FocusArticles => self.active_pane = ActivePane::Articles,
+            Action::FocusArticleView => self.active_pane = ActivePane::ArticleView,
+
             Action::MoveUp => {
                 let count = self.pending_count.unwrap_or(1) as i32;
                 self.pending_count = None;
@@ -718,7 +1418,10 @@ impl App {
 
                     match item {
                         FeedListItem::All { .. } => {
-                            self.start_mark_all_read_all();
+                            self.confirm_mark_all_read_all();
+                        }
+                        FeedListItem::Starred { .. } | FeedListItem::Queue { .. } | FeedListItem::Recent { .. } => {
+                            // Virtual lists span feeds; leave read state alone
                         }
                         FeedListItem::GroupHeader { full_path, .. } => {
                             let group_path = full_path.clone();
@@ -743,7 +1446,10 @@ impl App {
 
                     match item {
                         FeedListItem::All { .. } => {
-                            self.start_mark_all_read_all();
+                            self.confirm_mark_all_read_all();
+                        }
+                        FeedListItem::Starred { .. } | FeedListItem::Queue { .. } | FeedListItem::Recent { .. } => {
+                            // Virtual lists span feeds; leave read state alone
                         }
                         FeedListItem::GroupHeader { full_path, .. } => {
                             let group_path = full_path.clone();
@@ -758,15 +1464,22 @@ impl App {
 
             Action::ToggleRead => {
                 if let Some(article) = self.selected_article() {
-                    let article_id = article.id;
-                    self.start_toggle_read(article_id);
+                    let (article_id, is_read) = (article.id, article.is_read);
+                    self.start_set_read(article_id, !is_read);
                 }
             }
 
             Action::ToggleStar => {
                 if let Some(article) = self.selected_article() {
-                    let article_id = article.id;
-                    self.start_toggle_star(article_id);
+                    let (article_id, is_starred) = (article.id, article.is_starred);
+                    self.start_set_starred(article_id, !is_starred);
+                }
+            }
+
+            Action::ToggleQueue => {
+                if let Some(article) = self.selected_article() {
+                    let (article_id, is_queued) = (article.id, article.is_queued);
+                    self.start_set_queued(article_id, !is_queued);
                 }
             }
 
@@ -778,14 +1491,32 @@ impl App {
                     .unwrap_or(false);
 
                 if is_all {
-                    self.start_mark_all_read_all();
+                    self.confirm_mark_all_read_all();
                 } else if let Some(feed) = self.selected_feed() {
                     let feed_id = feed.id;
                     self.start_mark_all_read(feed_id);
                 }
             }
 
+            Action::MarkReadToHere => {
+                if let Some(selected) = self.articles_state.selected() {
+                    let ids: Vec<i64> = self.articles[..selected.min(self.articles.len())]
+                        .iter()
+                        .filter(|a| !a.is_read)
+                        .map(|a| a.id)
+                        .collect();
+                    if !ids.is_empty() {
+                        self.start_mark_read_by_ids(ids);
+                    }
+                }
+            }
+
             Action::OpenInBrowser => {
+                // Opening an article always counts as reading it
+                if let Some(article) = self.selected_article().filter(|a| !a.is_read) {
+                    let article_id = article.id;
+                    self.start_set_read(article_id, true);
+                }
                 if let Some(article) = self.selected_article() {
                     if let Some(ref url) = article.url.clone() {
                         // Run browser opening in background to avoid blocking the TUI
@@ -797,6 +1528,39 @@ impl App {
                 }
             }
 
+            Action::OpenFeedSite => {
+                let feed = match self.active_pane {
+                    ActivePane::Feeds => self.selected_feed(),
+                    _ => self.selected_article()
+                        .and_then(|a| self.feeds.iter().find(|f| f.id == a.feed_id)),
+                };
+                match feed {
+                    Some(feed) => {
+                        let url = feed.site_url.clone()
+                            .filter(|u| !u.is_empty())
+                            .unwrap_or_else(|| feed.url.clone());
+                        tokio::spawn(async move {
+                            let _ = open::that(&url);
+                        });
+                    }
+                    None => self.status_message = Some("No feed selected".to_string()),
+                }
+            }
+
+            Action::FetchFullArticle => self.fetch_full_article(),
+
+            Action::ToggleZen => self.zen = !self.zen,
+
+            Action::GrowFeedsPane => self.resize_feeds_pane(FEEDS_PANE_RESIZE_STEP),
+            Action::ShrinkFeedsPane => self.resize_feeds_pane(-FEEDS_PANE_RESIZE_STEP),
+
+            Action::ToggleTimeFormat => {
+                let format = &mut self.config.display.format;
+                format.time = if format.time == 24 { 12 } else { 24 };
+                self.status_message = Some(format!("{}-hour time", format.time));
+                self.start_render_article_content();
+            }
+
             Action::ScrollUp => match self.active_pane {
                 ActivePane::ArticleView => {
                     self.article_scroll = self.article_scroll.saturating_sub(1);
@@ -806,36 +1570,30 @@ impl App {
 
             Action::ScrollDown => match self.active_pane {
                 ActivePane::ArticleView => {
-                    // Don't scroll past the last line
-                    let max_scroll = if self.article_content_lines > 0 {
-                        self.article_content_lines.saturating_sub(1)
-                    } else {
-                        0
-                    };
-                    self.article_scroll = self.article_scroll.saturating_add(1).min(max_scroll);
+                    // Keep the last page full
+                    self.article_scroll = self.article_scroll.saturating_add(1).min(self.max_article_scroll());
+                    self.mark_read_on_scroll();
                 }
                 _ => {}
             },
 
             Action::ScrollHalfPageUp => match self.active_pane {
-                ActivePane::Feeds => self.move_feed_selection(-10),
-                ActivePane::Articles => self.move_article_selection(-10),
+                ActivePane::Feeds => self.move_feed_selection(-half_page(self.feeds_pane_height)),
+                ActivePane::Articles => self.move_article_selection(-self.half_page_articles()),
                 ActivePane::ArticleView => {
-                    self.article_scroll = self.article_scroll.saturating_sub(10);
+                    let delta = half_page(self.article_view_height) as u16;
+                    self.article_scroll = self.article_scroll.saturating_sub(delta);
                 }
             },
 
             Action::ScrollHalfPageDown => match self.active_pane {
-                ActivePane::Feeds => self.move_feed_selection(10),
-                ActivePane::Articles => self.move_article_selection(10),
+                ActivePane::Feeds => self.move_feed_selection(half_page(self.feeds_pane_height)),
+                ActivePane::Articles => self.move_article_selection(self.half_page_articles()),
                 ActivePane::ArticleView => {
-                    // Don't scroll past the last line
-                    let max_scroll = if self.article_content_lines > 0 {
-                        self.article_content_lines.saturating_sub(1)
-                    } else {
-                        0
-                    };
-                    self.article_scroll = self.article_scroll.saturating_add(10).min(max_scroll);
+                    let delta = half_page(self.article_view_height) as u16;
+                    // Keep the last page full
+                    self.article_scroll = self.article_scroll.saturating_add(delta).min(self.max_article_scroll());
+                    self.mark_read_on_scroll();
                 }
             },
 
@@ -844,16 +1602,26 @@ impl App {
             }
 
             Action::RefreshCurrent => {
-                // If "All" is selected, refresh all feeds.
-                let is_all = self.feeds_state.selected()
-                    .and_then(|idx| self.feed_list_items.get(idx))
-                    .map(|item| matches!(item, FeedListItem::All { .. }))
-                    .unwrap_or(false);
+                // "All" refreshes every feed, a group its feeds and subgroups.
+                let selected = self.feeds_state.selected()
+                    .and_then(|idx| self.feed_list_items.get(idx));
+                let group_path = match selected {
+                    Some(FeedListItem::GroupHeader { full_path, .. }) => Some(full_path.clone()),
+                    _ => None,
+                };
 
-                if is_all {
+                if matches!(selected, Some(FeedListItem::All { .. })) {
                     self.start_refresh_all();
+                } else if let Some(group_path) = group_path {
+                    self.start_refresh_group(&group_path);
                 } else if let Some(feed) = self.selected_feed().cloned() {
+                    if self.pending_refreshes == 0 {
+                        self.refresh_total = 0;
+                        self.refresh_new_articles = 0;
+                        self.refresh_updated_articles = 0;
+                    }
                     self.pending_refreshes += 1;
+                    self.refresh_total += 1;
                     self.is_refreshing = true;
                     feed::refresh_one(&self.feed_update_tx, &feed);
                 }
@@ -873,10 +1641,8 @@ impl App {
                             self.articles_state.select(Some(0));
                             if let Some(article) = self.articles.first() {
                                 self.selected_article_id = Some(article.id);
-                                if !article.is_read {
-                                    self.start_toggle_read(article.id);
-                                }
                             }
+                            self.mark_read_on_select(0);
                         }
                         self.start_render_article_content();
                     }
@@ -902,21 +1668,16 @@ impl App {
                             self.articles_state.select(Some(last_idx));
                             if let Some(article) = self.articles.last() {
                                 self.selected_article_id = Some(article.id);
-                                if !article.is_read {
-                                    self.start_toggle_read(article.id);
-                                }
                             }
+                            self.mark_read_on_select(last_idx);
                         }
                         self.start_render_article_content();
                     }
                 }
                 ActivePane::ArticleView => {
-                    // Scroll to the bottom of the content
-                    self.article_scroll = if self.article_content_lines > 0 {
-                        self.article_content_lines.saturating_sub(1)
-                    } else {
-                        0
-                    };
+                    // Scroll to the last page of the content
+                    self.article_scroll = self.max_article_scroll();
+                    self.mark_read_on_scroll();
                 }
             },
 
@@ -938,6 +1699,16 @@ impl App {
                 }
             },
 
+            Action::MoveItemUp => self.move_selected_item(true),
+
+            Action::MoveItemDown => self.move_selected_item(false),
+
+            Action::MoveToGroup => self.open_move_to_group_popup(),
+
+            Action::CommandPrompt => {
+                self.popup = Some(crate::ui::popup::Popup::command());
+            },
+
             Action::CreateGroup => {
                 self.popup = Some(crate::ui::popup::Popup::create_group());
             },
@@ -969,6 +1740,55 @@ impl App {
                     self.paste_clipboard();
                 }
             },
+
+            Action::NextUnread => {
+                self.pending_count = None;
+                self.goto_next_unread();
+            },
+
+            Action::CopyEnclosure => {
+                let enclosure = self.selected_article().and_then(|a| a.enclosure_url.clone());
+                self.status_message = Some(match enclosure {
+                    Some(url) => match crate::osc52::copy(&url) {
+                        Ok(()) => format!("Copied {}", url),
+                        Err(e) => format!("Failed to copy enclosure URL: {}", e),
+                    },
+                    None => "No enclosure for this article".to_string(),
+                });
+            },
+
+            Action::OpenImage => {
+                // A count prefix picks the image by its number in the list
+                let number = self.pending_count.take().unwrap_or(1) as usize;
+                match number.checked_sub(1).and_then(|i| self.article_images.get(i)) {
+                    Some(image) => {
+                        // Relative sources only make sense next to the article
+                        let base = self.articles.iter()
+                            .find(|a| Some(a.id) == self.opened_article_id)
+                            .and_then(|a| a.url.as_deref());
+                        let src = feed::resolve_image_url(&image.src, base)
+                            .map_or_else(|| image.src.clone(), String::from);
+                        tokio::spawn(async move {
+                            let _ = open::that(&src);
+                        });
+                    }
+                    None if self.article_images.is_empty() => {
+                        self.status_message = Some("No images in this article".to_string());
+                    }
+                    None => {
+                        self.status_message = Some(format!(
+                            "No image {} (article has {})",
+                            number,
+                            self.article_images.len()
+                        ));
+                    }
+                }
+            },
+        }
+
+        // An article counts as opened once the article view has focus
+        if self.active_pane == ActivePane::ArticleView {
+            self.start_fetch_full_content();
         }
     }
 
@@ -985,68 +1805,266 @@ impl App {
         let db = self.db.clone();
         let tx = self.db_result_tx.clone();
         let feed_id = result.feed_id;
-        let articles = result.articles;
-        let error = result.error;
+        let display = self.config.display.clone();
+        let with_totals = self.config.display.show_total_counts;
+        let dedup = self.config.display.dedup_by_url;
 
-        tokio::spawn(async move {
-            // Upsert articles
-            if let Err(_e) = db.upsert_articles(articles).await {
-                let _ = tx.send(DbResult::FeedsLoaded(Vec::new())); // Dummy to wake up
-                // TODO: send error
-            }
+        if self.wants_notifications(feed_id) {
+            self.notify_candidates.insert(feed_id);
+        }
 
-            // Update last_fetched
-            if let Err(_e) = db.update_last_fetched(feed_id).await {
-                // TODO: send error
-            }
+        // Surface fetch errors to the user.
+        if let Some(ref err) = result.error {
+            self.status_message = Some(format!("Fetch error: {err}"));
+        } else if let Some(ref warning) = result.warning {
+            let title = self.feed_title(feed_id).unwrap_or("Feed");
+            self.status_message = Some(format!("{title}: {warning}"));
+        }
+
+        self.spawn_db_task(async move {
+            // A failed store counts as nothing new
+            let counts = match crate::headless::store_feed_update(&db, result, &display).await {
+                Ok(counts) => counts,
+                Err(e) => {
+                    send_db_error(&tx, "save fetched articles", e);
+                    db::UpsertCounts::default()
+                }
+            };
+
+            // Report completion so the refresh batch can be tallied
+            let _ = tx.send(DbResult::ArticlesUpserted {
+                feed_id,
+                inserted: counts.inserted,
+                updated: counts.updated,
+                newest_title: counts.newest_title,
+            });
 
             // Trigger feed reload to update unread counts
-            match db.get_all_feeds().await {
-                Ok(feeds) => {
-                    let _ = tx.send(DbResult::FeedsLoaded(feeds));
+            match load_feeds(&db, with_totals, dedup).await {
+                Ok(result) => {
+                    let _ = tx.send(result);
                 }
-                Err(_) => {}
+                Err(e) => send_db_error(&tx, "load feeds", e),
             }
         });
+    }
 
-        // Surface fetch errors to the user.
-        if let Some(ref err) = error {
-            self.status_message = Some(format!("Fetch error: {err}"));
+    /// Whether new articles in this feed should raise a desktop notification.
+    ///
+    /// Feeds that were never fetched successfully before are skipped, so the
+    /// initial sync of a new feed doesn't announce its whole backlog.
+    fn wants_notifications(&self, feed_id: i64) -> bool {
+        if !self.config.notifications.enabled {
+            return false;
         }
+        let Some(feed) = self.feeds.iter().find(|f| f.id == feed_id && f.last_fetched.is_some()) else {
+            return false;
+        };
+        self.config.feeds.iter()
+            .flat_map(|item| item.collect_feeds())
+            .any(|(_, source)| source.notify && source.feed_url() == feed.url)
+    }
+
+    /// Send desktop notifications for the refresh batch that just finished.
+    fn send_notifications(&mut self) {
+        self.notify_candidates.clear();
+        let news = std::mem::take(&mut self.pending_news);
 
-        // Track outstanding refreshes.
-        self.pending_refreshes = self.pending_refreshes.saturating_sub(1);
+        for (summary, body) in crate::notify::build_notifications(&news) {
+            tokio::task::spawn_blocking(move || {
+                let _ = crate::notify::send(&summary, &body);
+            });
+        }
+    }
+
+    /// Progress of the current refresh batch as (completed, total), if any.
+    pub fn refresh_progress(&self) -> Option<(usize, usize)> {
         if self.pending_refreshes == 0 {
-            self.is_refreshing = false;
+            return None;
+        }
+        Some((self.refresh_total - self.pending_refreshes, self.refresh_total))
+    }
+
+    /// Advance time-based UI state; called on every event-loop tick.
+    pub fn handle_tick(&mut self) {
+        if self.is_refreshing {
+            self.spinner_frame = self.spinner_frame.wrapping_add(1);
+            self.needs_redraw = true;
+        }
+        // Keep the feeds pane's fetch ages ("5m") current on an idle screen
+        if self.last_frame.elapsed() >= IDLE_REDRAW_INTERVAL {
+            self.needs_redraw = true;
+        }
+        self.expire_pending_keys();
+    }
+
+    /// Record that a frame was just drawn.
+    pub fn frame_drawn(&mut self) {
+        self.needs_redraw = false;
+        self.last_frame = Instant::now();
+
+        // An article that fits the view can't be scrolled, so reading it
+        // counts once it is open. The wrapped height is only known now.
+        if self.active_pane == ActivePane::ArticleView
+            && self.max_article_scroll() == 0
+            && self.opened_article_id.is_some()
+            && self.opened_article_id == self.selected_article().map(|a| a.id)
+        {
+            self.mark_read_on_scroll();
+        }
+    }
+
+    /// Widen (or, for a negative `delta`, narrow) the feeds pane for the
+    /// session, giving the width to or taking it from the other two panes.
+    fn resize_feeds_pane(&mut self, delta: i16) {
+        let columns = &mut self.config.display.columns;
+        columns.resize_feeds_list(delta);
+        self.status_message = Some(format!(
+            "Pane widths: {}% / {}% / {}%",
+            columns.feeds_list, columns.articles_list, columns.article_view
+        ));
+    }
+
+    /// Add a key to the pending multi-key sequence, running its action once
+    /// the sequence is complete.
+    fn push_pending_key(&mut self, c: char) {
+        self.pending_keys.push(c);
+        match action::resolve_sequence(&self.pending_keys) {
+            SequenceMatch::Complete(action) => {
+                self.pending_keys.clear();
+                self.pending_keys_since = None;
+                self.update(action);
+            }
+            SequenceMatch::Partial => {
+                self.pending_keys_since = Some(Instant::now());
+            }
+            SequenceMatch::Invalid => {
+                self.pending_keys.pop();
+                let keys = std::mem::take(&mut self.pending_keys);
+                self.pending_keys_since = None;
+                let actions = action::abandon_sequence(&keys, c, self.active_pane, &self.config.keybindings);
+                for action in actions {
+                    self.update(action);
+                }
+            }
+        }
+    }
+
+    /// Give up on a multi-key sequence once the next key is overdue. A lone
+    /// key (e.g. `g`) then does whatever it is bound to on its own.
+    fn expire_pending_keys(&mut self) {
+        if self.pending_keys_since.is_none_or(|t| t.elapsed() < action::KEY_SEQUENCE_TIMEOUT) {
+            return;
+        }
+        let keys = std::mem::take(&mut self.pending_keys);
+        self.pending_keys_since = None;
+        self.needs_redraw = true;
+
+        if let Some(action) = action::lone_key_action(&keys, self.active_pane, &self.config.keybindings) {
+            self.update(action);
         }
     }
 
     /// Kick off a background refresh of all feeds.
     pub fn start_refresh_all(&mut self) {
+        self.refresh_all_with_jitter(Duration::ZERO);
+    }
+
+    /// Kick off the startup or periodic refresh of all feeds, spread over
+    /// `network.refresh_jitter_secs`.
+    ///
+    /// Skipped while a refresh is still running, which happens when the
+    /// jitter is close to `refresh_every`: restarting would reset the
+    /// progress counts and fetch feeds twice.
+    pub fn start_auto_refresh(&mut self) {
+        if self.is_refreshing {
+            tracing::info!("previous refresh still running, skipping this one");
+            return;
+        }
+        self.refresh_all_with_jitter(Duration::from_secs(self.config.network.refresh_jitter_secs));
+    }
+
+    fn refresh_all_with_jitter(&mut self, jitter: Duration) {
         if self.feeds.is_empty() {
             return;
         }
         self.pending_refreshes = self.feeds.len();
+        self.refresh_total = self.feeds.len();
+        self.refresh_new_articles = 0;
+        self.refresh_updated_articles = 0;
+        self.is_refreshing = true;
+        feed::refresh_all(&self.feed_update_tx, &self.feeds, jitter);
+    }
+
+    /// Kick off a background refresh of the feeds in a group and its
+    /// subgroups, adding them to any refresh already in progress.
+    fn start_refresh_group(&mut self, group_path: &str) {
+        let feeds = Self::feeds_in_group(&self.feeds, group_path);
+        if feeds.is_empty() {
+            self.status_message = Some(format!("No feeds in '{}'", group_path));
+            return;
+        }
+        if self.pending_refreshes == 0 {
+            self.refresh_total = 0;
+            self.refresh_new_articles = 0;
+            self.refresh_updated_articles = 0;
+        }
+        self.pending_refreshes += feeds.len();
+        self.refresh_total += feeds.len();
         self.is_refreshing = true;
-        feed::refresh_all(&self.feed_update_tx, &self.feeds);
+        feed::refresh_all(&self.feed_update_tx, &feeds, Duration::ZERO);
+    }
+
+    /// Feeds directly in `group_path` or in any group nested below it.
+    fn feeds_in_group(feeds: &[db::Feed], group_path: &str) -> Vec<db::Feed> {
+        let nested_prefix = format!("{} > ", group_path);
+        feeds
+            .iter()
+            .filter(|f| f.group_title == group_path || f.group_title.starts_with(&nested_prefix))
+            .cloned()
+            .collect()
     }
 
     // ---------------------------------------------------------------------
     // Async database operation starters
     // ---------------------------------------------------------------------
 
+    /// Spawn a background task that uses the database. It is counted in
+    /// `db_tasks` until it finishes (or panics).
+    fn spawn_db_task(&self, task: impl Future<Output = ()> + Send + 'static) {
+        self.db_tasks.fetch_add(1, Ordering::SeqCst);
+        let guard = DbTaskGuard(self.db_tasks.clone());
+        tokio::spawn(async move {
+            let _guard = guard;
+            task.await;
+        });
+    }
+
+    /// Wait up to `timeout` for background database tasks to finish.
+    /// Returns false if some were still running when it gave up.
+    pub async fn wait_for_db_tasks(&self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        while self.db_tasks.load(Ordering::SeqCst) > 0 {
+            if Instant::now() >= deadline {
+                return false;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        true
+    }
+
     /// Start an async reload of all feeds from the database.
     fn start_reload_feeds(&mut self) {
         let db = self.db.clone();
         let tx = self.db_result_tx.clone();
-        tokio::spawn(async move {
-            match db.get_all_feeds().await {
-                Ok(feeds) => {
-                    let _ = tx.send(DbResult::FeedsLoaded(feeds));
-                }
-                Err(_e) => {
-                    // TODO: send error
+        let with_totals = self.config.display.show_total_counts;
+        let dedup = self.config.display.dedup_by_url;
+        self.spawn_db_task(async move {
+            match load_feeds(&db, with_totals, dedup).await {
+                Ok(result) => {
+                    let _ = tx.send(result);
                 }
+                Err(e) => send_db_error(&tx, "load feeds", e),
             }
         });
     }
@@ -1055,12 +2073,12 @@ impl App {
     fn start_load_articles_for_feed(&mut self, feed_id: i64) {
         let db = self.db.clone();
         let tx = self.db_result_tx.clone();
-        tokio::spawn(async move {
+        self.spawn_db_task(async move {
             match db.get_articles_for_feed(feed_id).await {
                 Ok(articles) => {
                     let _ = tx.send(DbResult::ArticlesLoaded { feed_id, articles });
                 }
-                Err(_) => {}
+                Err(e) => send_db_error(&tx, "load articles", e),
             }
         });
         // Don't clear articles immediately - keep showing current articles until new ones arrive
@@ -1070,12 +2088,14 @@ impl App {
     fn start_load_articles_for_group(&mut self, group_title: String) {
         let db = self.db.clone();
         let tx = self.db_result_tx.clone();
-        tokio::spawn(async move {
+        let dedup = self.config.display.dedup_by_url;
+        self.spawn_db_task(async move {
             match db.get_articles_for_group(&group_title).await {
                 Ok(articles) => {
+                    let articles = if dedup { db::dedup_by_url(articles) } else { articles };
                     let _ = tx.send(DbResult::GroupArticlesLoaded { group_title, articles });
                 }
-                Err(_) => {}
+                Err(e) => send_db_error(&tx, "load articles", e),
             }
         });
         // Don't clear articles immediately - keep showing current articles until new ones arrive
@@ -1085,41 +2105,142 @@ impl App {
     fn start_load_all_articles(&mut self) {
         let db = self.db.clone();
         let tx = self.db_result_tx.clone();
-        tokio::spawn(async move {
+        let dedup = self.config.display.dedup_by_url;
+        self.spawn_db_task(async move {
             match db.get_all_articles().await {
                 Ok(articles) => {
+                    let articles = if dedup { db::dedup_by_url(articles) } else { articles };
                     let _ = tx.send(DbResult::AllArticlesLoaded(articles));
                 }
-                Err(_) => {}
+                Err(e) => send_db_error(&tx, "load articles", e),
             }
         });
         // Don't clear articles immediately - keep showing current articles until new ones arrive
     }
 
-    /// Start an async toggle read operation.
-    fn start_toggle_read(&mut self, article_id: i64) {
+    /// Start an async load of starred articles.
+    fn start_load_starred_articles(&mut self) {
         let db = self.db.clone();
         let tx = self.db_result_tx.clone();
-        tokio::spawn(async move {
-            match db.toggle_read(article_id).await {
-                Ok(new_value) => {
-                    let _ = tx.send(DbResult::ReadToggled { article_id, new_value });
+        self.spawn_db_task(async move {
+            match db.get_starred_articles().await {
+                Ok(articles) => {
+                    let _ = tx.send(DbResult::StarredLoaded(articles));
                 }
-                Err(_) => {}
+                Err(e) => send_db_error(&tx, "load articles", e),
             }
         });
+        // Don't clear articles immediately - keep showing current articles until new ones arrive
     }
 
-    /// Start an async toggle star operation.
-    fn start_toggle_star(&mut self, article_id: i64) {
+    /// Start an async load of the "read later" queue.
+    fn start_load_queued_articles(&mut self) {
         let db = self.db.clone();
         let tx = self.db_result_tx.clone();
-        tokio::spawn(async move {
-            match db.toggle_star(article_id).await {
-                Ok(new_value) => {
-                    let _ = tx.send(DbResult::StarToggled { article_id, new_value });
+        self.spawn_db_task(async move {
+            match db.get_queued_articles().await {
+                Ok(articles) => {
+                    let _ = tx.send(DbResult::QueueLoaded(articles));
+                }
+                Err(e) => send_db_error(&tx, "load articles", e),
+            }
+        });
+        // Don't clear articles immediately - keep showing current articles until new ones arrive
+    }
+
+    /// Start an async load of articles published within the recent window.
+    fn start_load_recent_articles(&mut self) {
+        let db = self.db.clone();
+        let tx = self.db_result_tx.clone();
+        let dedup = self.config.display.dedup_by_url;
+        let hours = i64::try_from(self.config.display.recent_window_hours).unwrap_or(i64::MAX);
+        let since = Utc::now() - chrono::Duration::try_hours(hours).unwrap_or(chrono::Duration::MAX);
+        self.spawn_db_task(async move {
+            match db.get_recent_articles(since).await {
+                Ok(articles) => {
+                    let articles = if dedup { db::dedup_by_url(articles) } else { articles };
+                    let _ = tx.send(DbResult::RecentLoaded(articles));
+                }
+                Err(e) => send_db_error(&tx, "load articles", e),
+            }
+        });
+        // Don't clear articles immediately - keep showing current articles until new ones arrive
+    }
+
+    /// Start an async write of an article's read state. With
+    /// `display.dedup_by_url`, copies sharing its URL follow, so a merged
+    /// row never shows a state the toggle can't change.
+    fn start_set_read(&mut self, article_id: i64, new_value: bool) {
+        let db = self.db.clone();
+        let tx = self.db_result_tx.clone();
+        let same_url = self.config.display.dedup_by_url;
+        self.spawn_db_task(async move {
+            match db.set_read(article_id, new_value, same_url).await {
+                Ok(changed_feed_ids) => {
+                    let _ = tx.send(DbResult::ReadToggled { article_id, new_value, changed_feed_ids });
+                }
+                Err(e) => send_db_error(&tx, "save the read state", e),
+            }
+        });
+    }
+
+    /// Start an async reload of the unread same-URL copies behind the
+    /// deduplicated unread counts.
+    fn start_load_unread_duplicates(&mut self) {
+        let db = self.db.clone();
+        let tx = self.db_result_tx.clone();
+        self.spawn_db_task(async move {
+            match db.unread_url_duplicates().await {
+                Ok(duplicates) => {
+                    let _ = tx.send(DbResult::UnreadDuplicatesLoaded(duplicates));
                 }
-                Err(_) => {}
+                Err(e) => send_db_error(&tx, "count unread articles", e),
+            }
+        });
+    }
+
+    /// Start an async recount of one feed's unread articles.
+    fn start_update_unread_count(&mut self, feed_id: i64) {
+        let db = self.db.clone();
+        let tx = self.db_result_tx.clone();
+        self.spawn_db_task(async move {
+            match db.unread_count_for_feed(feed_id).await {
+                Ok(count) => {
+                    let _ = tx.send(DbResult::UnreadCountUpdated { feed_id, count });
+                }
+                Err(e) => send_db_error(&tx, "count unread articles", e),
+            }
+        });
+    }
+
+    /// Start an async write of an article's starred state; see
+    /// [`App::start_set_read`].
+    fn start_set_starred(&mut self, article_id: i64, new_value: bool) {
+        let db = self.db.clone();
+        let tx = self.db_result_tx.clone();
+        let same_url = self.config.display.dedup_by_url;
+        self.spawn_db_task(async move {
+            match db.set_starred(article_id, new_value, same_url).await {
+                Ok(changed_feed_ids) => {
+                    let _ = tx.send(DbResult::StarToggled { article_id, new_value, changed_feed_ids });
+                }
+                Err(e) => send_db_error(&tx, "save the star", e),
+            }
+        });
+    }
+
+    /// Start an async write of an article's place in the "read later" queue;
+    /// see [`App::start_set_read`].
+    fn start_set_queued(&mut self, article_id: i64, new_value: bool) {
+        let db = self.db.clone();
+        let tx = self.db_result_tx.clone();
+        let same_url = self.config.display.dedup_by_url;
+        self.spawn_db_task(async move {
+            match db.set_queued(article_id, new_value, same_url).await {
+                Ok(changed_feed_ids) => {
+                    let _ = tx.send(DbResult::QueueToggled { article_id, new_value, changed_feed_ids });
+                }
+                Err(e) => send_db_error(&tx, "save the queue", e),
             }
         });
     }
@@ -1128,33 +2249,62 @@ impl App {
     fn start_mark_all_read(&mut self, feed_id: i64) {
         let db = self.db.clone();
         let tx = self.db_result_tx.clone();
-        tokio::spawn(async move {
+        self.spawn_db_task(async move {
             match db.mark_all_read(feed_id).await {
                 Ok(_) => {
                     let _ = tx.send(DbResult::MarkedRead { feed_id: Some(feed_id) });
                 }
-                Err(_) => {}
+                Err(e) => send_db_error(&tx, "mark articles read", e),
             }
         });
     }
 
+    /// Mark every article read, first asking for confirmation unless
+    /// `display.confirm_mark_all_read` is off or nothing is unread.
+    fn confirm_mark_all_read_all(&mut self) {
+        let unread = self.total_unread();
+        if !self.config.display.confirm_mark_all_read || unread == 0 {
+            self.start_mark_all_read_all();
+            return;
+        }
+        self.popup = Some(crate::ui::popup::Popup::confirmation(
+            format!("Mark ALL {} articles across every feed as read?", unread),
+            ConfirmAction::MarkAllReadAll,
+        ));
+    }
+
     /// Start an async mark all read operation for all feeds.
     fn start_mark_all_read_all(&mut self) {
         let db = self.db.clone();
         let tx = self.db_result_tx.clone();
-        tokio::spawn(async move {
+        self.spawn_db_task(async move {
             match db.mark_all_read_all().await {
                 Ok(_) => {
                     let _ = tx.send(DbResult::MarkedRead { feed_id: None });
                 }
-                Err(_) => {}
+                Err(e) => send_db_error(&tx, "mark articles read", e),
+            }
+        });
+    }
+
+    /// Start an async mark read operation for a batch of articles.
+    fn start_mark_read_by_ids(&mut self, ids: Vec<i64>) {
+        let db = self.db.clone();
+        let tx = self.db_result_tx.clone();
+        let same_url = self.config.display.dedup_by_url;
+        self.spawn_db_task(async move {
+            match db.mark_read_by_ids(ids, same_url).await {
+                Ok(_) => {
+                    let _ = tx.send(DbResult::MarkedRead { feed_id: None });
+                }
+                Err(e) => send_db_error(&tx, "mark articles read", e),
             }
         });
     }
 
     /// Start an async mark all read operation for a group (direct feeds only).
     fn start_mark_all_read_for_group(&mut self, group_title: String) {
-        // Find all feeds in this group and mark each as read
+        // Find the feeds in this group for the status message
         let feed_ids: Vec<i64> = self.feeds
             .iter()
             .filter(|f| f.group_title == group_title)
@@ -1170,14 +2320,12 @@ impl App {
         self.status_message = Some(format!("Marked {} feed(s) in '{}' as read: {}",
             matched_titles.len(), group_title, matched_titles.join(", ")));
 
-        for feed_id in feed_ids {
-            self.start_mark_all_read(feed_id);
-        }
+        self.spawn_mark_all_read_for_group(group_title, false);
     }
 
     /// Start an async mark all read operation for a group and all nested groups recursively.
     fn start_mark_all_read_for_group_recursive(&mut self, group_path: String) {
-        // Find all feeds in this group or any nested group and mark each as read
+        // Find the feeds in this group or any nested group for the status message
         let feed_ids: Vec<i64> = self.feeds
             .iter()
             .filter(|f| {
@@ -1201,51 +2349,243 @@ impl App {
         self.status_message = Some(format!("Marked {} feed(s) in '{}' and subgroups as read: {}",
             matched_titles.len(), group_path, matched_titles.join(", ")));
 
-        for feed_id in feed_ids {
-            self.start_mark_all_read(feed_id);
+        self.spawn_mark_all_read_for_group(group_path, true);
+    }
+
+    /// Mark a whole group read with a single DB call, then reload once.
+    fn spawn_mark_all_read_for_group(&mut self, group_title: String, recursive: bool) {
+        let db = self.db.clone();
+        let tx = self.db_result_tx.clone();
+        self.spawn_db_task(async move {
+            match db.mark_all_read_for_group(group_title, recursive).await {
+                Ok(()) => {
+                    let _ = tx.send(DbResult::MarkedRead { feed_id: None });
+                }
+                Err(e) => send_db_error(&tx, "mark articles read", e),
+            }
+        });
+    }
+
+    /// Download the selected article's first image for inline display.
+    ///
+    /// Only runs when `display.inline_images` is enabled and the terminal
+    /// supports a graphics protocol; otherwise (or if the download fails)
+    /// the article keeps its text placeholders.
+    fn start_load_hero_image(&mut self) {
+        if !self.config.display.inline_images {
+            return;
+        }
+        let Some(picker) = self.image_picker.clone() else {
+            return;
+        };
+        let Some(article) = self.selected_article() else {
+            return;
+        };
+        let Some(image) = self.article_images.first() else {
+            return;
+        };
+        // Re-rendering the same article keeps the image already loaded
+        if self.hero_image.as_ref().is_some_and(|h| h.article_id == article.id) {
+            return;
+        }
+
+        let article_id = article.id;
+        let src = image.src.clone();
+        let base = article.url.clone();
+        let tx = self.net_result_tx.clone();
+
+        tokio::spawn(async move {
+            let result = match feed::fetch_image(&src, base.as_deref()).await {
+                Ok(data) => match HeroImage::decode(article_id, &data, &picker) {
+                    Some(image) => NetResult::HeroImageLoaded { article_id, image: Some(image) },
+                    None => NetResult::HeroImageUnsupported { article_id },
+                },
+                Err(_) => NetResult::HeroImageLoaded { article_id, image: None },
+            };
+            let _ = tx.send(result);
+        });
+    }
+
+    /// Replace the selected article's content with the text of its web page,
+    /// for feeds with `fetch_full_content`.
+    ///
+    /// Each article is fetched at most once per session, and not at all once
+    /// its full text is stored.
+    fn start_fetch_full_content(&mut self) {
+        let Some(article) = self.selected_article() else {
+            return;
+        };
+        if article.has_full_content
+            || self.full_content_requested.contains(&article.id)
+            || !self.fetches_full_content(article.feed_id)
+        {
+            return;
+        }
+        let Some(url) = article.url.clone() else {
+            return;
+        };
+        self.spawn_fetch_full_content(article.id, url);
+    }
+
+    /// Handle `FetchFullArticle`: fetch the selected article's web page on
+    /// request, whatever the feed's `fetch_full_content` setting.
+    fn fetch_full_article(&mut self) {
+        let Some(article) = self.selected_article() else {
+            return;
+        };
+        if self.full_content_in_flight.contains(&article.id) {
+            self.status_message = Some(FETCHING_FULL_ARTICLE.to_string());
+            return;
         }
+        let Some(url) = article.url.clone() else {
+            self.status_message = Some("Article has no link".to_string());
+            return;
+        };
+        self.spawn_fetch_full_content(article.id, url);
+        self.status_message = Some(FETCHING_FULL_ARTICLE.to_string());
+    }
+
+    /// Fetch `url` in the background and store the extracted text for
+    /// `article_id`, answering with [`NetResult::FullContentFetched`].
+    fn spawn_fetch_full_content(&mut self, article_id: i64, url: String) {
+        self.full_content_requested.insert(article_id);
+        self.full_content_in_flight.insert(article_id);
+        let db = self.db.clone();
+        let tx = self.db_result_tx.clone();
+        let net_tx = self.net_result_tx.clone();
+
+        tokio::spawn(async move {
+            let result = feed::fetch_full_content(&url).await;
+            if let Ok(content) = &result
+                && let Err(e) = db.update_article_content(article_id, content.clone()).await
+            {
+                send_db_error(&tx, "save the full article", e);
+            }
+            let _ = net_tx.send(NetResult::FullContentFetched { article_id, result });
+        });
+    }
+
+    /// Whether the feed has `fetch_full_content` set in the config.
+    fn fetches_full_content(&self, feed_id: i64) -> bool {
+        let Some(feed) = self.feeds.iter().find(|f| f.id == feed_id) else {
+            return false;
+        };
+        self.config.feeds.iter()
+            .flat_map(|item| item.collect_feeds())
+            .any(|(_, source)| source.fetch_full_content && source.feed_url() == feed.url)
     }
 
     /// Start an async render of the current article's content.
     fn start_render_article_content(&mut self) {
+        self.switch_article_scroll(self.selected_article().map(|a| a.id));
+
+        // A different article's hero image must not linger
+        if self.hero_image.as_ref().map(|h| h.article_id) != self.selected_article().map(|a| a.id) {
+            self.hero_image = None;
+        }
+
         let idx = match self.articles_state.selected() {
             Some(i) if i < self.articles.len() => i,
             _ => {
                 self.article_content.clear();
-        self.article_content_lines = 0;
+                self.article_code_lines.clear();
+                self.article_images.clear();
+                self.article_content_lines = 0;
+                self.opened_article_id = None;
                 return;
             }
         };
 
-        let article = match self.articles.get(idx) {
-            Some(a) => a,
-            None => return,
+        let article_id = self.articles[idx].id;
+        let source_hash = self.render_source_hash(&self.articles[idx]);
+        self.render_generation += 1;
+
+        if let Some(cached) = self.render_cache.get(article_id, source_hash).cloned() {
+            self.show_render(cached);
+        } else {
+            self.spawn_render(idx, Some(self.render_generation));
+            self.article_content.clear();
+            self.article_code_lines.clear();
+            self.article_images.clear();
+            self.article_content_lines = 0;
+            self.opened_article_id = None;
+        }
+
+        // Prefetch the neighbours so the next j/k lands instantly
+        for neighbour in [idx.checked_sub(1), Some(idx + 1)].into_iter().flatten() {
+            if self.articles.get(neighbour).is_some_and(|a| !self.render_cache.contains(a.id, self.render_source_hash(a))) {
+                self.spawn_render(neighbour, None);
+            }
+        }
+    }
+
+    /// Keep `article_scroll` with the article it belongs to: remember where
+    /// the outgoing article was left and restore the incoming one's offset.
+    fn switch_article_scroll(&mut self, article_id: Option<i64>) {
+        if self.scroll_article_id == article_id {
+            return;
+        }
+        if let Some(old_id) = self.scroll_article_id {
+            if self.article_scroll > 0 {
+                self.article_scrolls.insert(old_id, self.article_scroll);
+            } else {
+                self.article_scrolls.remove(&old_id);
+            }
+        }
+        self.article_scroll = article_id
+            .and_then(|id| self.article_scrolls.get(&id).copied())
+            .unwrap_or(0);
+        self.scroll_article_id = article_id;
+    }
+
+    /// Hash of everything an article's render depends on.
+    fn render_source_hash(&self, article: &db::Article) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        (&article.title, &article.url, &article.author, &article.published).hash(&mut hasher);
+        (&article.content, &article.summary, &article.enclosure_url).hash(&mut hasher);
+        // The header shows the feed's group path too, which moves and renames change
+        self.feeds.iter().find(|f| f.id == article.feed_id).map(feed_breadcrumb).hash(&mut hasher);
+        (&self.config.display.format.date_detail, self.config.display.format.time).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Render the article at `idx` on a blocking thread, answering request
+    /// `generation` (or `None` for a prefetch).
+    fn spawn_render(&self, idx: usize, generation: Option<u64>) {
+        let Some(article) = self.articles.get(idx) else {
+            return;
         };
 
+        let article_id = article.id;
+        let source_hash = self.render_source_hash(article);
         let html = article.content
             .as_deref()
             .or(article.summary.as_deref())
-            .unwrap_or("(No content available)")
-            .to_string();
+            .map(str::to_string);
+        let url = article.url.clone();
+        let open_key = action::format_bindings(&self.config.keybindings.global.open_browser);
 
         let title = article.title.clone();
         let author = article.author.clone();
-        let (date_format, strip_day_zero) = to_strftime_format(&self.config.display.format.date_detail);
-        let published = article.published
-            .as_ref()
-            .map(|d| {
-                let formatted = d.format(&date_format).to_string();
-                if strip_day_zero {
-                    strip_day_leading_zero(&formatted)
-                } else {
-                    formatted
-                }
-            });
+        let has_enclosure = article.enclosure_url.is_some();
+        let (date_format, strip_day_zero) = to_strftime_format(&self.config.display.format.date_detail, self.config.display.format.time);
+        // Append the time unless `date_detail` already has one
+        let time_format = ["%H", "%-H", "%I", "%-I", "%M"]
+            .iter()
+            .all(|spec| !date_format.contains(spec))
+            .then(|| time_strftime_format(self.config.display.format.time));
+        let published = article.published.map(|d| {
+            let date = format_local_date(d, &date_format, strip_day_zero);
+            match time_format {
+                Some(time_format) => format!("{} {}", date, d.with_timezone(&chrono::Local).format(time_format)),
+                None => date,
+            }
+        });
 
-        // Look up feed name
+        // Look up the feed with its group path
         let feed_name = self.feeds.iter()
             .find(|f| f.id == article.feed_id)
-            .map(|f| f.title.clone());
+            .map(feed_breadcrumb);
 
         let tx = self.render_tx.clone();
 
@@ -1264,17 +2604,30 @@ impl App {
             if let Some(ref published) = published {
                 content.push_str(&format!("{published}\n"));
             }
+            if has_enclosure {
+                content.push_str("\u{1F3A7} enclosure\n");
+            }
             content.push_str("\n──────────\n\n");
 
-            // Convert HTML to plain text
-            let body = html2text::from_read(html.as_bytes(), 80);
+            // Convert HTML to plain text; plain-text/Markdown content is shown
+            // as-is and link-only entries point at the browser
+            let header_lines = content.lines().count();
+            let (body, code_lines, images) = match html {
+                Some(html) if crate::render::is_probably_html(&html) => {
+                    let rendered = crate::render::render_html(&html, 80);
+                    let code_lines = rendered.code_lines
+                        .into_iter()
+                        .map(|r| r.start + header_lines..r.end + header_lines)
+                        .collect();
+                    (rendered.text, code_lines, rendered.images)
+                }
+                Some(text) => (crate::render::render_plain_text(&text, 80), Vec::new(), Vec::new()),
+                None => (no_content_body(url.as_deref(), &open_key), Vec::new(), Vec::new()),
+            };
             content.push_str(&body);
 
-            let _ = tx.send(RenderResult { content });
+            let _ = tx.send(RenderResult { article_id, generation, source_hash, content, code_lines, images });
         });
-
-        self.article_content.clear();
-        self.article_content_lines = 0;
     }
 
     // ---------------------------------------------------------------------
@@ -1288,13 +2641,11 @@ impl App {
     fn build_feed_list_items(&mut self) {
         let old_selection = self.feeds_state.selected();
 
-        // Remember what was selected before rebuilding.
-        let old_was_all = old_selection.and_then(|idx| {
-            self.feed_list_items.get(idx).and_then(|item| match item {
-                FeedListItem::All { .. } => Some(true),
-                _ => None,
-            })
-        }).unwrap_or(false);
+        // Remember what was selected before rebuilding. The built-in entries
+        // always sit at the same index at the top of the list.
+        let old_virtual_idx = old_selection.filter(|&idx| {
+            self.feed_list_items.get(idx).is_some_and(|item| item.virtual_name().is_some())
+        });
 
         let old_selected_feed_id = old_selection.and_then(|idx| {
             self.feed_list_items.get(idx).and_then(|item| match item {
@@ -1313,23 +2664,34 @@ impl App {
 
         self.feed_list_items.clear();
 
-        // Calculate total unread count for "All"
-        let total_unread: u32 = self.feeds.iter().map(|f| f.unread_count).sum();
+        // Calculate total unread and article counts for "All"
+        let total_unread = self.total_unread();
+        let total_count: u32 = self.feeds.iter().map(|f| f.total_count).sum();
 
-        // Add "All" at the top.
-        self.feed_list_items.push(FeedListItem::All { unread_count: total_unread });
+        // Add "All", "Starred", "Queue" and "Recent" at the top.
+        self.feed_list_items.push(FeedListItem::All { unread_count: total_unread, total_count });
+        self.feed_list_items.push(FeedListItem::Starred { count: self.starred_count });
+        self.feed_list_items.push(FeedListItem::Queue { count: self.queued_count });
+        self.feed_list_items.push(FeedListItem::Recent { hours: self.config.display.recent_window_hours });
 
         // Separate standalone feeds (empty group_title) from grouped feeds
-        let standalone_feeds: Vec<_> = self.feeds.iter()
+        let mut standalone_feeds: Vec<_> = self.feeds.iter()
             .filter(|f| f.group_title.is_empty())
             .cloned()
             .collect();
 
-        let grouped_feeds: Vec<_> = self.feeds.iter()
+        let mut grouped_feeds: Vec<_> = self.feeds.iter()
             .filter(|f| !f.group_title.is_empty())
             .cloned()
             .collect();
 
+        // Feeds come back in config order; optionally sort them by title
+        // (the sort is stable, so groups keep their own order)
+        if self.config.display.sort_feeds {
+            standalone_feeds.sort_by_cached_key(|f| title_sort_key(&f.title));
+            grouped_feeds.sort_by_cached_key(|f| title_sort_key(&f.title));
+        }
+
         // Add standalone feeds first (no header, no indent)
         for feed in standalone_feeds {
             self.feed_list_items.push(FeedListItem::Feed {
@@ -1339,7 +2701,8 @@ impl App {
         }
 
         // Build tree from grouped feeds and empty groups
-        let tree = build_group_tree(&grouped_feeds, &self.empty_groups);
+        let group_order = crate::config::collect_group_paths_from_config(&self.config);
+        let tree = build_group_tree(&grouped_feeds, &self.empty_groups, &group_order);
 
         // Recursively add tree items
         for node in tree {
@@ -1349,9 +2712,9 @@ impl App {
         // Attempt to restore the selection to the same item.
         let mut restored = false;
 
-        // Restore "All" selection.
-        if old_was_all {
-            self.feeds_state.select(Some(0));
+        // Restore "All"/"Starred"/"Queue"/"Recent" selection.
+        if let Some(idx) = old_virtual_idx {
+            self.feeds_state.select(Some(idx));
             restored = true;
         }
 
@@ -1395,11 +2758,20 @@ impl App {
         let is_collapsed = self.collapsed_groups.contains(&node.full_path);
         let actually_collapsed = parent_collapsed || is_collapsed;
 
+        // Same-URL copies within the group count once, as they are listed
+        let nested_prefix = format!("{} > ", node.full_path);
+        let hidden = hidden_unread_duplicates(&self.unread_duplicates, |feed_id| {
+            self.feeds.iter().any(|f| {
+                f.id == feed_id && (f.group_title == node.full_path || f.group_title.starts_with(&nested_prefix))
+            })
+        });
+
         self.feed_list_items.push(FeedListItem::GroupHeader {
             title: node.title.clone(),
             full_path: node.full_path.clone(),
             collapsed: is_collapsed,
-            unread_count: node.unread_count,
+            unread_count: node.unread_count.saturating_sub(hidden),
+            total_count: node.total_count,
             depth,
         });
 
@@ -1431,19 +2803,7 @@ impl App {
         let current = self.feeds_state.selected().unwrap_or(0);
         let len = self.feed_list_items.len();
 
-        // Circular scrolling: wrap around using modulo
-        let new_idx = if delta >= 0 {
-            (current + delta as usize) % len
-        } else {
-            let abs_delta = (-delta) as usize;
-            // Handle wrap-around for negative delta
-            let offset = abs_delta % len;
-            if offset <= current {
-                current - offset
-            } else {
-                len - (offset - current)
-            }
-        };
+        let new_idx = step_index(current, delta, len, self.config.display.wrap_navigation);
 
         self.feeds_state.select(Some(new_idx));
         self.load_articles_for_selection_at(new_idx);
@@ -1455,6 +2815,15 @@ impl App {
             Some(FeedListItem::All { .. }) => {
                 self.start_load_all_articles();
             }
+            Some(FeedListItem::Starred { .. }) => {
+                self.start_load_starred_articles();
+            }
+            Some(FeedListItem::Queue { .. }) => {
+                self.start_load_queued_articles();
+            }
+            Some(FeedListItem::Recent { .. }) => {
+                self.start_load_recent_articles();
+            }
             Some(FeedListItem::GroupHeader { full_path, .. }) => {
                 let group_path = full_path.clone();
                 self.start_load_articles_for_group(group_path);
@@ -1475,47 +2844,202 @@ impl App {
         self.load_articles_for_selection_at(idx);
     }
 
+    /// Articles moved by a half-page scroll: rows of multi-line entries
+    /// (wrapped titles, previews) rather than single lines.
+    fn half_page_articles(&self) -> i32 {
+        (half_page(self.articles_pane_height) / self.articles_row_height.max(1) as i32).max(1)
+    }
+
     /// Move the article list selection by `delta` (+1 = down, -1 = up).
     fn move_article_selection(&mut self, delta: i32) {
         if self.articles.is_empty() {
             return;
         }
+        // Start from the selected article's current row, in case the list
+        // shifted since it was selected
+        self.sync_article_selection();
         let current = self.articles_state.selected().unwrap_or(0);
         let len = self.articles.len();
 
-        // Circular scrolling: wrap around using modulo
-        let new_idx = if delta >= 0 {
-            (current + delta as usize) % len
-        } else {
-            let abs_delta = (-delta) as usize;
-            // Handle wrap-around for negative delta
-            let offset = abs_delta % len;
-            if offset <= current {
-                current - offset
-            } else {
-                len - (offset - current)
-            }
-        };
+        let new_idx = step_index(current, delta, len, self.config.display.wrap_navigation);
 
+        self.select_article_at(new_idx);
+    }
+
+    /// Select the article at `idx`, marking it as read if the selection is
+    /// actually changing, and start rendering its content.
+    fn select_article_at(&mut self, idx: usize) {
         // Mark the new article as read if the selection is actually changing
-        let should_mark_read = new_idx != current;
+        let should_mark_read = self.articles_state.selected() != Some(idx);
 
-        self.articles_state.select(Some(new_idx));
+        self.articles_state.select(Some(idx));
 
         // Update selected_article_id
-        if let Some(article) = self.articles.get(new_idx) {
+        if let Some(article) = self.articles.get(idx) {
             self.selected_article_id = Some(article.id);
         }
 
         if should_mark_read {
-            if let Some(article) = self.articles.get(new_idx) {
-                if !article.is_read {
-                    self.start_toggle_read(article.id);
+            self.mark_read_on_select(idx);
+        }
+
+        self.start_render_article_content();
+    }
+
+    /// Jump to the next unread article after the current selection.
+    ///
+    /// When the current list has no more unread articles, moves on to the
+    /// next feed (or collapsed group) with unread articles instead.
+    fn goto_next_unread(&mut self) {
+        let start = self.articles_state.selected().map(|i| i + 1).unwrap_or(0);
+        let next = self.articles
+            .iter()
+            .enumerate()
+            .skip(start)
+            .find(|(_, a)| !a.is_read)
+            .map(|(idx, _)| idx);
+
+        match next {
+            Some(idx) => self.select_article_at(idx),
+            None => self.goto_next_unread_feed(),
+        }
+    }
+
+    /// Select the next feed in the feeds pane that has unread articles and
+    /// land on its first unread article once its articles have loaded.
+    ///
+    /// Only feeds after the current selection are considered. A group header
+    /// qualifies when it is collapsed (its feeds are hidden) and its direct
+    /// feeds have unread articles, since that is what selecting it loads.
+    pub fn goto_next_unread_feed(&mut self) {
+        let start = self.feeds_state.selected().map(|i| i + 1).unwrap_or(0);
+        let target = self.feed_list_items
+            .iter()
+            .enumerate()
+            .skip(start)
+            .find(|(_, item)| match item {
+                FeedListItem::Feed { feed, .. } => feed.unread_count > 0,
+                FeedListItem::GroupHeader { full_path, collapsed: true, .. } => {
+                    self.feeds
+                        .iter()
+                        .filter(|f| f.group_title == *full_path)
+                        .any(|f| f.unread_count > 0)
                 }
+                _ => false,
+            })
+            .map(|(idx, _)| idx);
+
+        let Some(idx) = target else {
+            self.status_message = Some("No more unread articles".to_string());
+            return;
+        };
+
+        self.feeds_state.select(Some(idx));
+        self.pending_first_unread = true;
+        self.load_articles_for_selection_at(idx);
+    }
+
+    /// Swap in a freshly loaded article list, keeping the cursor on the
+    /// same article when it is still listed.
+    ///
+    /// If the selected article disappeared (e.g. filtered out), the next
+    /// surviving article below it is selected instead of jumping to the top.
+    fn replace_articles(&mut self, articles: Vec<db::Article>) {
+        let prev_selected = self.articles_state.selected();
+        let prev_selected_id = prev_selected
+            .and_then(|idx| self.articles.get(idx))
+            .map(|a| a.id);
+
+        let old_ids: Vec<i64> = self.articles.iter().map(|a| a.id).collect();
+        self.articles = articles;
+
+        let source = self.feeds_state.selected()
+            .and_then(|idx| self.feed_list_items.get(idx))
+            .map(FeedListItem::source_key);
+        let same_source = source.is_some() && source == self.articles_source;
+        self.articles_source = source;
+
+        let listed: HashSet<i64> = self.articles.iter().map(|a| a.id).collect();
+        self.article_scrolls.retain(|id, _| listed.contains(id));
+
+        // Try to restore the previous selection
+        let restored_idx = restored_article_index(&old_ids, &self.articles, prev_selected, same_source);
+
+        if let Some(idx) = self.take_pending_restore_article() {
+            // Reopening where the last run left off: don't count as reading
+            self.articles_state.select(Some(idx));
+            self.selected_article_id = Some(self.articles[idx].id);
+            self.start_render_article_content();
+        } else if let Some(idx) = self.take_pending_first_unread() {
+            // Landed here via "next unread": skip straight to it
+            self.select_article_at(idx);
+        } else if self.articles.is_empty() {
+            self.articles_state.select(None);
+            self.selected_article_id = None;
+        } else if let Some(idx) = restored_idx.filter(|&idx| Some(self.articles[idx].id) == prev_selected_id) {
+            self.articles_state.select(Some(idx));
+            self.selected_article_id = prev_selected_id;
+            // Always render when restoring selection
+            self.start_render_article_content();
+        } else {
+            // Previous article not found, another source, or first load:
+            // select its nearest surviving neighbour, or the first article
+            let idx = restored_idx.unwrap_or(0);
+            self.articles_state.select(Some(idx));
+            let new_article_id = self.articles.get(idx).map(|a| a.id);
+            // Render if this is a new feed (prev_selected_id was None) or different article
+            if prev_selected_id.is_none() || prev_selected_id != new_article_id {
+                self.selected_article_id = new_article_id;
+                self.mark_read_on_select(idx);
+                self.start_render_article_content();
             }
         }
+    }
 
-        self.start_render_article_content();
+    /// Select the feed or group remembered from the last run, if it is
+    /// still listed. Only applies to the first feed load.
+    fn restore_pending_source(&mut self) {
+        let Some(source) = self.pending_restore_source.take() else {
+            return;
+        };
+
+        let idx = self.feed_list_items.iter().position(|item| match (item, &source) {
+            (FeedListItem::Feed { feed, .. }, SelectedSource::Feed(url)) => feed.url == *url,
+            (FeedListItem::GroupHeader { full_path, .. }, SelectedSource::Group(path)) => full_path == path,
+            _ => false,
+        });
+
+        // Missing feeds/groups fall back to the default ("All")
+        if let Some(idx) = idx {
+            self.feeds_state.select(Some(idx));
+        }
+    }
+
+    /// Position of the article remembered from the last run in the current
+    /// list. Only applies to the first article load.
+    fn take_pending_restore_article(&mut self) -> Option<usize> {
+        let guid = self.pending_restore_article.take()?;
+        self.articles.iter().position(|a| a.guid == guid)
+    }
+
+    /// Re-point `articles_state` at `selected_article_id` if the article
+    /// moved within the list (e.g. after a read toggle under a filter).
+    fn sync_article_selection(&mut self) {
+        let Some(id) = self.selected_article_id else {
+            return;
+        };
+        if let Some(idx) = self.articles.iter().position(|a| a.id == id) {
+            self.articles_state.select(Some(idx));
+        }
+    }
+
+    /// Consume the "next unread" pending flag, returning the index of the
+    /// first unread article in the freshly loaded list (if any).
+    fn take_pending_first_unread(&mut self) -> Option<usize> {
+        if !std::mem::take(&mut self.pending_first_unread) {
+            return None;
+        }
+        self.articles.iter().position(|a| !a.is_read)
     }
 
     /// Handle `Select` in the feeds pane.
@@ -1532,7 +3056,7 @@ impl App {
         };
 
         match item {
-            FeedListItem::All { .. } => {
+            FeedListItem::All { .. } | FeedListItem::Starred { .. } | FeedListItem::Queue { .. } | FeedListItem::Recent { .. } => {
                 // Articles already loaded by navigation, just switch focus.
                 self.active_pane = ActivePane::Articles;
             }
@@ -1547,6 +3071,54 @@ impl App {
         }
     }
 
+    /// The read policy in effect for the current article list.
+    ///
+    /// A single feed's own `mark_read_on` wins over `display.mark_read_on`;
+    /// group and "All" views always use the global setting.
+    fn mark_read_policy(&self) -> MarkReadOn {
+        let feed_policy = self
+            .selected_feed()
+            .and_then(|f| f.mark_read_on.as_deref())
+            .and_then(|value| parse_mark_read_on(value).ok());
+
+        feed_policy
+            .or_else(|| parse_mark_read_on(&self.config.display.mark_read_on).ok())
+            .unwrap_or(MarkReadOn::Select)
+    }
+
+    /// Mark the article at `idx` read because the cursor moved onto it,
+    /// if the read policy is "select".
+    fn mark_read_on_select(&mut self, idx: usize) {
+        if self.mark_read_policy() != MarkReadOn::Select {
+            return;
+        }
+        if let Some(article) = self.articles.get(idx).filter(|a| !a.is_read) {
+            let article_id = article.id;
+            self.start_set_read(article_id, true);
+        }
+    }
+
+    /// Largest article-view scroll offset that still fills the view.
+    fn max_article_scroll(&self) -> u16 {
+        max_scroll(self.article_content_lines, self.article_view_height)
+    }
+
+    /// Mark the selected article read once the article view has been
+    /// scrolled past halfway (or as far as it goes), if the read policy is
+    /// "scroll".
+    fn mark_read_on_scroll(&mut self) {
+        if self.mark_read_policy() != MarkReadOn::Scroll {
+            return;
+        }
+        if !scrolled_to_read(self.article_scroll, self.article_content_lines, self.article_view_height) {
+            return;
+        }
+        if let Some(article) = self.selected_article().filter(|a| !a.is_read) {
+            let article_id = article.id;
+            self.start_set_read(article_id, true);
+        }
+    }
+
     /// Handle `Select` in the articles pane.
     ///
     /// Marks the article as read and switches focus to the article view.
@@ -1563,7 +3135,7 @@ impl App {
 
         // Mark the article as read if it is not already.
         if !article.is_read {
-            self.start_toggle_read(article_id);
+            self.start_set_read(article_id, true);
         }
 
         // Content is already loaded by navigation, just switch focus.
@@ -1578,12 +3150,14 @@ impl App {
         } else {
             self.collapsed_groups.insert(group_title.to_string());
         }
+        self.save_ui_state();
         self.build_feed_list_items();
     }
 
     /// Expand all groups by clearing the collapsed_groups set.
     fn expand_all_groups(&mut self) {
         self.collapsed_groups.clear();
+        self.save_ui_state();
         self.build_feed_list_items();
     }
 
@@ -1595,6 +3169,7 @@ impl App {
             all_groups.insert(feed.group_title.clone());
         }
         self.collapsed_groups = all_groups;
+        self.save_ui_state();
         self.build_feed_list_items();
     }
 
@@ -1614,9 +3189,34 @@ impl App {
             // Some or all are collapsed → expand all
             self.collapsed_groups.clear();
         }
+        self.save_ui_state();
         self.build_feed_list_items();
     }
 
+    /// Persist collapsed groups and the current selection so they survive
+    /// a restart.
+    fn save_ui_state(&mut self) {
+        let mut collapsed_groups: Vec<String> = self.collapsed_groups.iter().cloned().collect();
+        collapsed_groups.sort();
+
+        let selected_source = self.feeds_state.selected()
+            .and_then(|idx| self.feed_list_items.get(idx))
+            .and_then(|item| match item {
+                FeedListItem::All { .. } | FeedListItem::Starred { .. } | FeedListItem::Queue { .. } | FeedListItem::Recent { .. } => None,
+                FeedListItem::Feed { feed, .. } => Some(SelectedSource::Feed(feed.url.clone())),
+                FeedListItem::GroupHeader { full_path, .. } => Some(SelectedSource::Group(full_path.clone())),
+            });
+
+        let state = crate::ui_state::UiState {
+            collapsed_groups,
+            selected_source,
+            selected_article_guid: self.selected_article().map(|a| a.guid.clone()),
+        };
+        if let Err(e) = crate::ui_state::save(&state) {
+            self.status_message = Some(format!("Failed to save UI state: {}", e));
+        }
+    }
+
     // ---------------------------------------------------------------------
     // Selection accessors
     // ---------------------------------------------------------------------
@@ -1627,24 +3227,70 @@ impl App {
     pub fn selected_feed(&self) -> Option<&db::Feed> {
         let idx = self.feeds_state.selected()?;
         match self.feed_list_items.get(idx)? {
-            FeedListItem::All { .. } => None,
+            FeedListItem::All { .. } | FeedListItem::Starred { .. } | FeedListItem::Queue { .. } | FeedListItem::Recent { .. } => None,
             FeedListItem::Feed { feed, .. } => Some(feed),
             FeedListItem::GroupHeader { .. } => None,
         }
     }
 
+    /// Describe the current feeds-pane selection for the articles pane title:
+    /// the feed's title, the group's name plus "(group)", or "All Feeds".
+    pub fn current_source_label(&self) -> String {
+        self.feeds_state.selected()
+            .and_then(|idx| self.feed_list_items.get(idx))
+            .map(source_label)
+            .unwrap_or_else(|| "Articles".to_string())
+    }
+
     /// Return a reference to the article at the current articles-list cursor.
     pub fn selected_article(&self) -> Option<&db::Article> {
         let idx = self.articles_state.selected()?;
         self.articles.get(idx)
     }
 
+    /// Look up the title of the feed with the given ID.
+    pub fn feed_title(&self, feed_id: i64) -> Option<&str> {
+        self.feeds
+            .iter()
+            .find(|f| f.id == feed_id)
+            .map(|f| f.title.as_str())
+    }
+
+    /// Total number of unread articles across all feeds.
+    ///
+    /// With `display.dedup_by_url`, same-URL copies count once, as they are
+    /// listed under "All".
+    pub fn total_unread(&self) -> u32 {
+        let unread: u32 = self.feeds.iter().map(|f| f.unread_count).sum();
+        unread.saturating_sub(hidden_unread_duplicates(&self.unread_duplicates, |_| true))
+    }
+
+    /// Number of feeds, and of groups shown in the feeds pane.
+    pub fn feed_and_group_counts(&self) -> (usize, usize) {
+        (self.feeds.len(), count_groups(&self.feeds, &self.empty_groups))
+    }
+
+    /// Most recent `last_fetched` time across all feeds, if any were fetched.
+    pub fn last_fetched(&self) -> Option<DateTime<Utc>> {
+        self.feeds.iter().filter_map(|f| f.last_fetched).max()
+    }
+
     // ---------------------------------------------------------------------
     // Popup handling
     // ---------------------------------------------------------------------
 
     /// Handle character input when popup is active
+    ///
+    /// Confirmation popups answer to `y` and `n`.
     pub fn handle_popup_char(&mut self, c: char) {
+        if self.popup.as_ref().is_some_and(|p| p.confirm_action().is_some()) {
+            match c.to_ascii_lowercase() {
+                'y' => self.handle_popup_enter(),
+                'n' => self.handle_popup_escape(),
+                _ => {}
+            }
+            return;
+        }
         if let Some(ref mut popup) = self.popup {
             popup.handle_char(c);
         }
@@ -1657,9 +3303,79 @@ impl App {
         }
     }
 
+    /// Paste the system clipboard into the active popup field
+    pub fn handle_popup_paste(&mut self) {
+        if self.popup.is_none() {
+            return;
+        }
+        match arboard::Clipboard::new().and_then(|mut clipboard| clipboard.get_text()) {
+            Ok(text) => {
+                if let Some(ref mut popup) = self.popup {
+                    popup.handle_paste(&text);
+                }
+            }
+            Err(e) => self.status_message = Some(format!("Couldn't read the clipboard: {}", e)),
+        }
+    }
+
+    /// Handle Delete when popup is active
+    pub fn handle_popup_delete(&mut self) {
+        if let Some(ref mut popup) = self.popup {
+            popup.handle_delete();
+        }
+    }
+
+    /// Handle Left key when popup is active
+    pub fn handle_popup_left(&mut self) {
+        if let Some(ref mut popup) = self.popup {
+            popup.handle_left();
+        }
+    }
+
+    /// Handle Right key when popup is active
+    pub fn handle_popup_right(&mut self) {
+        if let Some(ref mut popup) = self.popup {
+            popup.handle_right();
+        }
+    }
+
+    /// Handle Home key when popup is active
+    pub fn handle_popup_home(&mut self) {
+        if let Some(ref mut popup) = self.popup {
+            popup.handle_home();
+        }
+    }
+
+    /// Handle End key when popup is active
+    pub fn handle_popup_end(&mut self) {
+        if let Some(ref mut popup) = self.popup {
+            popup.handle_end();
+        }
+    }
+
     /// Handle Enter key when popup is active
     pub fn handle_popup_enter(&mut self) {
         if let Some(popup) = self.popup.take() {
+            if popup.is_command() {
+                let (line, ..) = popup.confirm();
+                self.run_command(&line);
+                return;
+            }
+
+            if let Some(action) = popup.confirm_action() {
+                match action {
+                    ConfirmAction::MarkAllReadAll => self.start_mark_all_read_all(),
+                }
+                return;
+            }
+
+            if popup.is_move_to_group() {
+                if let Some((feed_url, group)) = popup.move_selection() {
+                    self.move_feed_to_group(feed_url, group);
+                }
+                return;
+            }
+
             let is_create_feed = popup.is_create_feed();
             let is_edit_feed = popup.is_edit_feed();
             let is_edit_group = popup.is_edit() && !is_edit_feed; // Edit group but not Edit feed
@@ -1693,6 +3409,41 @@ impl App {
         }
     }
 
+    /// Run a line typed at the `:` prompt.
+    fn run_command(&mut self, line: &str) {
+        if line.trim().is_empty() {
+            return;
+        }
+        match command::parse(line) {
+            Ok(Command::Refresh) => self.update(Action::RefreshAll),
+            Ok(Command::MarkAllRead) => self.update(Action::MarkAllRead),
+            Ok(Command::Quit) => self.update(Action::Quit),
+            Ok(Command::Goto(name)) => match find_source(&self.feed_list_items, &name) {
+                Some(idx) => {
+                    self.feeds_state.select(Some(idx));
+                    self.load_articles_for_selection_at(idx);
+                }
+                None => self.status_message = Some(format!("No feed or group matching '{}'", name)),
+            },
+            Ok(Command::Set { key, value }) => {
+                match command::apply_setting(&mut self.config, &key, &value) {
+                    Ok(()) => {
+                        // Entry labels and article lists may depend on the setting
+                        self.build_feed_list_items();
+                        self.load_articles_for_current_selection();
+                        if key == "show_total_counts" {
+                            // Totals are only counted when the feeds load
+                            self.start_reload_feeds();
+                        }
+                        self.status_message = Some(format!("{} = {}", key, value));
+                    }
+                    Err(err) => self.status_message = Some(err),
+                }
+            }
+            Err(err) => self.status_message = Some(err),
+        }
+    }
+
     /// Handle Tab key when popup is active
     pub fn handle_popup_tab(&mut self) {
         if let Some(ref mut popup) = self.popup {
@@ -1707,6 +3458,20 @@ impl App {
         }
     }
 
+    /// Handle Up key when popup is active
+    pub fn handle_popup_up(&mut self) {
+        if let Some(ref mut popup) = self.popup {
+            popup.handle_up();
+        }
+    }
+
+    /// Handle Down key when popup is active
+    pub fn handle_popup_down(&mut self) {
+        if let Some(ref mut popup) = self.popup {
+            popup.handle_down();
+        }
+    }
+
     /// Handle Escape key when popup is active
     pub fn handle_popup_escape(&mut self) {
         self.popup = None;
@@ -1744,10 +3509,28 @@ impl App {
     }
 
     /// Create a new feed with the given title, URL, and optional feed URL
+    ///
+    /// Without a feed URL, the website URL is fetched first to discover the
+    /// feed it advertises; the feed is added once discovery completes.
     fn create_feed(&mut self, title: String, url: String, feed_url: Option<String>) {
         // Get the parent group path (if a group is selected)
         let parent_group = self.get_selected_group_path();
 
+        if feed_url.is_some() {
+            self.add_feed(title, url, feed_url, parent_group);
+            return;
+        }
+
+        self.status_message = Some(format!("Looking for a feed at {}...", url));
+        let tx = self.net_result_tx.clone();
+        tokio::spawn(async move {
+            let result = feed::discover(&url).await;
+            let _ = tx.send(NetResult::FeedDiscovered { title, url, parent_group, result });
+        });
+    }
+
+    /// Add a feed to the config under `parent_group`, save, and reload.
+    fn add_feed(&mut self, title: String, url: String, feed_url: Option<String>, parent_group: Option<String>) {
         // Add to config
         self.add_feed_to_config(&title, &url, feed_url.as_deref(), parent_group.as_deref());
 
@@ -1779,9 +3562,9 @@ impl App {
             return;
         };
 
-        // Cannot edit "All"
-        if matches!(item, FeedListItem::All { .. }) {
-            self.status_message = Some("Cannot edit 'All'".to_string());
+        // Cannot edit "All" or "Starred"
+        if let Some(name) = item.virtual_name() {
+            self.status_message = Some(format!("Cannot edit '{}'", name));
             return;
         }
 
@@ -1789,12 +3572,16 @@ impl App {
             FeedListItem::Feed { feed, .. } => {
                 // Open edit feed popup with pre-populated values
                 // original_url = feed.url (used to identify the feed)
-                // title = feed.title
+                // title = config title (feed.title may be the feed's own)
                 // url = website URL (from site_url)
                 // feed_url = feed URL (from url field, optional)
+                let title = self.config.feeds.iter()
+                    .flat_map(|item| item.collect_feeds())
+                    .find(|(_, source)| source.feed_url() == feed.url)
+                    .map_or_else(|| feed.title.clone(), |(_, source)| source.title);
                 self.popup = Some(crate::ui::popup::Popup::edit_feed(
                     feed.url.clone(),
-                    feed.title.clone(),
+                    title,
                     feed.site_url.clone().unwrap_or_default(),
                     Some(feed.url.clone()),
                 ));
@@ -1806,12 +3593,79 @@ impl App {
                     title.clone(),
                 ));
             }
-            FeedListItem::All { .. } => {
+            FeedListItem::All { .. } | FeedListItem::Starred { .. } | FeedListItem::Queue { .. } | FeedListItem::Recent { .. } => {
                 // Already handled above
             }
         }
     }
 
+    /// Open the group picker for moving the selected feed
+    fn open_move_to_group_popup(&mut self) {
+        let Some(item) = self.feeds_state.selected().and_then(|idx| self.feed_list_items.get(idx)) else {
+            return;
+        };
+
+        let feed = match item {
+            FeedListItem::Feed { feed, .. } => feed,
+            FeedListItem::GroupHeader { .. } => {
+                self.status_message = Some("Select a feed to move it to another group".to_string());
+                return;
+            }
+            FeedListItem::All { .. } | FeedListItem::Starred { .. } | FeedListItem::Queue { .. } | FeedListItem::Recent { .. } => {
+                self.status_message = Some(format!("Cannot move '{}'", item.virtual_name().unwrap_or_default()));
+                return;
+            }
+        };
+
+        // Every group the feed isn't already in, with "" for the root level
+        let groups: Vec<String> = std::iter::once(String::new())
+            .chain(crate::config::collect_group_paths_from_config(&self.config))
+            .filter(|path| *path != feed.group_title)
+            .collect();
+
+        self.popup = Some(crate::ui::popup::Popup::move_to_group(feed.url.clone(), groups));
+    }
+
+    /// Move a feed into the group at `group_path` (or the root level if None)
+    fn move_feed_to_group(&mut self, feed_url: &str, group_path: Option<&str>) {
+        let Some(title) = Self::relocate_feed(&mut self.config.feeds, feed_url, group_path) else {
+            return;
+        };
+
+        // Save only the feeds section to preserve formatting
+        if let Err(e) = crate::config::save_feeds_only(&self.config.feeds) {
+            self.status_message = Some(format!("Failed to save config: {}", e));
+            return;
+        }
+
+        // Reload feeds from updated config; the selection follows the feed
+        self.reload_feeds_from_config();
+
+        let location = match group_path {
+            Some(group) => format!("'{}'", group),
+            None => "root".to_string(),
+        };
+        self.status_message = Some(format!("Moved '{}' to {}", title, location));
+    }
+
+    /// Move a feed to the end of the group at `group_path` (or the root
+    /// level if None). Returns the feed's title, or `None` if it isn't in
+    /// `feeds`.
+    fn relocate_feed(feeds: &mut Vec<FeedConfigItem>, feed_url: &str, group_path: Option<&str>) -> Option<String> {
+        let feed_source = Self::remove_and_return_feed(feeds, feed_url)?;
+        let title = feed_source.title.clone();
+
+        match group_path {
+            Some(group_path) => {
+                let components: Vec<&str> = group_path.split(" > ").collect();
+                Self::insert_feed_into_group(feeds, &components, 0, feed_source);
+            }
+            None => feeds.push(FeedConfigItem::Standalone(feed_source)),
+        }
+
+        Some(title)
+    }
+
     /// Edit an existing feed with new values
     fn edit_feed(&mut self, original_url: String, new_title: String, new_url: String, new_feed_url: Option<String>) {
         // Update the feed in config
@@ -1836,8 +3690,26 @@ impl App {
 
     /// Edit an existing group title
     fn edit_group(&mut self, original_path: String, new_name: String) {
+        let new_name = new_name.trim();
+        if new_name.contains(" > ") {
+            self.status_message = Some("Group names can't contain ' > '".to_string());
+            return;
+        }
+
+        // A sibling with the new name would silently merge the two groups
+        let new_path = match original_path.rsplit_once(" > ") {
+            Some((parent, _)) => format!("{} > {}", parent, new_name),
+            None => new_name.to_string(),
+        };
+        if new_path != original_path
+            && crate::config::collect_group_paths_from_config(&self.config).contains(&new_path)
+        {
+            self.status_message = Some(format!("A group named '{}' already exists there", new_name));
+            return;
+        }
+
         // Update the group title in config
-        let updated = Self::update_group_in_config(&mut self.config.feeds, &original_path, &new_name);
+        let updated = Self::update_group_in_config(&mut self.config.feeds, &original_path, new_name);
 
         if !updated {
             self.status_message = Some(format!("Group '{}' not found in config", original_path));
@@ -1850,6 +3722,18 @@ impl App {
             return;
         }
 
+        // Nested paths change with the renamed group; carry their collapsed
+        // state and the selection over so the rebuilt list matches
+        self.collapsed_groups = self.collapsed_groups
+            .iter()
+            .map(|path| rename_group_path(path, &original_path, &new_path))
+            .collect();
+        for item in &mut self.feed_list_items {
+            if let FeedListItem::GroupHeader { full_path, .. } = item {
+                *full_path = rename_group_path(full_path, &original_path, &new_path);
+            }
+        }
+
         // Reload feeds from updated config
         self.reload_feeds_from_config();
 
@@ -1865,7 +3749,7 @@ impl App {
     fn get_selected_group_path(&self) -> Option<String> {
         let idx = self.feeds_state.selected()?;
         match self.feed_list_items.get(idx)? {
-            FeedListItem::All { .. } => None,
+            FeedListItem::All { .. } | FeedListItem::Starred { .. } | FeedListItem::Queue { .. } | FeedListItem::Recent { .. } => None,
             FeedListItem::GroupHeader { full_path, .. } => Some(full_path.clone()),
             FeedListItem::Feed { feed, .. } => {
                 // If feed has a parent group, use that; otherwise create at root
@@ -1893,6 +3777,7 @@ impl App {
             title: title.to_string(),
             url: url.to_string(),
             feed: feed_url.map(|s| s.to_string()),
+            ..Default::default()
         };
 
         if let Some(group_path) = parent_group {
@@ -2009,22 +3894,93 @@ impl App {
         let config = self.config.clone();
         let tx = self.db_result_tx.clone();
 
-        tokio::spawn(async move {
+        self.spawn_db_task(async move {
             match db.sync_feeds_from_config(&config).await {
                 Ok(_) => {
                     // After syncing, reload feeds to update the UI
-                    match db.get_all_feeds().await {
-                        Ok(feeds) => {
-                            let _ = tx.send(DbResult::FeedsLoaded(feeds));
+                    match load_feeds(&db, config.display.show_total_counts, config.display.dedup_by_url).await {
+                        Ok(result) => {
+                            let _ = tx.send(result);
                         }
-                        Err(_) => {}
+                        Err(e) => send_db_error(&tx, "load feeds", e),
                     }
                 }
-                Err(e) => {
-                    eprintln!("Failed to sync feeds from config: {}", e);
-                }
+                Err(e) => send_db_error(&tx, "sync feeds from the config", e),
+            }
+        });
+    }
+
+    /// Move the selected feed or group one place up (or down) among its
+    /// siblings in the config.
+    fn move_selected_item(&mut self, up: bool) {
+        let Some(item) = self.feeds_state.selected().and_then(|idx| self.feed_list_items.get(idx)) else {
+            return;
+        };
+
+        let target = match item {
+            FeedListItem::Feed { feed, .. } => SelectedSource::Feed(feed.url.clone()),
+            FeedListItem::GroupHeader { full_path, .. } => SelectedSource::Group(full_path.clone()),
+            FeedListItem::All { .. } | FeedListItem::Starred { .. } | FeedListItem::Queue { .. } | FeedListItem::Recent { .. } => {
+                self.status_message = Some(format!("Cannot move '{}'", item.virtual_name().unwrap_or_default()));
+                return;
             }
+        };
+
+        if Self::move_config_item(&mut self.config.feeds, None, &target, up) != Some(true) {
+            return;
+        }
+
+        // Save only the feeds section to preserve formatting
+        if let Err(e) = crate::config::save_feeds_only(&self.config.feeds) {
+            self.status_message = Some(format!("Failed to save config: {}", e));
+            return;
+        }
+
+        // Reload feeds from updated config; the selection follows the item
+        self.reload_feeds_from_config();
+    }
+
+    /// Swap `target` with its nearest sibling of the same kind (feed or
+    /// group) above or below it. Feeds and groups are listed separately in
+    /// the feeds pane, so swapping past the other kind would look like a
+    /// no-op.
+    ///
+    /// Returns `None` if `target` isn't in `feeds`, otherwise whether it moved.
+    fn move_config_item(
+        feeds: &mut [FeedConfigItem],
+        parent_path: Option<&str>,
+        target: &SelectedSource,
+        up: bool,
+    ) -> Option<bool> {
+        let group_path = |title: &str| match parent_path {
+            Some(parent) => format!("{} > {}", parent, title),
+            None => title.to_string(),
+        };
+
+        let pos = feeds.iter().position(|item| match (item, target) {
+            (FeedConfigItem::Standalone(source), SelectedSource::Feed(url)) => source.feed_url() == url,
+            (FeedConfigItem::Group(group), SelectedSource::Group(path)) => group_path(&group.title) == *path,
+            _ => false,
         });
+
+        if let Some(pos) = pos {
+            let is_feed = matches!(feeds[pos], FeedConfigItem::Standalone(_));
+            let same_kind = |item: &FeedConfigItem| matches!(item, FeedConfigItem::Standalone(_)) == is_feed;
+            let neighbour = if up {
+                feeds[..pos].iter().rposition(same_kind)
+            } else {
+                feeds[pos + 1..].iter().position(same_kind).map(|i| pos + 1 + i)
+            };
+            return Some(neighbour.map(|n| feeds.swap(pos, n)).is_some());
+        }
+
+        feeds.iter_mut().find_map(|item| match item {
+            FeedConfigItem::Group(group) => {
+                let path = group_path(&group.title);
+                Self::move_config_item(&mut group.feeds, Some(&path), target, up)
+            }
+            FeedConfigItem::Standalone(_) => None,
+        })
     }
 
     /// Delete the currently selected feed or group
@@ -2038,9 +3994,9 @@ impl App {
             return;
         };
 
-        // Cannot delete "All"
-        if matches!(item, FeedListItem::All { .. }) {
-            self.status_message = Some("Cannot delete 'All'".to_string());
+        // Cannot delete "All" or "Starred"
+        if let Some(name) = item.virtual_name() {
+            self.status_message = Some(format!("Cannot delete '{}'", name));
             return;
         }
 
@@ -2055,7 +4011,7 @@ impl App {
                 let feed_url = feed.url.clone();
                 self.delete_feed_from_config(&feed_url);
             }
-            FeedListItem::All { .. } => {
+            FeedListItem::All { .. } | FeedListItem::Starred { .. } | FeedListItem::Queue { .. } | FeedListItem::Recent { .. } => {
                 // Already handled above
             }
         }
@@ -2111,7 +4067,7 @@ impl App {
         for i in 0..feeds.len() {
             match &mut feeds[i] {
                 FeedConfigItem::Standalone(feed_source) => {
-                    if feed_source.feed.as_deref() == Some(feed_url) {
+                    if feed_source.feed_url() == feed_url {
                         feeds.remove(i);
                         return true;
                     }
@@ -2119,11 +4075,11 @@ impl App {
                 FeedConfigItem::Group(group) => {
                     // Check if the feed is in this group's feeds
                     for j in 0..group.feeds.len() {
-                        if let FeedConfigItem::Standalone(feed_source) = &group.feeds[j] {
-                            if feed_source.feed.as_deref() == Some(feed_url) {
-                                group.feeds.remove(j);
-                                return true;
-                            }
+                        if let FeedConfigItem::Standalone(feed_source) = &group.feeds[j]
+                            && feed_source.feed_url() == feed_url
+                        {
+                            group.feeds.remove(j);
+                            return true;
                         }
                     }
                     // Recursively check nested groups
@@ -2193,24 +4149,24 @@ impl App {
         for item in feeds.iter_mut() {
             match item {
                 FeedConfigItem::Standalone(feed_source) => {
-                    // Check by URL (not feed URL, since URL can change)
-                    if feed_source.url == original_url || feed_source.feed.as_deref() == Some(original_url) {
+                    // Match on the fetch URL, which is what the DB knows the feed by
+                    if feed_source.feed_url() == original_url {
                         feed_source.title = new_title.to_string();
                         feed_source.url = new_url.to_string();
-                        feed_source.feed = new_feed_url.map(|s| s.to_string());
+                        feed_source.feed = new_feed_url.filter(|f| *f != new_url).map(|s| s.to_string());
                         return true;
                     }
                 }
                 FeedConfigItem::Group(group) => {
                     // Check feeds in this group
                     for feed_item in group.feeds.iter_mut() {
-                        if let FeedConfigItem::Standalone(feed_source) = feed_item {
-                            if feed_source.url == original_url || feed_source.feed.as_deref() == Some(original_url) {
-                                feed_source.title = new_title.to_string();
-                                feed_source.url = new_url.to_string();
-                                feed_source.feed = new_feed_url.map(|s| s.to_string());
-                                return true;
-                            }
+                        if let FeedConfigItem::Standalone(feed_source) = feed_item
+                            && feed_source.feed_url() == original_url
+                        {
+                            feed_source.title = new_title.to_string();
+                            feed_source.url = new_url.to_string();
+                            feed_source.feed = new_feed_url.filter(|f| *f != new_url).map(|s| s.to_string());
+                            return true;
                         }
                     }
                     // Recursively check nested groups
@@ -2275,9 +4231,9 @@ impl App {
             return;
         };
 
-        // Cannot cut "All"
-        if matches!(item, FeedListItem::All { .. }) {
-            self.status_message = Some("Cannot cut 'All'".to_string());
+        // Cannot cut "All" or "Starred"
+        if let Some(name) = item.virtual_name() {
+            self.status_message = Some(format!("Cannot cut '{}'", name));
             return;
         }
 
@@ -2317,7 +4273,7 @@ impl App {
                     return;
                 }
             }
-            FeedListItem::All { .. } => {
+            FeedListItem::All { .. } | FeedListItem::Starred { .. } | FeedListItem::Queue { .. } | FeedListItem::Recent { .. } => {
                 // Already handled above
             }
         }
@@ -2358,7 +4314,7 @@ impl App {
 
         // Determine target group path
         let target_group = match item {
-            FeedListItem::All { .. } => None, // Paste at root level
+            FeedListItem::All { .. } | FeedListItem::Starred { .. } | FeedListItem::Queue { .. } | FeedListItem::Recent { .. } => None, // Paste at root level
             FeedListItem::GroupHeader { full_path, .. } => Some(full_path.clone()),
             FeedListItem::Feed { feed, .. } => {
                 // Paste into the feed's parent group
@@ -2440,21 +4396,20 @@ impl App {
         for i in 0..feeds.len() {
             match &mut feeds[i] {
                 FeedConfigItem::Standalone(feed_source) => {
-                    if feed_source.feed.as_deref() == Some(feed_url) {
-                        if let FeedConfigItem::Standalone(removed) = feeds.remove(i) {
-                            return Some(removed);
-                        }
+                    if feed_source.feed_url() == feed_url
+                        && let FeedConfigItem::Standalone(removed) = feeds.remove(i)
+                    {
+                        return Some(removed);
                     }
                 }
                 FeedConfigItem::Group(group) => {
                     // Check if the feed is in this group's feeds
                     for j in 0..group.feeds.len() {
-                        if let FeedConfigItem::Standalone(feed_source) = &group.feeds[j] {
-                            if feed_source.feed.as_deref() == Some(feed_url) {
-                                if let FeedConfigItem::Standalone(removed) = group.feeds.remove(j) {
-                                    return Some(removed);
-                                }
-                            }
+                        if let FeedConfigItem::Standalone(feed_source) = &group.feeds[j]
+                            && feed_source.feed_url() == feed_url
+                            && let FeedConfigItem::Standalone(removed) = group.feeds.remove(j)
+                        {
+                            return Some(removed);
                         }
                     }
                     // Recursively check nested groups
@@ -2627,27 +4582,63 @@ mod tests {
 
     #[test]
     fn test_to_strftime_format_default() {
-        assert_eq!(to_strftime_format("D MMM YYYY"), ("%d %b %Y".to_string(), true));
+        assert_eq!(to_strftime_format("D MMM YYYY", 24), ("%d %b %Y".to_string(), true));
     }
 
     #[test]
     fn test_to_strftime_format_double_digit_day() {
-        assert_eq!(to_strftime_format("DD MMM YYYY"), ("%d %b %Y".to_string(), false));
+        assert_eq!(to_strftime_format("DD MMM YYYY", 24), ("%d %b %Y".to_string(), false));
     }
 
     #[test]
     fn test_to_strftime_format_full_month() {
-        assert_eq!(to_strftime_format("D MMMM YYYY"), ("%d %B %Y".to_string(), true));
+        assert_eq!(to_strftime_format("D MMMM YYYY", 24), ("%d %B %Y".to_string(), true));
     }
 
     #[test]
     fn test_to_strftime_format_two_digit_year() {
-        assert_eq!(to_strftime_format("D MMM YY"), ("%d %b %y".to_string(), true));
+        assert_eq!(to_strftime_format("D MMM YY", 24), ("%d %b %y".to_string(), true));
     }
 
     #[test]
     fn test_to_strftime_format_combined() {
-        assert_eq!(to_strftime_format("DD MMMM YY"), ("%d %B %y".to_string(), false));
+        assert_eq!(to_strftime_format("DD MMMM YY", 24), ("%d %B %y".to_string(), false));
+    }
+
+    #[test]
+    fn test_to_strftime_format_24_hour_time() {
+        assert_eq!(to_strftime_format("D MMM YYYY HH:mm", 24), ("%d %b %Y %H:%M".to_string(), true));
+        assert_eq!(to_strftime_format("H:mm", 24), ("%-H:%M".to_string(), false));
+        // `format.time` wins over the token case; AM/PM goes away
+        assert_eq!(to_strftime_format("hh:mm a", 24), ("%H:%M".to_string(), false));
+    }
+
+    #[test]
+    fn test_to_strftime_format_12_hour_time() {
+        assert_eq!(to_strftime_format("hh:mm a", 12), ("%I:%M %p".to_string(), false));
+        assert_eq!(
+            to_strftime_format("ddd D MMM at h:mm a", 12),
+            ("%a %d %b at %-I:%M %p".to_string(), true)
+        );
+        // A 12-hour clock gets AM/PM even when the format has no `a`
+        assert_eq!(to_strftime_format("HH:mm, D MMM", 12), ("%I:%M %p, %d %b".to_string(), true));
+    }
+
+    #[test]
+    fn test_to_strftime_format_keeps_literals() {
+        assert_eq!(to_strftime_format("Day D, 100%", 24), ("Day %d, 100%%".to_string(), true));
+    }
+
+    #[test]
+    fn test_strip_day_leading_zero_keeps_hour() {
+        assert_eq!(strip_day_leading_zero("02 Nov 2025 09:05"), "2 Nov 2025 09:05");
+        assert_eq!(strip_day_leading_zero("09:05, 02 Nov"), "09:05, 2 Nov");
+    }
+
+    #[test]
+    fn test_time_strftime_format() {
+        assert_eq!(time_strftime_format(12), "%I:%M %p");
+        assert_eq!(time_strftime_format(24), "%H:%M");
     }
 
     #[test]
@@ -2677,27 +4668,289 @@ mod tests {
 
     #[test]
     fn test_to_strftime_format_abbreviated_weekday() {
-        assert_eq!(to_strftime_format("ddd, D MMM YYYY"), ("%a, %d %b %Y".to_string(), true));
+        assert_eq!(to_strftime_format("ddd, D MMM YYYY", 24), ("%a, %d %b %Y".to_string(), true));
     }
 
     #[test]
     fn test_to_strftime_format_full_weekday() {
-        assert_eq!(to_strftime_format("dddd, D MMMM YYYY"), ("%A, %d %B %Y".to_string(), true));
+        assert_eq!(to_strftime_format("dddd, D MMMM YYYY", 24), ("%A, %d %B %Y".to_string(), true));
+    }
+
+    #[test]
+    fn test_list_and_detail_dates_use_their_own_formats() {
+        use chrono::TimeZone;
+        let published = chrono::Local.with_ymd_and_hms(2025, 3, 5, 9, 30, 0).unwrap().with_timezone(&Utc);
+        let format_as = |format: &str| {
+            let (strftime_format, strip_day_zero) = to_strftime_format(format, 24);
+            format_local_date(published, &strftime_format, strip_day_zero)
+        };
+
+        // format.date in the list, format.date_detail in the article view
+        assert_eq!(format_as("D MMM"), "5 Mar");
+        assert_eq!(format_as("dddd, DD MMMM YYYY"), "Wednesday, 05 March 2025");
+        assert_eq!(format_as("D MMM HH:mm"), "5 Mar 09:30");
+    }
+
+    #[test]
+    fn test_toggling_time_format_changes_list_dates() {
+        use chrono::TimeZone;
+        let published = chrono::Local.with_ymd_and_hms(2025, 3, 5, 21, 30, 0).unwrap().with_timezone(&Utc);
+        let mut format = crate::config::Config::default().display.format;
+        format.date = "D MMM HH:mm".to_string();
+        let list_date = |format: &crate::config::FormatConfig| {
+            let (strftime_format, strip_day_zero) = to_strftime_format(&format.date, format.time);
+            format_local_date(published, &strftime_format, strip_day_zero)
+        };
+
+        format.time = 24;
+        assert_eq!(list_date(&format), "5 Mar 21:30");
+        format.time = 12;
+        assert_eq!(list_date(&format), "5 Mar 09:30 PM");
+    }
+
+    fn test_article(id: i64, is_read: bool) -> db::Article {
+        db::Article {
+            id,
+            feed_id: 1,
+            guid: format!("g{id}"),
+            title: format!("Post {id}"),
+            url: None,
+            author: None,
+            summary: None,
+            content: None,
+            published: None,
+            is_read,
+            is_starred: false,
+            is_queued: false,
+            enclosure_url: None,
+            has_full_content: false,
+        }
+    }
+
+    fn test_feed(id: i64, group: &str, title: &str) -> db::Feed {
+        db::Feed {
+            id,
+            group_title: group.to_string(),
+            title: title.to_string(),
+            url: format!("https://example.com/{id}.xml"),
+            site_url: None,
+            last_fetched: None,
+            unread_count: 0,
+            total_count: 0,
+            last_error: None,
+            mark_read_on: None,
+        }
+    }
+
+    /// A feed in `group` titled after its ID.
+    fn test_group_feed(id: i64, group: &str) -> db::Feed {
+        test_feed(id, group, &format!("Feed {id}"))
+    }
+
+    /// [`test_group_feed`] with unread and total article counts.
+    fn test_feed_with_counts(id: i64, group: &str, unread_count: u32, total_count: u32) -> db::Feed {
+        db::Feed { unread_count, total_count, ..test_group_feed(id, group) }
+    }
+
+    #[test]
+    fn test_restored_article_index_keeps_same_article() {
+        let old_ids = [1, 2, 3, 4];
+        // A new article arrived at the top; the cursor stays on article 3
+        let articles: Vec<_> = [9, 1, 2, 3, 4].iter().map(|&id| test_article(id, false)).collect();
+        assert_eq!(restored_article_index(&old_ids, &articles, Some(2), true), Some(3));
+    }
+
+    #[test]
+    fn test_restored_article_index_mark_read_while_navigating() {
+        // Unread-only view: the user moves down from 1 to 2 and then 3,
+        // marking each read, and the list reloads without them.
+        let old_ids = [1, 2, 3, 4, 5];
+        let articles: Vec<_> = [4, 5].iter().map(|&id| test_article(id, false)).collect();
+        // Cursor was on article 3 (row 2): land on article 4, not row 2
+        assert_eq!(restored_article_index(&old_ids, &articles, Some(2), true), Some(0));
+
+        // The last article vanished: fall back to the one above it
+        let articles: Vec<_> = [1, 2].iter().map(|&id| test_article(id, false)).collect();
+        assert_eq!(restored_article_index(&old_ids, &articles, Some(4), true), Some(1));
+    }
+
+    #[test]
+    fn test_restored_article_index_without_selection() {
+        let articles: Vec<_> = [1, 2].iter().map(|&id| test_article(id, true)).collect();
+        assert_eq!(restored_article_index(&[], &articles, None, true), None);
+        assert_eq!(restored_article_index(&[7], &articles, Some(0), true), None);
+    }
+
+    #[test]
+    fn test_hidden_unread_duplicates_counts_extra_copies_in_scope() {
+        let duplicates = vec![
+            (1, "https://example.com/a".to_string()),
+            (2, "https://example.com/a".to_string()),
+            (3, "https://example.com/a".to_string()),
+            (1, "https://example.com/b".to_string()),
+            (3, "https://example.com/b".to_string()),
+        ];
+        assert_eq!(hidden_unread_duplicates(&duplicates, |_| true), 3);
+        // Feed 3 is out of scope: "a" is shown once for feeds 1 and 2, "b" is alone
+        assert_eq!(hidden_unread_duplicates(&duplicates, |id| id != 3), 1);
+        assert_eq!(hidden_unread_duplicates(&duplicates, |id| id == 2), 0);
+        assert_eq!(hidden_unread_duplicates(&[], |_| true), 0);
+    }
+
+    #[test]
+    fn test_restored_article_index_after_switching_source() {
+        // Another feed shares article 3, but its list starts afresh
+        let old_ids = [1, 2, 3, 4];
+        let articles: Vec<_> = [9, 3, 8].iter().map(|&id| test_article(id, false)).collect();
+        assert_eq!(restored_article_index(&old_ids, &articles, Some(2), false), None);
+        assert_eq!(restored_article_index(&old_ids, &articles, Some(3), false), None);
+    }
+
+    #[test]
+    fn test_feed_breadcrumb() {
+        let feed = |group: &str| test_feed(1, group, "Rust Blog");
+        assert_eq!(feed_breadcrumb(&feed("Tech > Rust")), "Tech > Rust > Rust Blog");
+        assert_eq!(feed_breadcrumb(&feed("")), "Rust Blog");
+    }
+
+    #[test]
+    fn test_no_content_body() {
+        assert_eq!(
+            no_content_body(Some("https://example.com/post"), "o"),
+            "This feed doesn't include the article text.\n\nhttps://example.com/post\n\nPress o to open in browser\n"
+        );
+        assert_eq!(no_content_body(None, "o"), "(No content available)\n");
+    }
+
+    #[test]
+    fn test_find_source() {
+        let feed = |id: i64, title: &str| FeedListItem::Feed {
+            feed: test_feed(id, "Tech", title),
+            depth: 1,
+        };
+        let items = vec![
+            FeedListItem::All { unread_count: 0, total_count: 0 },
+            FeedListItem::Starred { count: 0 },
+            FeedListItem::GroupHeader {
+                title: "Tech".to_string(),
+                full_path: "Tech".to_string(),
+                collapsed: false,
+                unread_count: 0,
+                total_count: 0,
+                depth: 0,
+            },
+            feed(1, "Rust Blog Digest"),
+            feed(2, "Rust Blog"),
+        ];
+
+        assert_eq!(find_source(&items, "starred"), Some(1));
+        assert_eq!(find_source(&items, "tech"), Some(2));
+        // Exact matches win over earlier partial ones
+        assert_eq!(find_source(&items, "rust blog"), Some(4));
+        assert_eq!(find_source(&items, "digest"), Some(3));
+        assert_eq!(find_source(&items, "python"), None);
+    }
+
+    #[test]
+    fn test_max_scroll_keeps_last_page_full() {
+        assert_eq!(max_scroll(100, 30), 70);
+        assert_eq!(max_scroll(20, 30), 0);
+        assert_eq!(max_scroll(0, 30), 0);
+        assert_eq!(max_scroll(100, 0), 99);
+
+        // At any scroll up to the maximum, the view still ends on content
+        for (lines, height) in [(100, 30), (31, 30), (30, 30), (5, 30)] {
+            assert!(max_scroll(lines, height) + height >= lines);
+            assert!(lines == 0 || max_scroll(lines, height) < lines);
+        }
+    }
+
+    #[test]
+    fn test_scrolled_to_read() {
+        // A long article counts once scrolled past halfway or to the end
+        assert!(!scrolled_to_read(0, 100, 30));
+        assert!(!scrolled_to_read(49, 100, 30));
+        assert!(scrolled_to_read(50, 100, 30));
+        assert!(scrolled_to_read(20, 40, 30));
+
+        // One that fits the view counts without any scrolling
+        assert!(scrolled_to_read(0, 20, 30));
+        assert!(scrolled_to_read(0, 30, 30));
+    }
+
+    #[test]
+    fn test_half_page() {
+        assert_eq!(half_page(0), 10);
+        assert_eq!(half_page(1), 1);
+        assert_eq!(half_page(9), 4);
+        assert_eq!(half_page(50), 25);
+    }
+
+    #[test]
+    fn test_step_index_wraps() {
+        assert_eq!(step_index(3, 1, 5, true), 4);
+        assert_eq!(step_index(4, 1, 5, true), 0);
+        assert_eq!(step_index(0, -1, 5, true), 4);
+        // Half-page jumps past either end wrap by the remainder
+        assert_eq!(step_index(3, 10, 5, true), 3);
+        assert_eq!(step_index(1, -3, 5, true), 3);
+        assert_eq!(step_index(1, -13, 5, true), 3);
+        assert_eq!(step_index(2, -2, 5, true), 0);
+        assert_eq!(step_index(0, 0, 1, true), 0);
+    }
+
+    #[test]
+    fn test_step_index_clamps() {
+        assert_eq!(step_index(3, 1, 5, false), 4);
+        assert_eq!(step_index(4, 1, 5, false), 4);
+        assert_eq!(step_index(0, -1, 5, false), 0);
+        // Half-page jumps stop at the ends
+        assert_eq!(step_index(3, 10, 5, false), 4);
+        assert_eq!(step_index(1, -3, 5, false), 0);
+        assert_eq!(step_index(4, -2, 5, false), 2);
+        assert_eq!(step_index(0, i32::MIN, 1, false), 0);
+    }
+
+    #[test]
+    fn test_source_label() {
+        assert_eq!(source_label(&FeedListItem::All { unread_count: 3, total_count: 0 }), "All Feeds");
+        assert_eq!(source_label(&FeedListItem::Starred { count: 2 }), "Starred");
+        assert_eq!(source_label(&FeedListItem::Recent { hours: 24 }), "Recent (24h)");
+        assert_eq!(
+            source_label(&FeedListItem::GroupHeader {
+                title: "Domestic".to_string(),
+                full_path: "News > Domestic".to_string(),
+                collapsed: false,
+                unread_count: 0,
+                total_count: 0,
+                depth: 1,
+            }),
+            "Domestic (group)"
+        );
+        let feed = test_feed(1, "Tech", "Rust Blog");
+        assert_eq!(source_label(&FeedListItem::Feed { feed, depth: 1 }), "Rust Blog");
+    }
+
+    #[test]
+    fn test_count_groups_includes_parents_and_empty_groups() {
+        let feeds = vec![
+            test_group_feed(1, ""),
+            test_group_feed(2, "Tech > Rust"),
+            test_group_feed(3, "Tech > Rust"),
+            test_group_feed(4, "News"),
+        ];
+        let empty_groups = vec!["News > Local".to_string(), "Music".to_string()];
+
+        // Tech, Tech > Rust, News, News > Local and Music
+        assert_eq!(count_groups(&feeds, &empty_groups), 5);
+        assert_eq!(count_groups(&feeds[..1], &[]), 0);
     }
 
     #[test]
     fn test_build_group_tree_with_empty_groups() {
         // Create some feeds
         let feeds = vec![
-            db::Feed {
-                id: 1,
-                group_title: "Tech".to_string(),
-                title: "Rust Blog".to_string(),
-                url: "https://blog.rust-lang.org/feed.xml".to_string(),
-                site_url: Some("https://blog.rust-lang.org/".to_string()),
-                last_fetched: None,
-                unread_count: 5,
-            },
+            test_feed_with_counts(1, "Tech", 5, 0),
         ];
 
         // Create empty groups
@@ -2706,8 +4959,13 @@ mod tests {
             "Tech > Programming".to_string(),
         ];
 
-        // Build the tree
-        let tree = build_group_tree(&feeds, &empty_groups);
+        // Build the tree in config order
+        let group_order = vec![
+            "News".to_string(),
+            "Tech".to_string(),
+            "Tech > Programming".to_string(),
+        ];
+        let tree = build_group_tree(&feeds, &empty_groups, &group_order);
 
         // We should have 3 root nodes: "News", "Tech", and potentially nested groups
         assert_eq!(tree.len(), 2);
@@ -2733,19 +4991,11 @@ mod tests {
     fn test_build_group_tree_without_empty_groups() {
         // Create some feeds
         let feeds = vec![
-            db::Feed {
-                id: 1,
-                group_title: "Tech".to_string(),
-                title: "Rust Blog".to_string(),
-                url: "https://blog.rust-lang.org/feed.xml".to_string(),
-                site_url: Some("https://blog.rust-lang.org/".to_string()),
-                last_fetched: None,
-                unread_count: 5,
-            },
+            test_feed_with_counts(1, "Tech", 5, 0),
         ];
 
         // Build the tree without empty groups
-        let tree = build_group_tree(&feeds, &[]);
+        let tree = build_group_tree(&feeds, &[], &[]);
 
         // We should have 1 root node: "Tech"
         assert_eq!(tree.len(), 1);
@@ -2754,6 +5004,173 @@ mod tests {
         assert_eq!(tree[0].unread_count, 5);
     }
 
+    #[test]
+    fn test_build_group_tree_rolls_up_totals() {
+        let feeds = vec![
+            test_feed_with_counts(1, "News", 2, 10),
+            test_feed_with_counts(2, "News > World", 5, 40),
+            test_feed_with_counts(3, "News > World > Europe", 1, 7),
+        ];
+
+        let tree = build_group_tree(&feeds, &[], &[]);
+        let news = &tree[0];
+        assert_eq!((news.unread_count, news.total_count), (8, 57));
+        let world = &news.children[0];
+        assert_eq!((world.unread_count, world.total_count), (6, 47));
+        assert_eq!(world.children[0].total_count, 7);
+    }
+
+    #[test]
+    fn test_feeds_in_group_includes_subgroups() {
+        let feeds = vec![
+            test_group_feed(1, "News"),
+            test_group_feed(2, "News > World"),
+            test_group_feed(3, "Newsletters"),
+            test_group_feed(4, ""),
+        ];
+
+        let ids = |group: &str| -> Vec<i64> {
+            App::feeds_in_group(&feeds, group).iter().map(|f| f.id).collect()
+        };
+        assert_eq!(ids("News"), vec![1, 2]);
+        assert_eq!(ids("News > World"), vec![2]);
+        assert!(ids("Tech").is_empty());
+    }
+
+    #[test]
+    fn test_render_cache() {
+        let render = |article_id: i64, source_hash: u64| RenderResult {
+            article_id,
+            generation: None,
+            source_hash,
+            content: format!("article {article_id}"),
+            code_lines: Vec::new(),
+            images: Vec::new(),
+        };
+        let mut cache = RenderCache::default();
+        for id in 0..RenderCache::CAPACITY as i64 {
+            cache.insert(render(id, 1));
+        }
+
+        // Using an entry protects it from eviction
+        assert!(cache.get(0, 1).is_some());
+        cache.insert(render(100, 1));
+        assert!(cache.contains(0, 1));
+        assert!(!cache.contains(1, 1));
+        assert_eq!(cache.entries.len(), RenderCache::CAPACITY);
+
+        // A changed article misses; re-rendering replaces the old entry
+        assert!(cache.get(2, 2).is_none());
+        cache.insert(render(2, 2));
+        assert!(cache.contains(2, 2));
+        assert!(!cache.contains(2, 1));
+        assert_eq!(cache.entries.len(), RenderCache::CAPACITY);
+    }
+
+    #[test]
+    fn test_build_group_tree_follows_group_order() {
+        let feeds = vec![
+            test_group_feed(1, "Alpha"),
+            test_group_feed(2, "Zulu > Beta"),
+            test_group_feed(3, "Zulu > Alpha"),
+        ];
+        let order = vec![
+            "Zulu".to_string(),
+            "Zulu > Beta".to_string(),
+            "Zulu > Alpha".to_string(),
+            "Alpha".to_string(),
+        ];
+
+        let tree = build_group_tree(&feeds, &["Mike".to_string()], &order);
+        let titles: Vec<&str> = tree.iter().map(|n| n.title.as_str()).collect();
+        // Groups missing from the order go last
+        assert_eq!(titles, vec!["Zulu", "Alpha", "Mike"]);
+        let children: Vec<&str> = tree[0].children.iter().map(|n| n.title.as_str()).collect();
+        assert_eq!(children, vec!["Beta", "Alpha"]);
+    }
+
+    #[test]
+    fn test_build_group_tree_keeps_feed_order_without_group_order() {
+        let feeds = vec![
+            test_group_feed(1, "Zulu"),
+            test_group_feed(2, "Alpha"),
+            test_group_feed(3, "Zulu"),
+        ];
+
+        let tree = build_group_tree(&feeds, &["Mike".to_string()], &[]);
+        let titles: Vec<&str> = tree.iter().map(|n| n.title.as_str()).collect();
+        assert_eq!(titles, vec!["Zulu", "Alpha", "Mike"]);
+        let zulu_ids: Vec<i64> = tree[0].feeds.iter().map(|f| f.id).collect();
+        assert_eq!(zulu_ids, vec![1, 3]);
+    }
+
+    /// A config feed served at `https://{title}.example.com`.
+    fn test_source(title: &str) -> FeedConfigItem {
+        FeedConfigItem::Standalone(FeedSource {
+            title: title.to_string(),
+            url: format!("https://{title}.example.com"),
+            feed: None,
+            ..Default::default()
+        })
+    }
+
+    fn test_group(title: &str, feeds: Vec<FeedConfigItem>) -> FeedConfigItem {
+        FeedConfigItem::Group(FeedGroup { title: title.to_string(), feeds })
+    }
+
+    #[test]
+    fn test_move_config_item() {
+        let mut feeds = vec![
+            test_source("a"),
+            test_group("News", vec![
+                test_source("b"),
+                test_group("World", vec![]),
+                test_source("c"),
+                test_group("Local", vec![]),
+            ]),
+            test_source("d"),
+        ];
+        let feed = |title: &str| SelectedSource::Feed(format!("https://{title}.example.com"));
+
+        // Feeds swap with the next feed, skipping groups in between
+        assert_eq!(App::move_config_item(&mut feeds, None, &feed("a"), false), Some(true));
+        assert_eq!(App::move_config_item(&mut feeds, None, &feed("b"), false), Some(true));
+        let FeedConfigItem::Group(news) = &feeds[1] else { panic!("expected group") };
+        assert!(matches!(&news.feeds[0], FeedConfigItem::Standalone(s) if s.title == "c"));
+        assert!(matches!(&news.feeds[2], FeedConfigItem::Standalone(s) if s.title == "b"));
+
+        // Nested groups are found by path
+        let local = SelectedSource::Group("News > Local".to_string());
+        assert_eq!(App::move_config_item(&mut feeds, None, &local, true), Some(true));
+        assert_eq!(App::move_config_item(&mut feeds, None, &local, true), Some(false));
+
+        // "a" swapped with "d" above, so it is now the last feed
+        assert_eq!(App::move_config_item(&mut feeds, None, &feed("a"), false), Some(false));
+        assert_eq!(App::move_config_item(&mut feeds, None, &feed("zzz"), true), None);
+    }
+
+    #[test]
+    fn test_relocate_feed() {
+        let mut feeds = vec![
+            test_source("a"),
+            test_group("News", vec![test_source("b"), test_group("World", vec![test_source("c")])]),
+        ];
+
+        let title = App::relocate_feed(&mut feeds, "https://a.example.com", Some("News > World"));
+        assert_eq!(title.as_deref(), Some("a"));
+        assert_eq!(feeds.len(), 1);
+        let FeedConfigItem::Group(news) = &feeds[0] else { panic!("expected group") };
+        let FeedConfigItem::Group(world) = &news.feeds[1] else { panic!("expected group") };
+        assert!(matches!(&world.feeds[1], FeedConfigItem::Standalone(s) if s.title == "a"));
+
+        // Back out to the root level
+        App::relocate_feed(&mut feeds, "https://c.example.com", None);
+        assert!(matches!(&feeds[1], FeedConfigItem::Standalone(s) if s.title == "c"));
+
+        assert_eq!(App::relocate_feed(&mut feeds, "https://zzz.example.com", None), None);
+        assert_eq!(feeds.len(), 2);
+    }
+
     #[test]
     fn test_remove_feed_recursive_standalone() {
         let mut feeds = vec![
@@ -2761,6 +5178,7 @@ mod tests {
                 title: "BAIR".to_string(),
                 url: "http://bair.berkeley.edu/blog/".to_string(),
                 feed: Some("https://bair.berkeley.edu/blog/feed.xml".to_string()),
+                ..Default::default()
             }),
             FeedConfigItem::Group(FeedGroup {
                 title: "Tech".to_string(),
@@ -2769,6 +5187,7 @@ mod tests {
                         title: "Rust Blog".to_string(),
                         url: "https://blog.rust-lang.org/".to_string(),
                         feed: Some("https://blog.rust-lang.org/feed.xml".to_string()),
+                        ..Default::default()
                     }),
                 ],
             }),
@@ -2786,6 +5205,77 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_remove_feed_recursive_without_feed_url() {
+        let mut feeds = vec![
+            FeedConfigItem::Group(FeedGroup {
+                title: "Tech".to_string(),
+                feeds: vec![
+                    FeedConfigItem::Standalone(FeedSource {
+                        title: "Rust Blog".to_string(),
+                        url: "https://blog.rust-lang.org/feed.xml".to_string(),
+                        feed: None,
+                        ..Default::default()
+                    }),
+                ],
+            }),
+        ];
+
+        // A feed with only `url` is stored in the DB under that URL
+        let removed = App::remove_feed_recursive(&mut feeds, "https://blog.rust-lang.org/feed.xml");
+        assert!(removed);
+        match &feeds[0] {
+            FeedConfigItem::Group(g) => assert!(g.feeds.is_empty()),
+            _ => panic!("Expected group"),
+        }
+    }
+
+    #[test]
+    fn test_remove_and_return_feed_without_feed_url() {
+        let mut feeds = vec![
+            FeedConfigItem::Standalone(FeedSource {
+                title: "Rust Blog".to_string(),
+                url: "https://blog.rust-lang.org/feed.xml".to_string(),
+                feed: None,
+                ..Default::default()
+            }),
+        ];
+
+        let cut = App::remove_and_return_feed(&mut feeds, "https://blog.rust-lang.org/feed.xml");
+        assert_eq!(cut.map(|f| f.title), Some("Rust Blog".to_string()));
+        assert!(feeds.is_empty());
+    }
+
+    #[test]
+    fn test_update_feed_in_config_without_feed_url() {
+        let mut feeds = vec![
+            FeedConfigItem::Standalone(FeedSource {
+                title: "Rust Blog".to_string(),
+                url: "https://blog.rust-lang.org/feed.xml".to_string(),
+                feed: None,
+                ..Default::default()
+            }),
+        ];
+
+        // The edit popup pre-fills the feed URL from the DB; it shouldn't
+        // turn into a redundant `feed` entry
+        let updated = App::update_feed_in_config(
+            &mut feeds,
+            "https://blog.rust-lang.org/feed.xml",
+            "Rust",
+            "https://blog.rust-lang.org/feed.xml",
+            Some("https://blog.rust-lang.org/feed.xml"),
+        );
+        assert!(updated);
+        match &feeds[0] {
+            FeedConfigItem::Standalone(f) => {
+                assert_eq!(f.title, "Rust");
+                assert_eq!(f.feed, None);
+            }
+            _ => panic!("Expected standalone feed"),
+        }
+    }
+
     #[test]
     fn test_remove_feed_recursive_in_group() {
         let mut feeds = vec![
@@ -2796,11 +5286,13 @@ mod tests {
                         title: "Rust Blog".to_string(),
                         url: "https://blog.rust-lang.org/".to_string(),
                         feed: Some("https://blog.rust-lang.org/feed.xml".to_string()),
+                        ..Default::default()
                     }),
                     FeedConfigItem::Standalone(FeedSource {
                         title: "Go Blog".to_string(),
                         url: "https://go.dev/blog/".to_string(),
                         feed: Some("https://go.dev/blog/feed.xml".to_string()),
+                        ..Default::default()
                     }),
                 ],
             }),
@@ -2831,6 +5323,7 @@ mod tests {
                 title: "BAIR".to_string(),
                 url: "http://bair.berkeley.edu/blog/".to_string(),
                 feed: Some("https://bair.berkeley.edu/blog/feed.xml".to_string()),
+                ..Default::default()
             }),
             FeedConfigItem::Group(FeedGroup {
                 title: "Tech".to_string(),
@@ -2839,6 +5332,7 @@ mod tests {
                         title: "Rust Blog".to_string(),
                         url: "https://blog.rust-lang.org/".to_string(),
                         feed: Some("https://blog.rust-lang.org/feed.xml".to_string()),
+                        ..Default::default()
                     }),
                 ],
             }),
@@ -2855,6 +5349,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rename_group_path() {
+        let rename = |path| rename_group_path(path, "News > World", "News > Global");
+        assert_eq!(rename("News > World"), "News > Global");
+        assert_eq!(rename("News > World > Europe"), "News > Global > Europe");
+        assert_eq!(rename("News"), "News");
+        assert_eq!(rename("News > Worldwide"), "News > Worldwide");
+    }
+
+    #[test]
+    fn test_title_sort_key_ignores_case_and_accents() {
+        let mut titles = vec!["ZDNet", "apple", "Élan", "eclipse", "Ars Technica"];
+        titles.sort_by_cached_key(|t| title_sort_key(t));
+        assert_eq!(titles, vec!["apple", "Ars Technica", "eclipse", "Élan", "ZDNet"]);
+    }
+
+    #[test]
+    fn test_update_group_in_config_renames_middle_node() {
+        let mut feeds: Vec<FeedConfigItem> = serde_yaml::from_str(
+            r#"
+- title: "News"
+  feeds:
+    - title: "Wire"
+      url: "https://wire.example.com/feed.xml"
+    - title: "World"
+      feeds:
+        - title: "Globe"
+          url: "https://globe.example.com/feed.xml"
+        - title: "Europe"
+          feeds:
+            - title: "Euro Daily"
+              url: "https://euro.example.com/feed.xml"
+- title: "World"
+  feeds:
+    - title: "Atlas"
+      url: "https://atlas.example.com/feed.xml"
+"#,
+        )
+        .unwrap();
+
+        assert!(App::update_group_in_config(&mut feeds, "News > World", "Global"));
+        assert!(!App::update_group_in_config(&mut feeds, "News > World", "Global"));
+
+        // Feeds below the renamed node pick up the new path; the top-level
+        // group with the same old name is untouched
+        let db_feeds: Vec<db::Feed> = feeds
+            .iter()
+            .flat_map(|item| item.collect_feeds())
+            .enumerate()
+            .map(|(id, (group, source))| test_feed(id as i64, &group.unwrap_or_default(), &source.title))
+            .collect();
+        let paths: Vec<(&str, &str)> = db_feeds.iter().map(|f| (f.title.as_str(), f.group_title.as_str())).collect();
+        assert_eq!(paths, vec![
+            ("Wire", "News"),
+            ("Globe", "News > Global"),
+            ("Euro Daily", "News > Global > Europe"),
+            ("Atlas", "World"),
+        ]);
+
+        let tree = build_group_tree(&db_feeds, &[], &[]);
+        let news = &tree[0];
+        assert_eq!(news.children[0].full_path, "News > Global");
+        assert_eq!(news.children[0].children[0].full_path, "News > Global > Europe");
+        assert_eq!(tree[1].full_path, "World");
+    }
+
     #[test]
     fn test_remove_group_recursive_nested() {
         let mut feeds = vec![
@@ -2865,6 +5425,7 @@ mod tests {
                         title: "Foreign Policy".to_string(),
                         url: "https://foreignpolicy.com".to_string(),
                         feed: Some("http://foreignpolicy.com/feed".to_string()),
+                        ..Default::default()
                     }),
                     FeedConfigItem::Group(FeedGroup {
                         title: "Domestic".to_string(),
@@ -2873,6 +5434,7 @@ mod tests {
                                 title: "BBC World News".to_string(),
                                 url: "https://www.bbc.co.uk/news/".to_string(),
                                 feed: Some("http://feeds.bbci.co.uk/news/world/rss.xml".to_string()),
+                                ..Default::default()
                             }),
                         ],
                     }),
@@ -2912,6 +5474,7 @@ mod tests {
                                 title: "BBC World News".to_string(),
                                 url: "https://www.bbc.co.uk/news/".to_string(),
                                 feed: Some("http://feeds.bbci.co.uk/news/world/rss.xml".to_string()),
+                                ..Default::default()
                             }),
                         ],
                     }),
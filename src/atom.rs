@@ -0,0 +1,121 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::db::Article;
+
+/// Escape text for use in XML character data and attribute values.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Whether `text` looks like an absolute IRI: a scheme, a colon and no
+/// whitespace.
+fn is_iri(text: &str) -> bool {
+    let Some((scheme, rest)) = text.split_once(':') else {
+        return false;
+    };
+    !scheme.is_empty()
+        && !rest.is_empty()
+        && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+        && !text.contains(char::is_whitespace)
+}
+
+/// Atom `<id>` for an article. Atom ids must be IRIs, so a GUID without a
+/// scheme falls back to the article's link, then to a `urn:` built from its
+/// database id.
+fn entry_id(article: &Article) -> String {
+    if is_iri(&article.guid) {
+        article.guid.clone()
+    } else if let Some(url) = article.url.as_deref().filter(|url| is_iri(url)) {
+        url.to_string()
+    } else {
+        format!("urn:lazyrss:article:{}", article.id)
+    }
+}
+
+/// Build an Atom feed of `articles` (the starred articles, for
+/// `--export-starred`). Articles without a published date use `now` as their
+/// `<updated>` time.
+pub fn starred_feed(articles: &[Article], now: DateTime<Utc>) -> String {
+    let timestamp = |date: Option<DateTime<Utc>>| date.unwrap_or(now).to_rfc3339_opts(SecondsFormat::Secs, true);
+    let updated = articles.iter().filter_map(|a| a.published).max();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    xml.push_str("  <title>Starred articles</title>\n");
+    xml.push_str("  <id>urn:lazyrss:starred</id>\n");
+    xml.push_str(&format!("  <updated>{}</updated>\n", timestamp(updated)));
+    xml.push_str("  <author><name>lazyrss</name></author>\n");
+    xml.push_str("  <generator>lazyrss</generator>\n");
+
+    for article in articles {
+        xml.push_str("  <entry>\n");
+        xml.push_str(&format!("    <title>{}</title>\n", escape_xml(&article.title)));
+        if let Some(url) = &article.url {
+            xml.push_str(&format!("    <link rel=\"alternate\" href=\"{}\"/>\n", escape_xml(url)));
+        }
+        xml.push_str(&format!("    <id>{}</id>\n", escape_xml(&entry_id(article))));
+        xml.push_str(&format!("    <updated>{}</updated>\n", timestamp(article.published)));
+        if let Some(author) = &article.author {
+            xml.push_str(&format!("    <author><name>{}</name></author>\n", escape_xml(author)));
+        }
+        if let Some(summary) = &article.summary {
+            xml.push_str(&format!("    <summary type=\"html\">{}</summary>\n", escape_xml(summary)));
+        }
+        if let Some(content) = &article.content {
+            xml.push_str(&format!("    <content type=\"html\">{}</content>\n", escape_xml(content)));
+        }
+        xml.push_str("  </entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn article(guid: &str, url: Option<&str>) -> Article {
+        Article {
+            id: 7,
+            feed_id: 1,
+            guid: guid.to_string(),
+            title: "Fish & <Chips>".to_string(),
+            url: url.map(str::to_string),
+            author: None,
+            summary: Some("<p>Tasty</p>".to_string()),
+            content: None,
+            published: Some(Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap()),
+            is_read: false,
+            is_starred: true,
+            is_queued: false,
+            enclosure_url: None,
+            has_full_content: false,
+        }
+    }
+
+    #[test]
+    fn starred_feed_escapes_entries() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let xml = starred_feed(&[article("https://a.com/1", Some("https://a.com/1?x=1&y=2"))], now);
+
+        assert!(xml.contains("<updated>2024-05-01T12:00:00Z</updated>\n  <author>"));
+        assert!(xml.contains("<title>Fish &amp; &lt;Chips&gt;</title>"));
+        assert!(xml.contains("<link rel=\"alternate\" href=\"https://a.com/1?x=1&amp;y=2\"/>"));
+        assert!(xml.contains("<id>https://a.com/1</id>"));
+        assert!(xml.contains("<summary type=\"html\">&lt;p&gt;Tasty&lt;/p&gt;</summary>"));
+        assert!(!xml.contains("<content"));
+        assert!(xml.ends_with("  </entry>\n</feed>\n"));
+    }
+
+    #[test]
+    fn entry_id_is_always_an_iri() {
+        assert_eq!(entry_id(&article("tag:a.com,2024:1", None)), "tag:a.com,2024:1");
+        assert_eq!(entry_id(&article("1234", Some("https://a.com/1"))), "https://a.com/1");
+        assert_eq!(entry_id(&article("post 1", None)), "urn:lazyrss:article:7");
+    }
+}
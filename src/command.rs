@@ -0,0 +1,137 @@
+use std::str::FromStr;
+
+use crate::config::{parse_mark_read_on, Config};
+
+/// A command typed at the `:` prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Refresh all feeds.
+    Refresh,
+    /// Mark every article in the current feed (or "All") as read.
+    MarkAllRead,
+    /// Select the feeds-pane entry with this name.
+    Goto(String),
+    /// Change a setting for the rest of the session.
+    Set { key: String, value: String },
+    /// Quit the application.
+    Quit,
+}
+
+/// Parse a command line such as `goto Rust Blog` or `set refresh_every=600`.
+///
+/// A leading `:` is ignored.
+pub fn parse(line: &str) -> Result<Command, String> {
+    let line = line.trim();
+    let line = line.strip_prefix(':').unwrap_or(line).trim_start();
+    let (name, arg) = match line.split_once(char::is_whitespace) {
+        Some((name, arg)) => (name, arg.trim()),
+        None => (line, ""),
+    };
+
+    let command = match name {
+        "refresh" => Command::Refresh,
+        "mark-all-read" => Command::MarkAllRead,
+        "quit" | "q" => Command::Quit,
+        "goto" => {
+            if arg.is_empty() {
+                return Err("Usage: :goto <feed or group>".to_string());
+            }
+            return Ok(Command::Goto(arg.to_string()));
+        }
+        "set" => {
+            let (key, value) = arg
+                .split_once('=')
+                .ok_or_else(|| "Usage: :set <option>=<value>".to_string())?;
+            return Ok(Command::Set {
+                key: key.trim().to_string(),
+                value: value.trim().to_string(),
+            });
+        }
+        _ => return Err(format!("Unknown command: {}", name)),
+    };
+
+    if arg.is_empty() {
+        Ok(command)
+    } else {
+        Err(format!(":{} takes no arguments", name))
+    }
+}
+
+/// Apply `:set key=value` to the in-memory config. Changes are not saved to
+/// the config file.
+pub fn apply_setting(config: &mut Config, key: &str, value: &str) -> Result<(), String> {
+    match key {
+        "refresh_every" => {
+            let secs: u64 = parse_value(key, value)?;
+            if secs == 0 {
+                return Err("refresh_every must be at least 1".to_string());
+            }
+            config.refresh_every = secs;
+        }
+        "mark_read_on" => {
+            parse_mark_read_on(value)?;
+            config.display.mark_read_on = value.to_string();
+        }
+        "dedup_by_url" => config.display.dedup_by_url = parse_value(key, value)?,
+        "wrap_navigation" => config.display.wrap_navigation = parse_value(key, value)?,
+        "recent_window_hours" => config.display.recent_window_hours = parse_value(key, value)?,
+        "relative_dates" => config.display.format.relative_dates = parse_value(key, value)?,
+        "strip_tracking_params" => config.display.strip_tracking_params = parse_value(key, value)?,
+        "unread_on_update" => config.display.unread_on_update = parse_value(key, value)?,
+        "confirm_mark_all_read" => config.display.confirm_mark_all_read = parse_value(key, value)?,
+        "show_total_counts" => config.display.show_total_counts = parse_value(key, value)?,
+        "sort_feeds" => config.display.sort_feeds = parse_value(key, value)?,
+        "show_preview" => config.display.show_preview = parse_value(key, value)?,
+        _ => return Err(format!("Unknown option: {}", key)),
+    }
+    Ok(())
+}
+
+/// Parse a `:set` value, naming the option in the error.
+fn parse_value<T: FromStr>(key: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {}: {}", key, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!(parse("refresh"), Ok(Command::Refresh));
+        assert_eq!(parse(":mark-all-read "), Ok(Command::MarkAllRead));
+        assert_eq!(parse("q"), Ok(Command::Quit));
+        assert_eq!(parse("goto  Rust Blog"), Ok(Command::Goto("Rust Blog".to_string())));
+        assert_eq!(
+            parse("set refresh_every = 600"),
+            Ok(Command::Set { key: "refresh_every".to_string(), value: "600".to_string() })
+        );
+    }
+
+    #[test]
+    fn parse_rejects_bad_input() {
+        assert_eq!(parse("frobnicate"), Err("Unknown command: frobnicate".to_string()));
+        assert!(parse("goto").is_err());
+        assert!(parse("set refresh_every").is_err());
+        assert!(parse("refresh now").is_err());
+    }
+
+    #[test]
+    fn apply_setting_updates_config() {
+        let mut config = Config::default();
+        apply_setting(&mut config, "refresh_every", "600").unwrap();
+        apply_setting(&mut config, "mark_read_on", "open").unwrap();
+        apply_setting(&mut config, "wrap_navigation", "false").unwrap();
+        assert_eq!(config.refresh_every, 600);
+        assert_eq!(config.display.mark_read_on, "open");
+        assert!(!config.display.wrap_navigation);
+
+        assert!(apply_setting(&mut config, "refresh_every", "0").is_err());
+        assert!(apply_setting(&mut config, "mark_read_on", "never").is_err());
+        assert!(apply_setting(&mut config, "dedup_by_url", "maybe").is_err());
+        assert!(apply_setting(&mut config, "colour", "red").is_err());
+        assert_eq!(config.refresh_every, 600);
+    }
+}
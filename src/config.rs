@@ -5,6 +5,9 @@ use anyhow::Context;
 use crossterm::event::{KeyCode, KeyModifiers};
 use serde::{Deserialize, Serialize};
 use serde_yaml::Value;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::app::ActivePane;
 
 /// Top-level application configuration.
 ///
@@ -24,6 +27,18 @@ pub struct Config {
     #[serde(default)]
     pub display: DisplayConfig,
 
+    /// Desktop notification settings.
+    #[serde(default)]
+    pub notifications: NotificationConfig,
+
+    /// Network settings for feed fetching.
+    #[serde(default)]
+    pub network: NetworkConfig,
+
+    /// Diagnostics log settings.
+    #[serde(default)]
+    pub logging: LoggingConfig,
+
     /// List of RSS/Atom feed sources - can be standalone feeds or groups.
     #[serde(default)]
     pub feeds: Vec<FeedConfigItem>,
@@ -33,7 +48,49 @@ pub struct Config {
     pub keybindings: KeyBindings,
 }
 
+/// Desktop notifications for new articles.
+///
+/// Only feeds with `notify: true` trigger notifications.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct NotificationConfig {
+    /// Whether to send notifications at all.
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// Diagnostics log for fetch and database failures.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct LoggingConfig {
+    /// Whether to write `$XDG_STATE_HOME/lazyrss/lazyrss.log`.
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// Network settings for feed fetching.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NetworkConfig {
+    /// How many feeds may be fetched at the same time.
+    #[serde(default = "default_max_concurrent_fetches")]
+    pub max_concurrent_fetches: usize,
+
+    /// Automatic refreshes start each feed's fetch after a random delay of
+    /// up to this many seconds, so they don't all hit at once.
+    #[serde(default)]
+    pub refresh_jitter_secs: u64,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_fetches: default_max_concurrent_fetches(),
+            refresh_jitter_secs: 0,
+        }
+    }
+}
+
 /// Keybinding configuration for all actions.
+///
+/// Each action takes a single key binding or a list of alternatives.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct KeyBindings {
     /// Global keybindings (work in all panes).
@@ -57,115 +114,188 @@ pub struct KeyBindings {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct GlobalKeyBindings {
     /// Quit the application.
-    #[serde(default = "default_quit")]
+    #[serde(default = "default_quit", deserialize_with = "one_or_many")]
     pub quit: Vec<KeyBinding>,
 
     /// Switch focus to the next pane.
-    #[serde(default = "default_focus_next")]
+    #[serde(default = "default_focus_next", deserialize_with = "one_or_many")]
     pub focus_next: Vec<KeyBinding>,
 
     /// Switch focus to the previous pane.
-    #[serde(default = "default_focus_prev")]
+    #[serde(default = "default_focus_prev", deserialize_with = "one_or_many")]
     pub focus_prev: Vec<KeyBinding>,
 
-    /// Refresh the current feed.
-    #[serde(default = "default_refresh_current")]
-    pub refresh_current: KeyBinding,
+    /// Focus the feeds pane. Plain digits are taken by count prefixes, so
+    /// the pane jumps default to Alt+digit.
+    #[serde(default = "default_focus_feeds", deserialize_with = "one_or_many")]
+    pub focus_feeds: Vec<KeyBinding>,
+
+    /// Focus the articles pane.
+    #[serde(default = "default_focus_articles", deserialize_with = "one_or_many")]
+    pub focus_articles: Vec<KeyBinding>,
+
+    /// Focus the article view.
+    #[serde(default = "default_focus_article_view", deserialize_with = "one_or_many")]
+    pub focus_article_view: Vec<KeyBinding>,
+
+    /// Refresh the current feed, or every feed in the selected group.
+    #[serde(default = "default_refresh_current", deserialize_with = "one_or_many")]
+    pub refresh_current: Vec<KeyBinding>,
 
     /// Refresh all feeds.
-    #[serde(default = "default_refresh_all")]
-    pub refresh_all: KeyBinding,
+    #[serde(default = "default_refresh_all", deserialize_with = "one_or_many")]
+    pub refresh_all: Vec<KeyBinding>,
 
     /// Open the selected article in a browser.
-    #[serde(default = "default_open_browser")]
-    pub open_browser: KeyBinding,
+    #[serde(default = "default_open_browser", deserialize_with = "one_or_many")]
+    pub open_browser: Vec<KeyBinding>,
+
+    /// Open the website of the selected feed (or of the selected article's
+    /// feed) in a browser.
+    #[serde(default = "default_open_feed_site", deserialize_with = "one_or_many")]
+    pub open_feed_site: Vec<KeyBinding>,
+
+    /// Switch article times between 12- and 24-hour clocks for the session.
+    #[serde(default = "default_toggle_time_format", deserialize_with = "one_or_many")]
+    pub toggle_time_format: Vec<KeyBinding>,
 
     /// Jump to the top of the list.
-    #[serde(default = "default_jump_top")]
-    pub jump_top: KeyBinding,
+    #[serde(default = "default_jump_top", deserialize_with = "one_or_many")]
+    pub jump_top: Vec<KeyBinding>,
 
     /// Jump to the bottom of the list.
-    #[serde(default = "default_jump_bottom")]
-    pub jump_bottom: KeyBinding,
+    #[serde(default = "default_jump_bottom", deserialize_with = "one_or_many")]
+    pub jump_bottom: Vec<KeyBinding>,
 
     /// Create a new group.
-    #[serde(default = "default_create_group")]
-    pub create_group: KeyBinding,
+    #[serde(default = "default_create_group", deserialize_with = "one_or_many")]
+    pub create_group: Vec<KeyBinding>,
 
     /// Create a new feed.
-    #[serde(default = "default_create_feed")]
-    pub create_feed: KeyBinding,
+    #[serde(default = "default_create_feed", deserialize_with = "one_or_many")]
+    pub create_feed: Vec<KeyBinding>,
+
+    /// Jump to the next unread article, moving on to the next feed with
+    /// unread articles once the current list is exhausted.
+    #[serde(default = "default_next_unread", deserialize_with = "one_or_many")]
+    pub next_unread: Vec<KeyBinding>,
+
+    /// Copy the selected article's enclosure (e.g. podcast audio) URL.
+    #[serde(default = "default_copy_enclosure", deserialize_with = "one_or_many")]
+    pub copy_enclosure: Vec<KeyBinding>,
+
+    /// Open an image from the selected article in a browser; a count
+    /// prefix picks which one.
+    #[serde(default = "default_open_image", deserialize_with = "one_or_many")]
+    pub open_image: Vec<KeyBinding>,
+
+    /// Fetch the selected article's web page and show the text extracted
+    /// from it.
+    #[serde(default = "default_fetch_full_article", deserialize_with = "one_or_many")]
+    pub fetch_full_article: Vec<KeyBinding>,
+
+    /// Open the `:` command prompt.
+    #[serde(default = "default_command", deserialize_with = "one_or_many")]
+    pub command: Vec<KeyBinding>,
+
+    /// Widen the feeds pane for the session.
+    #[serde(default = "default_grow_feeds_pane", deserialize_with = "one_or_many")]
+    pub grow_feeds_pane: Vec<KeyBinding>,
+
+    /// Narrow the feeds pane for the session.
+    #[serde(default = "default_shrink_feeds_pane", deserialize_with = "one_or_many")]
+    pub shrink_feeds_pane: Vec<KeyBinding>,
 }
 
 /// Keybindings for the Feeds pane.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct FeedsKeyBindings {
     /// Move selection down.
-    #[serde(default = "default_move_down")]
+    #[serde(default = "default_move_down", deserialize_with = "one_or_many")]
     pub move_down: Vec<KeyBinding>,
 
     /// Move selection up.
-    #[serde(default = "default_move_up")]
+    #[serde(default = "default_move_up", deserialize_with = "one_or_many")]
     pub move_up: Vec<KeyBinding>,
 
     /// Select the current feed/group.
-    #[serde(default = "default_select")]
-    pub select: KeyBinding,
+    #[serde(default = "default_select", deserialize_with = "one_or_many")]
+    pub select: Vec<KeyBinding>,
 
     /// Toggle collapse of the current group.
-    #[serde(default = "default_toggle_collapse")]
-    pub toggle_collapse: KeyBinding,
+    #[serde(default = "default_toggle_collapse", deserialize_with = "one_or_many")]
+    pub toggle_collapse: Vec<KeyBinding>,
 
     /// Expand all groups.
-    #[serde(default = "default_expand_all")]
+    #[serde(default = "default_expand_all", deserialize_with = "one_or_many")]
     pub expand_all: Vec<KeyBinding>,
 
     /// Collapse all groups.
-    #[serde(default = "default_collapse_all")]
+    #[serde(default = "default_collapse_all", deserialize_with = "one_or_many")]
     pub collapse_all: Vec<KeyBinding>,
 
     /// Scroll half-page down.
-    #[serde(default = "default_scroll_half_page_down")]
+    #[serde(default = "default_scroll_half_page_down", deserialize_with = "one_or_many")]
     pub scroll_half_page_down: Vec<KeyBinding>,
 
     /// Scroll half-page up.
-    #[serde(default = "default_scroll_half_page_up")]
+    #[serde(default = "default_scroll_half_page_up", deserialize_with = "one_or_many")]
     pub scroll_half_page_up: Vec<KeyBinding>,
+
+    /// Move the selected feed or group up among its siblings.
+    #[serde(default = "default_move_item_up", deserialize_with = "one_or_many")]
+    pub move_item_up: Vec<KeyBinding>,
+
+    /// Move the selected feed or group down among its siblings.
+    #[serde(default = "default_move_item_down", deserialize_with = "one_or_many")]
+    pub move_item_down: Vec<KeyBinding>,
+
+    /// Move the selected feed into another group.
+    #[serde(default = "default_move_to_group", deserialize_with = "one_or_many")]
+    pub move_to_group: Vec<KeyBinding>,
 }
 
 /// Keybindings for the Articles pane.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ArticlesKeyBindings {
     /// Move selection down.
-    #[serde(default = "default_move_down")]
+    #[serde(default = "default_move_down", deserialize_with = "one_or_many")]
     pub move_down: Vec<KeyBinding>,
 
     /// Move selection up.
-    #[serde(default = "default_move_up")]
+    #[serde(default = "default_move_up", deserialize_with = "one_or_many")]
     pub move_up: Vec<KeyBinding>,
 
     /// Select/open the current article.
-    #[serde(default = "default_select")]
-    pub select: KeyBinding,
+    #[serde(default = "default_select", deserialize_with = "one_or_many")]
+    pub select: Vec<KeyBinding>,
 
     /// Toggle read status of the current article.
-    #[serde(default = "default_toggle_read")]
-    pub toggle_read: KeyBinding,
+    #[serde(default = "default_toggle_read", deserialize_with = "one_or_many")]
+    pub toggle_read: Vec<KeyBinding>,
 
     /// Toggle star status of the current article.
-    #[serde(default = "default_toggle_star")]
-    pub toggle_star: KeyBinding,
+    #[serde(default = "default_toggle_star", deserialize_with = "one_or_many")]
+    pub toggle_star: Vec<KeyBinding>,
+
+    /// Add the current article to or remove it from the "read later" queue.
+    #[serde(default = "default_toggle_queue", deserialize_with = "one_or_many")]
+    pub toggle_queue: Vec<KeyBinding>,
 
     /// Mark all articles in the current feed as read.
-    #[serde(default = "default_mark_all_read")]
-    pub mark_all_read: KeyBinding,
+    #[serde(default = "default_mark_all_read", deserialize_with = "one_or_many")]
+    pub mark_all_read: Vec<KeyBinding>,
+
+    /// Mark every article above the current one as read.
+    #[serde(default = "default_mark_read_to_here", deserialize_with = "one_or_many")]
+    pub mark_read_to_here: Vec<KeyBinding>,
 
     /// Scroll half-page down.
-    #[serde(default = "default_scroll_half_page_down")]
+    #[serde(default = "default_scroll_half_page_down", deserialize_with = "one_or_many")]
     pub scroll_half_page_down: Vec<KeyBinding>,
 
     /// Scroll half-page up.
-    #[serde(default = "default_scroll_half_page_up")]
+    #[serde(default = "default_scroll_half_page_up", deserialize_with = "one_or_many")]
     pub scroll_half_page_up: Vec<KeyBinding>,
 }
 
@@ -173,20 +303,24 @@ pub struct ArticlesKeyBindings {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ArticleViewKeyBindings {
     /// Scroll content down.
-    #[serde(default = "default_scroll_down")]
+    #[serde(default = "default_scroll_down", deserialize_with = "one_or_many")]
     pub scroll_down: Vec<KeyBinding>,
 
     /// Scroll content up.
-    #[serde(default = "default_scroll_up")]
+    #[serde(default = "default_scroll_up", deserialize_with = "one_or_many")]
     pub scroll_up: Vec<KeyBinding>,
 
     /// Scroll half-page down.
-    #[serde(default = "default_scroll_half_page_down")]
+    #[serde(default = "default_scroll_half_page_down", deserialize_with = "one_or_many")]
     pub scroll_half_page_down: Vec<KeyBinding>,
 
     /// Scroll half-page up.
-    #[serde(default = "default_scroll_half_page_up")]
+    #[serde(default = "default_scroll_half_page_up", deserialize_with = "one_or_many")]
     pub scroll_half_page_up: Vec<KeyBinding>,
+
+    /// Let the article view fill the screen, hiding the other panes.
+    #[serde(default = "default_toggle_zen", deserialize_with = "one_or_many")]
+    pub toggle_zen: Vec<KeyBinding>,
 }
 
 /// A single key binding.
@@ -197,6 +331,7 @@ pub struct ArticleViewKeyBindings {
 /// - `"Enter"`, `"Tab"`, `"BackTab"`, `"Esc"`, `"Space"`, etc. - special keys
 /// - `"Up"`, `"Down"`, `"Left"`, `"Right"` - arrow keys
 /// - `"PageUp"`, `"PageDown"`, `"Home"`, `"End"` - navigation keys
+/// - `"none"` - no key; the action is unbound
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct KeyBinding {
     pub code: KeyCode,
@@ -221,13 +356,25 @@ impl Default for GlobalKeyBindings {
             quit: default_quit(),
             focus_next: default_focus_next(),
             focus_prev: default_focus_prev(),
+            focus_feeds: default_focus_feeds(),
+            focus_articles: default_focus_articles(),
+            focus_article_view: default_focus_article_view(),
             refresh_current: default_refresh_current(),
             refresh_all: default_refresh_all(),
             open_browser: default_open_browser(),
+            open_feed_site: default_open_feed_site(),
+            toggle_time_format: default_toggle_time_format(),
             jump_top: default_jump_top(),
             jump_bottom: default_jump_bottom(),
             create_group: default_create_group(),
             create_feed: default_create_feed(),
+            next_unread: default_next_unread(),
+            copy_enclosure: default_copy_enclosure(),
+            open_image: default_open_image(),
+            fetch_full_article: default_fetch_full_article(),
+            command: default_command(),
+            grow_feeds_pane: default_grow_feeds_pane(),
+            shrink_feeds_pane: default_shrink_feeds_pane(),
         }
     }
 }
@@ -243,6 +390,9 @@ impl Default for FeedsKeyBindings {
             collapse_all: default_collapse_all(),
             scroll_half_page_down: default_scroll_half_page_down(),
             scroll_half_page_up: default_scroll_half_page_up(),
+            move_item_up: default_move_item_up(),
+            move_item_down: default_move_item_down(),
+            move_to_group: default_move_to_group(),
         }
     }
 }
@@ -255,7 +405,9 @@ impl Default for ArticlesKeyBindings {
             select: default_select(),
             toggle_read: default_toggle_read(),
             toggle_star: default_toggle_star(),
+            toggle_queue: default_toggle_queue(),
             mark_all_read: default_mark_all_read(),
+            mark_read_to_here: default_mark_read_to_here(),
             scroll_half_page_down: default_scroll_half_page_down(),
             scroll_half_page_up: default_scroll_half_page_up(),
         }
@@ -269,14 +421,170 @@ impl Default for ArticleViewKeyBindings {
             scroll_up: default_scroll_up(),
             scroll_half_page_down: default_scroll_half_page_down(),
             scroll_half_page_up: default_scroll_half_page_up(),
+            toggle_zen: default_toggle_zen(),
+        }
+    }
+}
+
+/// An action's name in the config and the keys bound to it.
+type NamedBindings<'a> = (&'static str, &'a [KeyBinding]);
+
+/// Actions that may share a key, because the key toggles between them.
+const TOGGLE_PAIRS: &[[&str; 2]] = &[["expand_all", "collapse_all"]];
+
+/// Feeds-pane keys that `action::handle_key` checks directly; they can't be
+/// rebound, but other bindings can still shadow them.
+fn builtin_feeds_bindings() -> Vec<(&'static str, Vec<KeyBinding>)> {
+    let key = |code, modifiers| KeyBinding { code, modifiers };
+    vec![
+        ("delete", vec![
+            key(KeyCode::Char('d'), KeyModifiers::SHIFT),
+            key(KeyCode::Char('D'), KeyModifiers::SHIFT),
+        ]),
+        ("cut", vec![key(KeyCode::Char('x'), KeyModifiers::NONE)]),
+        ("paste", vec![key(KeyCode::Char('p'), KeyModifiers::NONE)]),
+        ("edit", vec![key(KeyCode::Char('e'), KeyModifiers::CONTROL)]),
+        ("toggle_collapse_recursive", vec![key(KeyCode::Char(' '), KeyModifiers::ALT)]),
+    ]
+}
+
+impl KeyBindings {
+    /// Describe keys bound to more than one action where only the first
+    /// would ever fire: among the global bindings, or within a pane
+    /// (including the global bindings, which are checked first).
+    ///
+    /// Binding one key to both actions of a [`TOGGLE_PAIRS`] entry is
+    /// allowed, since that toggles between them. The feeds pane's built-in
+    /// keys (delete, cut, paste, edit, Alt-Space) are checked too.
+    pub fn conflicts(&self) -> Vec<String> {
+        let global = self.global.named();
+        let builtin = builtin_feeds_bindings();
+        let mut feeds = self.feeds.named();
+        feeds.extend(builtin.iter().map(|(name, bindings)| (*name, bindings.as_slice())));
+
+        let mut conflicts = scope_conflicts(&global, &[], "globally");
+        for (pane, bindings) in [
+            ("in feeds pane", feeds),
+            ("in articles pane", self.articles.named()),
+            ("in article view", self.article_view.named()),
+        ] {
+            conflicts.extend(scope_conflicts(&global, &bindings, pane));
+        }
+        conflicts
+    }
+}
+
+/// Conflicts involving at least one of `pane`'s bindings, or among `global`
+/// ones if `pane` is empty.
+fn scope_conflicts(global: &[NamedBindings], pane: &[NamedBindings], scope: &str) -> Vec<String> {
+    let pane_names: Vec<&str> = pane.iter().map(|(name, _)| *name).collect();
+
+    // Actions per key, in the order the keys first appear
+    let mut keys: Vec<(&KeyBinding, Vec<&str>)> = Vec::new();
+    for (name, bindings) in global.iter().chain(pane) {
+        for binding in bindings.iter().filter(|b| b.code != KeyCode::Null) {
+            match keys.iter_mut().find(|(key, _)| *key == binding) {
+                Some((_, names)) if !names.contains(name) => names.push(name),
+                Some(_) => {}
+                None => keys.push((binding, vec![name])),
+            }
         }
     }
+
+    keys.into_iter()
+        .filter(|(_, names)| names.len() > 1)
+        .filter(|(_, names)| pane.is_empty() || names.iter().any(|n| pane_names.contains(n)))
+        .filter(|(_, names)| {
+            !TOGGLE_PAIRS.iter().any(|pair| names.len() == 2 && pair.iter().all(|n| names.contains(n)))
+        })
+        .map(|(key, names)| {
+            format!("'{}' bound to multiple actions {} ({})", key.display(), scope, names.join(", "))
+        })
+        .collect()
+}
+
+impl GlobalKeyBindings {
+    fn named(&self) -> Vec<NamedBindings<'_>> {
+        vec![
+            ("quit", &self.quit),
+            ("focus_next", &self.focus_next),
+            ("focus_prev", &self.focus_prev),
+            ("focus_feeds", &self.focus_feeds),
+            ("focus_articles", &self.focus_articles),
+            ("focus_article_view", &self.focus_article_view),
+            ("refresh_current", &self.refresh_current),
+            ("refresh_all", &self.refresh_all),
+            ("open_browser", &self.open_browser),
+            ("open_feed_site", &self.open_feed_site),
+            ("toggle_time_format", &self.toggle_time_format),
+            ("jump_top", &self.jump_top),
+            ("jump_bottom", &self.jump_bottom),
+            ("create_group", &self.create_group),
+            ("create_feed", &self.create_feed),
+            ("next_unread", &self.next_unread),
+            ("copy_enclosure", &self.copy_enclosure),
+            ("open_image", &self.open_image),
+            ("fetch_full_article", &self.fetch_full_article),
+            ("command", &self.command),
+            ("grow_feeds_pane", &self.grow_feeds_pane),
+            ("shrink_feeds_pane", &self.shrink_feeds_pane),
+        ]
+    }
+}
+
+impl FeedsKeyBindings {
+    fn named(&self) -> Vec<NamedBindings<'_>> {
+        vec![
+            ("move_down", &self.move_down),
+            ("move_up", &self.move_up),
+            ("select", &self.select),
+            ("toggle_collapse", &self.toggle_collapse),
+            ("expand_all", &self.expand_all),
+            ("collapse_all", &self.collapse_all),
+            ("scroll_half_page_down", &self.scroll_half_page_down),
+            ("scroll_half_page_up", &self.scroll_half_page_up),
+            ("move_item_up", &self.move_item_up),
+            ("move_item_down", &self.move_item_down),
+            ("move_to_group", &self.move_to_group),
+        ]
+    }
 }
 
+impl ArticlesKeyBindings {
+    fn named(&self) -> Vec<NamedBindings<'_>> {
+        vec![
+            ("move_down", &self.move_down),
+            ("move_up", &self.move_up),
+            ("select", &self.select),
+            ("toggle_read", &self.toggle_read),
+            ("toggle_star", &self.toggle_star),
+            ("toggle_queue", &self.toggle_queue),
+            ("mark_all_read", &self.mark_all_read),
+            ("mark_read_to_here", &self.mark_read_to_here),
+            ("scroll_half_page_down", &self.scroll_half_page_down),
+            ("scroll_half_page_up", &self.scroll_half_page_up),
+        ]
+    }
+}
+
+impl ArticleViewKeyBindings {
+    fn named(&self) -> Vec<NamedBindings<'_>> {
+        vec![
+            ("scroll_down", &self.scroll_down),
+            ("scroll_up", &self.scroll_up),
+            ("scroll_half_page_down", &self.scroll_half_page_down),
+            ("scroll_half_page_up", &self.scroll_half_page_up),
+            ("toggle_zen", &self.toggle_zen),
+        ]
+    }
+}
+
+use keybinding_serde::one_or_many;
+
 // KeyBinding parsing and serialization implementation
 mod keybinding_serde {
     use super::*;
-    use serde::de::{Error, Visitor};
+    use serde::de::{Error, SeqAccess, Visitor};
     use std::fmt;
 
     // Helper to parse a key binding string
@@ -329,7 +637,7 @@ mod keybinding_serde {
             "f10" => KeyCode::F(10),
             "f11" => KeyCode::F(11),
             "f12" => KeyCode::F(12),
-            "null" | "nop" => KeyCode::Null,
+            "null" | "nop" | "none" => KeyCode::Null,
             s if s.len() == 1 => KeyCode::Char(key.chars().next().unwrap()),
             _ => return Err(format!("Unknown key: {}", key)),
         };
@@ -344,6 +652,48 @@ mod keybinding_serde {
         Ok(KeyBinding { code, modifiers })
     }
 
+    /// Deserialize an action's keys from either a single key binding
+    /// (`"Enter"`) or a list of them (`["Enter", "l"]`).
+    ///
+    /// `"none"` (or an empty list) leaves the action unbound.
+    pub fn one_or_many<'de, D>(deserializer: D) -> Result<Vec<KeyBinding>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct OneOrManyVisitor;
+
+        impl<'de> Visitor<'de> for OneOrManyVisitor {
+            type Value = Vec<KeyBinding>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a key binding string or a list of them")
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                let kb = parse_keybinding(s).map_err(Error::custom)?;
+                Ok(if kb.code == KeyCode::Null { Vec::new() } else { vec![kb] })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut bindings = Vec::new();
+                while let Some(binding) = seq.next_element::<KeyBinding>()? {
+                    if binding.code != KeyCode::Null {
+                        bindings.push(binding);
+                    }
+                }
+                Ok(bindings)
+            }
+        }
+
+        deserializer.deserialize_any(OneOrManyVisitor)
+    }
+
     impl<'de> Deserialize<'de> for KeyBinding {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
@@ -385,6 +735,11 @@ impl serde::Serialize for KeyBinding {
 // Implement matching for KeyBinding
 impl KeyBinding {
     pub fn matches(&self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        // An unbound key never fires, even if the terminal reports Null
+        if self.code == KeyCode::Null {
+            return false;
+        }
+
         // Special case for BackTab (Shift+Tab):
         // Different terminals report this key differently:
         // - Some: BackTab with NONE modifiers
@@ -497,6 +852,92 @@ pub struct DisplayConfig {
     /// Color configuration for the UI.
     #[serde(default)]
     pub colours: ColourConfig,
+
+    /// Layout of each row in the articles list.
+    ///
+    /// Supported tokens: `{unread}`, `{star}`, `{queue}`, `{date}`, `{feed}`,
+    /// `{title}`.
+    /// Unknown tokens are rendered literally. When `{date}` is absent the date
+    /// is shown right-aligned on its own line below the title.
+    #[serde(default = "default_article_row_format")]
+    pub article_row_format: String,
+
+    /// Collapse articles sharing the same URL (ignoring query strings and
+    /// trailing slashes) in group and "All" views. Reading, starring or
+    /// queueing one copy applies to every copy, and unread counts count
+    /// them once.
+    #[serde(default)]
+    pub dedup_by_url: bool,
+
+    /// When an article counts as read: "select" (on moving to it), "open"
+    /// (only on Enter or opening in the browser) or "scroll" (once the
+    /// article view is scrolled past halfway or to its end, once it fits the
+    /// focused article view, or on open).
+    #[serde(default = "default_mark_read_on")]
+    pub mark_read_on: String,
+
+    /// How far back (in hours) the "Recent" feed-list entry looks.
+    #[serde(default = "default_recent_window_hours")]
+    pub recent_window_hours: u64,
+
+    /// Whether moving past the last row of a list wraps to the first (and
+    /// vice versa); when false, movement stops at the ends.
+    #[serde(default = "default_wrap_navigation")]
+    pub wrap_navigation: bool,
+
+    /// Show the article's first image at the top of the article view on
+    /// terminals with a graphics protocol (kitty, sixel or iTerm2), detected
+    /// at startup. PNG, JPEG, GIF and WebP images can be shown.
+    #[serde(default)]
+    pub inline_images: bool,
+
+    /// Remove tracking query parameters (`utm_*`, `fbclid`, `gclid`, ...)
+    /// from article and enclosure URLs before they are stored.
+    #[serde(default = "default_strip_tracking_params")]
+    pub strip_tracking_params: bool,
+
+    /// Mark a read article unread again when a refresh finds its text was
+    /// edited (useful for changelogs and other living documents).
+    #[serde(default)]
+    pub unread_on_update: bool,
+
+    /// Ask before marking every article read from the "All" entry.
+    #[serde(default = "default_confirm_mark_all_read")]
+    pub confirm_mark_all_read: bool,
+
+    /// Show "unread/total" article counts in the feeds pane instead of just
+    /// the unread count. Counting every article adds a little work to each
+    /// feeds reload.
+    #[serde(default)]
+    pub show_total_counts: bool,
+
+    /// Sort feeds alphabetically within each group, ignoring case and
+    /// accents, instead of keeping their order from the config file.
+    #[serde(default)]
+    pub sort_feeds: bool,
+
+    /// Show a dimmed one-line excerpt of each article below its title in
+    /// the articles list.
+    #[serde(default)]
+    pub show_preview: bool,
+
+    /// Pane focused at startup: "feeds", "articles" or "article_view".
+    #[serde(default = "default_initial_pane")]
+    pub initial_pane: String,
+
+    /// Pane arrangement: "horizontal" (side by side), "vertical" (stacked)
+    /// or "auto" (stacked in narrow terminals).
+    #[serde(default = "default_layout")]
+    pub layout: String,
+
+    /// Indicator glyphs used in the feeds and articles lists.
+    #[serde(default)]
+    pub glyphs: GlyphConfig,
+
+    /// Blank columns on each side of the text in the article view, to keep
+    /// lines short in wide panes.
+    #[serde(default)]
+    pub article_padding: u16,
 }
 
 impl Default for DisplayConfig {
@@ -505,6 +946,22 @@ impl Default for DisplayConfig {
             format: FormatConfig::default(),
             columns: ColumnConfig::default(),
             colours: ColourConfig::default(),
+            article_row_format: default_article_row_format(),
+            dedup_by_url: false,
+            mark_read_on: default_mark_read_on(),
+            recent_window_hours: default_recent_window_hours(),
+            wrap_navigation: default_wrap_navigation(),
+            inline_images: false,
+            strip_tracking_params: default_strip_tracking_params(),
+            unread_on_update: false,
+            confirm_mark_all_read: default_confirm_mark_all_read(),
+            show_total_counts: false,
+            sort_feeds: false,
+            show_preview: false,
+            initial_pane: default_initial_pane(),
+            layout: default_layout(),
+            glyphs: GlyphConfig::default(),
+            article_padding: 0,
         }
     }
 }
@@ -526,6 +983,11 @@ pub struct FormatConfig {
     /// Number of lines for article titles in the articles list (allows wrapping).
     #[serde(default = "default_title_lines")]
     pub title_lines: u8,
+
+    /// Show recent dates in the articles list relative to now ("3h", "2d").
+    /// Dates older than 30 days still use the `date` format.
+    #[serde(default)]
+    pub relative_dates: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -561,9 +1023,21 @@ pub struct ColourConfig {
     #[serde(default = "default_highlight_bg")]
     pub highlight_bg: String,
 
+    /// Background color for the selected row of an unfocused list pane.
+    #[serde(default = "default_inactive_highlight_bg")]
+    pub inactive_highlight_bg: String,
+
     /// Color for the unread indicator (filled dot) and unread counts.
     #[serde(default = "default_unread_indicator")]
     pub unread_indicator: String,
+
+    /// Background color for `<pre>` code blocks in the article view.
+    #[serde(default = "default_code_block_bg")]
+    pub code_block_bg: String,
+
+    /// Text color for read articles and fully read feeds.
+    #[serde(default = "default_read_fg")]
+    pub read_fg: String,
 }
 
 impl Default for ColourConfig {
@@ -573,7 +1047,111 @@ impl Default for ColourConfig {
             inactive_border: default_inactive_border(),
             border_type: default_border_type(),
             highlight_bg: default_highlight_bg(),
+            inactive_highlight_bg: default_inactive_highlight_bg(),
             unread_indicator: default_unread_indicator(),
+            code_block_bg: default_code_block_bg(),
+            read_fg: default_read_fg(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GlyphConfig {
+    /// Marker for unread articles.
+    #[serde(default = "default_unread_glyph")]
+    pub unread: String,
+
+    /// Marker for read articles.
+    #[serde(default = "default_read_glyph")]
+    pub read: String,
+
+    /// Marker for starred articles and the "Starred" entry.
+    #[serde(default = "default_starred_glyph")]
+    pub starred: String,
+
+    /// Marker for articles queued to read later and the "Queue" entry.
+    #[serde(default = "default_queued_glyph")]
+    pub queued: String,
+
+    /// Prefix for expanded groups.
+    #[serde(default = "default_group_open_glyph")]
+    pub group_open: String,
+
+    /// Prefix for collapsed groups.
+    #[serde(default = "default_group_closed_glyph")]
+    pub group_closed: String,
+
+    /// Prefix for feeds whose last fetch failed.
+    #[serde(default = "default_error_glyph")]
+    pub error: String,
+}
+
+impl Default for GlyphConfig {
+    fn default() -> Self {
+        Self {
+            unread: default_unread_glyph(),
+            read: default_read_glyph(),
+            starred: default_starred_glyph(),
+            queued: default_queued_glyph(),
+            group_open: default_group_open_glyph(),
+            group_closed: default_group_closed_glyph(),
+            error: default_error_glyph(),
+        }
+    }
+}
+
+/// Narrowest a pane can be made with [`ColumnConfig::resize_feeds_list`].
+const MIN_COLUMN_WIDTH: u16 = 10;
+
+impl ColumnConfig {
+    /// Widen the feeds pane by `delta` percent (narrow it when negative),
+    /// splitting the remaining width between the other two panes in their
+    /// current proportion. No pane becomes narrower than 10%, and the three
+    /// widths afterwards add up to 100.
+    pub fn resize_feeds_list(&mut self, delta: i16) {
+        let max = 100 - 2 * MIN_COLUMN_WIDTH;
+        let feeds = self.feeds_list.saturating_add_signed(delta).clamp(MIN_COLUMN_WIDTH, max);
+        let rest = 100 - feeds;
+
+        let others = u32::from(self.articles_list) + u32::from(self.article_view);
+        let articles = match others {
+            0 => rest / 2,
+            _ => (u32::from(rest) * u32::from(self.articles_list) / others) as u16,
+        };
+        let articles = articles.clamp(MIN_COLUMN_WIDTH, rest - MIN_COLUMN_WIDTH);
+
+        self.feeds_list = feeds;
+        self.articles_list = articles;
+        self.article_view = rest - articles;
+    }
+}
+
+impl GlyphConfig {
+    /// Replace every glyph that isn't a single grapheme with its default,
+    /// since longer ones would break list alignment. Returns an error naming
+    /// the glyphs that were reset, if any.
+    pub fn reset_invalid(&mut self) -> Result<(), String> {
+        let defaults = GlyphConfig::default();
+        let mut invalid = Vec::new();
+        for (name, glyph, default) in [
+            ("unread", &mut self.unread, defaults.unread),
+            ("read", &mut self.read, defaults.read),
+            ("starred", &mut self.starred, defaults.starred),
+            ("queued", &mut self.queued, defaults.queued),
+            ("group_open", &mut self.group_open, defaults.group_open),
+            ("group_closed", &mut self.group_closed, defaults.group_closed),
+            ("error", &mut self.error, defaults.error),
+        ] {
+            if glyph.graphemes(true).count() != 1 {
+                invalid.push(name);
+                *glyph = default;
+            }
+        }
+
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Glyphs must be a single character, using defaults for: {}", invalid.join(", ")))
         }
     }
 }
@@ -592,6 +1170,68 @@ pub fn parse_border_type(border_str: &str) -> Result<ratatui::widgets::BorderTyp
     }
 }
 
+/// How the three panes are arranged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaneLayout {
+    /// Side by side, left to right.
+    Horizontal,
+    /// Stacked, top to bottom.
+    Vertical,
+    /// Side by side, or stacked when the terminal is narrow.
+    Auto,
+}
+
+/// Parse a `layout` string into a [`PaneLayout`].
+pub fn parse_layout(value: &str) -> Result<PaneLayout, String> {
+    match value.to_lowercase().as_str() {
+        "horizontal" => Ok(PaneLayout::Horizontal),
+        "vertical" => Ok(PaneLayout::Vertical),
+        "auto" => Ok(PaneLayout::Auto),
+        _ => Err(format!(
+            "Unknown layout value: {}. Valid options: horizontal, vertical, auto",
+            value
+        )),
+    }
+}
+
+/// Parse a pane name from `display.initial_pane`.
+pub fn parse_pane(value: &str) -> Result<ActivePane, String> {
+    match value.to_lowercase().as_str() {
+        "feeds" => Ok(ActivePane::Feeds),
+        "articles" => Ok(ActivePane::Articles),
+        "article_view" => Ok(ActivePane::ArticleView),
+        _ => Err(format!(
+            "Unknown initial_pane value: {}. Valid options: feeds, articles, article_view",
+            value
+        )),
+    }
+}
+
+/// Policy for automatically marking articles as read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkReadOn {
+    /// Mark read as soon as the article is selected in the list.
+    Select,
+    /// Mark read only when explicitly opened (Enter or browser).
+    Open,
+    /// Mark read once the article view is scrolled past halfway or to its end,
+    /// or is focused on an article too short to scroll.
+    Scroll,
+}
+
+/// Parse a `mark_read_on` string into a [`MarkReadOn`] policy.
+pub fn parse_mark_read_on(value: &str) -> Result<MarkReadOn, String> {
+    match value.to_lowercase().as_str() {
+        "select" => Ok(MarkReadOn::Select),
+        "open" => Ok(MarkReadOn::Open),
+        "scroll" => Ok(MarkReadOn::Scroll),
+        _ => Err(format!(
+            "Unknown mark_read_on value: {}. Valid options: select, open, scroll",
+            value
+        )),
+    }
+}
+
 /// Parse a color name into ratatui::Color.
 pub fn parse_color(color_str: &str) -> Result<ratatui::style::Color, String> {
     match color_str.to_lowercase().as_str() {
@@ -612,7 +1252,6 @@ pub fn parse_color(color_str: &str) -> Result<ratatui::style::Color, String> {
         "lightmagenta" | "light_magenta" => Ok(ratatui::style::Color::LightMagenta),
         "lightcyan" | "light_cyan" => Ok(ratatui::style::Color::LightCyan),
         "lightwhite" | "light_white" => Ok(ratatui::style::Color::White),
-        // Indexed colors (0-255)
         s if s.starts_with('#') => {
             // Try to parse as RGB hex
             let hex = &s[1..];
@@ -625,12 +1264,40 @@ pub fn parse_color(color_str: &str) -> Result<ratatui::style::Color, String> {
                 Err(format!("Invalid hex color format: {}", color_str))
             }
         }
+        // RGB function form: rgb(r, g, b)
+        s if s.starts_with("rgb(") && s.ends_with(')') => {
+            let components: Vec<&str> = s[4..s.len() - 1].split(',').map(str::trim).collect();
+            match components.as_slice() {
+                [r, g, b] => match (r.parse::<u8>(), g.parse::<u8>(), b.parse::<u8>()) {
+                    (Ok(r), Ok(g), Ok(b)) => Ok(ratatui::style::Color::Rgb(r, g, b)),
+                    _ => Err(format!(
+                        "Invalid rgb color: {}. Components must be 0-255",
+                        color_str
+                    )),
+                },
+                _ => Err(format!(
+                    "Invalid rgb color format: {}. Expected rgb(r, g, b)",
+                    color_str
+                )),
+            }
+        }
+        // Indexed colors (0-255): "color123" or bare "123"
+        s if s.starts_with("color") || s.chars().all(|c| c.is_ascii_digit()) => {
+            let index = s.strip_prefix("color").unwrap_or(s);
+            if index.is_empty() || !index.chars().all(|c| c.is_ascii_digit()) {
+                return Err(format!("Unknown color: {}", color_str));
+            }
+            index
+                .parse::<u8>()
+                .map(ratatui::style::Color::Indexed)
+                .map_err(|_| format!("Invalid indexed color: {}. Index must be 0-255", color_str))
+        }
         _ => Err(format!("Unknown color: {}", color_str)),
     }
 }
 
 /// A single feed source within a group.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct FeedSource {
     pub title: String,
     /// URL to the website (for reference, opening in browser).
@@ -639,6 +1306,35 @@ pub struct FeedSource {
     /// If not provided, the `url` field will be used as the feed URL.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub feed: Option<String>,
+    /// Per-feed read policy ("select", "open" or "scroll").
+    ///
+    /// Takes precedence over `display.mark_read_on` while this feed's own
+    /// article list is shown. Group and "All" views mix feeds, so they always
+    /// use `display.mark_read_on`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mark_read_on: Option<String>,
+    /// Send a desktop notification when this feed gets new articles
+    /// (requires `notifications.enabled`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub notify: bool,
+    /// Show the title the feed gives itself instead of `title`, which is
+    /// then only used until the feed has been fetched.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub use_feed_title: bool,
+    /// Fetch each article's web page when it is opened and show the text
+    /// extracted from it, for feeds that only carry a teaser.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub fetch_full_content: bool,
+}
+
+impl FeedSource {
+    /// URL the feed is fetched from: `feed` if set, otherwise `url`.
+    ///
+    /// This is the URL stored as `feeds.url` in the database, so it's also
+    /// what identifies the feed when editing, deleting or cutting it.
+    pub fn feed_url(&self) -> &str {
+        self.feed.as_deref().unwrap_or(&self.url)
+    }
 }
 
 /// A named group of feeds (e.g. "Tech", "News (World)").
@@ -675,6 +1371,15 @@ pub fn collect_empty_groups_from_config(config: &Config) -> Vec<String> {
     result
 }
 
+/// Collect the full paths of every group (including nested ones) in the config.
+pub fn collect_group_paths_from_config(config: &Config) -> Vec<String> {
+    let mut result = Vec::new();
+    for item in &config.feeds {
+        item.collect_group_paths_recursive(None, &mut result);
+    }
+    result
+}
+
 impl FeedConfigItem {
     /// Recursively iterate over all feeds, collecting them with their full group path.
     ///
@@ -740,6 +1445,21 @@ impl FeedConfigItem {
             }
         }
     }
+
+    fn collect_group_paths_recursive(&self, current_path: Option<String>, result: &mut Vec<String>) {
+        if let FeedConfigItem::Group(group) = self {
+            let new_path = if let Some(ref path) = current_path {
+                format!("{} > {}", path, group.title)
+            } else {
+                group.title.clone()
+            };
+
+            result.push(new_path.clone());
+            for item in &group.feeds {
+                item.collect_group_paths_recursive(Some(new_path.clone()), result);
+            }
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -754,6 +1474,10 @@ fn default_refresh_on_start() -> bool {
     true
 }
 
+fn default_max_concurrent_fetches() -> usize {
+    16
+}
+
 fn default_time_format() -> u8 {
     12
 }
@@ -766,6 +1490,66 @@ fn default_title_lines() -> u8 {
     2
 }
 
+fn default_article_row_format() -> String {
+    "{unread} {star} {queue} {title}".to_string()
+}
+
+fn default_mark_read_on() -> String {
+    "select".to_string()
+}
+
+fn default_initial_pane() -> String {
+    "articles".to_string()
+}
+
+fn default_layout() -> String {
+    "horizontal".to_string()
+}
+
+fn default_recent_window_hours() -> u64 {
+    24
+}
+
+fn default_wrap_navigation() -> bool {
+    true
+}
+
+fn default_strip_tracking_params() -> bool {
+    true
+}
+
+fn default_confirm_mark_all_read() -> bool {
+    true
+}
+
+fn default_unread_glyph() -> String {
+    "\u{25CF}".to_string()
+}
+
+fn default_read_glyph() -> String {
+    "\u{25CB}".to_string()
+}
+
+fn default_starred_glyph() -> String {
+    "\u{2605}".to_string()
+}
+
+fn default_queued_glyph() -> String {
+    "\u{23F2}".to_string()
+}
+
+fn default_group_open_glyph() -> String {
+    "\u{25BC}".to_string()
+}
+
+fn default_group_closed_glyph() -> String {
+    "\u{25B6}".to_string()
+}
+
+fn default_error_glyph() -> String {
+    "\u{2717}".to_string()
+}
+
 fn default_feeds_list_width() -> u16 {
     25
 }
@@ -794,10 +1578,22 @@ fn default_highlight_bg() -> String {
     "darkgray".to_string()
 }
 
+fn default_inactive_highlight_bg() -> String {
+    "236".to_string()
+}
+
 fn default_unread_indicator() -> String {
     "cyan".to_string()
 }
 
+fn default_code_block_bg() -> String {
+    "236".to_string()
+}
+
+fn default_read_fg() -> String {
+    "darkgray".to_string()
+}
+
 // Keybinding defaults
 fn parse_kb(s: &str) -> KeyBinding {
     keybinding_serde::parse_keybinding(s).unwrap()
@@ -815,6 +1611,18 @@ fn default_focus_prev() -> Vec<KeyBinding> {
     vec![parse_kb("BackTab")]
 }
 
+fn default_focus_feeds() -> Vec<KeyBinding> {
+    vec![parse_kb("Alt-1")]
+}
+
+fn default_focus_articles() -> Vec<KeyBinding> {
+    vec![parse_kb("Alt-2")]
+}
+
+fn default_focus_article_view() -> Vec<KeyBinding> {
+    vec![parse_kb("Alt-3")]
+}
+
 fn default_move_down() -> Vec<KeyBinding> {
     vec![parse_kb("j"), parse_kb("Down")]
 }
@@ -823,12 +1631,12 @@ fn default_move_up() -> Vec<KeyBinding> {
     vec![parse_kb("k"), parse_kb("Up")]
 }
 
-fn default_select() -> KeyBinding {
-    parse_kb("Enter")
+fn default_select() -> Vec<KeyBinding> {
+    vec![parse_kb("Enter")]
 }
 
-fn default_toggle_collapse() -> KeyBinding {
-    parse_kb("Space")
+fn default_toggle_collapse() -> Vec<KeyBinding> {
+    vec![parse_kb("Space")]
 }
 
 fn default_expand_all() -> Vec<KeyBinding> {
@@ -839,16 +1647,38 @@ fn default_collapse_all() -> Vec<KeyBinding> {
     vec![parse_kb("E")]
 }
 
-fn default_toggle_read() -> KeyBinding {
-    parse_kb("m")
+fn default_move_item_up() -> Vec<KeyBinding> {
+    vec![parse_kb("K"), parse_kb("Alt-Up")]
+}
+
+fn default_move_item_down() -> Vec<KeyBinding> {
+    vec![parse_kb("J"), parse_kb("Alt-Down")]
 }
 
-fn default_toggle_star() -> KeyBinding {
-    parse_kb("s")
+fn default_move_to_group() -> Vec<KeyBinding> {
+    vec![parse_kb("m")]
 }
 
-fn default_mark_all_read() -> KeyBinding {
-    parse_kb("M")
+fn default_toggle_read() -> Vec<KeyBinding> {
+    vec![parse_kb("m")]
+}
+
+fn default_toggle_star() -> Vec<KeyBinding> {
+    vec![parse_kb("s")]
+}
+
+fn default_toggle_queue() -> Vec<KeyBinding> {
+    // "l" for "later"; q already quits
+    vec![parse_kb("l")]
+}
+
+fn default_mark_all_read() -> Vec<KeyBinding> {
+    vec![parse_kb("M")]
+}
+
+fn default_mark_read_to_here() -> Vec<KeyBinding> {
+    // Not Ctrl-m: terminals send that as Enter
+    vec![parse_kb("Ctrl-r")]
 }
 
 fn default_scroll_down() -> Vec<KeyBinding> {
@@ -867,32 +1697,72 @@ fn default_scroll_half_page_up() -> Vec<KeyBinding> {
     vec![parse_kb("Ctrl-u"), parse_kb("PageUp")]
 }
 
-fn default_refresh_current() -> KeyBinding {
-    parse_kb("r")
+fn default_toggle_zen() -> Vec<KeyBinding> {
+    vec![parse_kb("z")]
 }
 
-fn default_refresh_all() -> KeyBinding {
-    parse_kb("R")
+fn default_refresh_current() -> Vec<KeyBinding> {
+    vec![parse_kb("r")]
 }
 
-fn default_open_browser() -> KeyBinding {
-    parse_kb("o")
+fn default_refresh_all() -> Vec<KeyBinding> {
+    vec![parse_kb("R")]
 }
 
-fn default_jump_top() -> KeyBinding {
-    parse_kb("g")
+fn default_open_browser() -> Vec<KeyBinding> {
+    vec![parse_kb("o")]
 }
 
-fn default_jump_bottom() -> KeyBinding {
-    parse_kb("G")
+fn default_open_feed_site() -> Vec<KeyBinding> {
+    vec![parse_kb("O")]
 }
 
-fn default_create_group() -> KeyBinding {
-    parse_kb("Ctrl-g")
+fn default_toggle_time_format() -> Vec<KeyBinding> {
+    vec![parse_kb("T")]
 }
 
-fn default_create_feed() -> KeyBinding {
-    parse_kb("Ctrl-n")
+fn default_grow_feeds_pane() -> Vec<KeyBinding> {
+    vec![parse_kb("Alt-l")]
+}
+
+fn default_shrink_feeds_pane() -> Vec<KeyBinding> {
+    vec![parse_kb("Alt-h")]
+}
+
+fn default_jump_top() -> Vec<KeyBinding> {
+    vec![parse_kb("g")]
+}
+
+fn default_jump_bottom() -> Vec<KeyBinding> {
+    vec![parse_kb("G")]
+}
+
+fn default_create_group() -> Vec<KeyBinding> {
+    vec![parse_kb("Ctrl-g")]
+}
+
+fn default_create_feed() -> Vec<KeyBinding> {
+    vec![parse_kb("Ctrl-n")]
+}
+
+fn default_next_unread() -> Vec<KeyBinding> {
+    vec![parse_kb("n")]
+}
+
+fn default_copy_enclosure() -> Vec<KeyBinding> {
+    vec![parse_kb("y")]
+}
+
+fn default_open_image() -> Vec<KeyBinding> {
+    vec![parse_kb("i")]
+}
+
+fn default_fetch_full_article() -> Vec<KeyBinding> {
+    vec![parse_kb("F")]
+}
+
+fn default_command() -> Vec<KeyBinding> {
+    vec![parse_kb(":")]
 }
 
 impl Default for Config {
@@ -901,6 +1771,9 @@ impl Default for Config {
             refresh_every: default_refresh_every(),
             refresh_on_start: default_refresh_on_start(),
             display: DisplayConfig::default(),
+            notifications: NotificationConfig::default(),
+            network: NetworkConfig::default(),
+            logging: LoggingConfig::default(),
             feeds: Vec::new(),
             keybindings: KeyBindings::default(),
         }
@@ -914,6 +1787,7 @@ impl Default for FormatConfig {
             date: default_date_format(),
             date_detail: default_date_format(),
             title_lines: default_title_lines(),
+            relative_dates: false,
         }
     }
 }
@@ -1055,9 +1929,21 @@ mod tests {
         assert_eq!(cfg.display.format.time, 12);
         assert_eq!(cfg.display.format.date, "D MMM YYYY");
         assert_eq!(cfg.display.format.date_detail, "D MMM YYYY");
+        assert!(!cfg.display.format.relative_dates);
         assert_eq!(cfg.display.columns.feeds_list, 25);
         assert_eq!(cfg.display.columns.articles_list, 35);
         assert_eq!(cfg.display.columns.article_view, 40);
+        assert_eq!(cfg.display.article_row_format, "{unread} {star} {queue} {title}");
+        assert!(!cfg.display.dedup_by_url);
+        assert_eq!(cfg.display.mark_read_on, "select");
+        assert_eq!(cfg.display.recent_window_hours, 24);
+        assert!(cfg.display.strip_tracking_params);
+        assert!(!cfg.display.unread_on_update);
+        assert!(cfg.display.confirm_mark_all_read);
+        assert!(!cfg.display.show_total_counts);
+        assert!(!cfg.display.sort_feeds);
+        assert!(!cfg.display.show_preview);
+        assert_eq!(cfg.display.initial_pane, "articles");
         assert!(cfg.feeds.is_empty());
         // Check keybindings have defaults
         assert!(!cfg.keybindings.global.quit.is_empty());
@@ -1120,7 +2006,7 @@ keybindings:
             _ => panic!("Expected FeedConfigItem::Group"),
         }
         assert_eq!(cfg.keybindings.global.quit.len(), 1);
-        assert_eq!(cfg.keybindings.feeds.select.code, KeyCode::Enter);
+        assert_eq!(cfg.keybindings.feeds.select[0].code, KeyCode::Enter);
     }
 
     #[test]
@@ -1165,12 +2051,72 @@ feeds:
         }
     }
 
+    #[test]
+    fn resizing_the_feeds_list_keeps_widths_summing_to_100() {
+        let mut columns = ColumnConfig::default();
+        columns.resize_feeds_list(5);
+        assert_eq!((columns.feeds_list, columns.articles_list, columns.article_view), (30, 32, 38));
+
+        columns.resize_feeds_list(-100);
+        assert_eq!(columns.feeds_list, 10);
+        assert_eq!(columns.feeds_list + columns.articles_list + columns.article_view, 100);
+
+        // The other panes never drop below the minimum either
+        columns.resize_feeds_list(100);
+        assert_eq!((columns.feeds_list, columns.articles_list, columns.article_view), (80, 10, 10));
+    }
+
+    #[test]
+    fn deserialize_network() {
+        let cfg: Config = serde_yaml::from_str("network:\n  max_concurrent_fetches: 4\n  refresh_jitter_secs: 30\n").unwrap();
+        assert_eq!(cfg.network.max_concurrent_fetches, 4);
+        assert_eq!(cfg.network.refresh_jitter_secs, 30);
+
+        let cfg: Config = serde_yaml::from_str("{}").unwrap();
+        assert_eq!(cfg.network.max_concurrent_fetches, 16);
+        assert_eq!(cfg.network.refresh_jitter_secs, 0);
+    }
+
+    #[test]
+    fn deserialize_notifications() {
+        let yaml = r#"
+notifications:
+  enabled: true
+feeds:
+  - title: "BAIR"
+    url: "http://bair.berkeley.edu/blog/"
+    notify: true
+  - title: "Rust Blog"
+    url: "https://blog.rust-lang.org/"
+"#;
+        let cfg: Config = serde_yaml::from_str(yaml).unwrap();
+        assert!(cfg.notifications.enabled);
+
+        let notify: Vec<bool> = cfg.feeds.iter()
+            .flat_map(|item| item.collect_feeds())
+            .map(|(_, feed)| feed.notify)
+            .collect();
+        assert_eq!(notify, vec![true, false]);
+
+        // Off unless explicitly enabled, and left out when saving
+        let cfg: Config = serde_yaml::from_str("{}").unwrap();
+        assert!(!cfg.notifications.enabled);
+        let feed = FeedSource {
+            title: "Rust Blog".to_string(),
+            url: "https://blog.rust-lang.org/".to_string(),
+            feed: None,
+            ..Default::default()
+        };
+        assert!(!serde_yaml::to_string(&feed).unwrap().contains("notify"));
+    }
+
     #[test]
     fn feed_config_item_iter_feeds() {
         let standalone = FeedConfigItem::Standalone(FeedSource {
             title: "BAIR".to_string(),
             url: "http://bair.berkeley.edu/blog/".to_string(),
             feed: Some("https://bair.berkeley.edu/blog/feed.xml".to_string()),
+            ..Default::default()
         });
 
         let feeds = standalone.collect_feeds();
@@ -1185,6 +2131,7 @@ feeds:
                     title: "Rust Blog".to_string(),
                     url: "https://blog.rust-lang.org/".to_string(),
                     feed: Some("https://blog.rust-lang.org/feed.xml".to_string()),
+                    ..Default::default()
                 }),
             ],
         });
@@ -1205,6 +2152,7 @@ feeds:
                     title: "Foreign Policy".to_string(),
                     url: "https://foreignpolicy.com".to_string(),
                     feed: Some("http://foreignpolicy.com/feed".to_string()),
+                    ..Default::default()
                 }),
                 FeedConfigItem::Group(FeedGroup {
                     title: "Domestic".to_string(),
@@ -1213,6 +2161,7 @@ feeds:
                             title: "BBC World News".to_string(),
                             url: "https://www.bbc.co.uk/news/".to_string(),
                             feed: Some("http://feeds.bbci.co.uk/news/world/rss.xml".to_string()),
+                            ..Default::default()
                         }),
                     ],
                 }),
@@ -1278,6 +2227,7 @@ feeds:
                     title: "BAIR".to_string(),
                     url: "http://bair.berkeley.edu/blog/".to_string(),
                     feed: Some("https://bair.berkeley.edu/blog/feed.xml".to_string()),
+                    ..Default::default()
                 }),
                 FeedConfigItem::Group(FeedGroup {
                     title: "Tech".to_string(),
@@ -1286,6 +2236,7 @@ feeds:
                             title: "Rust Blog".to_string(),
                             url: "https://blog.rust-lang.org/".to_string(),
                             feed: Some("https://blog.rust-lang.org/feed.xml".to_string()),
+                            ..Default::default()
                         }),
                     ],
                 }),
@@ -1311,6 +2262,7 @@ feeds:
                     title: "Rust Blog".to_string(),
                     url: "https://blog.rust-lang.org/".to_string(),
                     feed: Some("https://blog.rust-lang.org/feed.xml".to_string()),
+                    ..Default::default()
                 }),
             ],
         });
@@ -1319,6 +2271,30 @@ feeds:
         assert_eq!(empty.len(), 0);
     }
 
+    #[test]
+    fn default_keybindings_have_no_conflicts() {
+        assert_eq!(KeyBindings::default().conflicts(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn keybinding_conflicts_are_reported_per_scope() {
+        let mut kb = KeyBindings::default();
+        kb.articles.toggle_read = vec![parse_kb("s")];
+        kb.global.open_image = vec![parse_kb("o")];
+        // Same key for expand and collapse toggles, and isn't a conflict
+        kb.feeds.collapse_all = vec![parse_kb("e")];
+        // Bindings in different panes never clash
+        kb.feeds.move_to_group = vec![parse_kb("s")];
+        // Keys the feeds pane handles itself are checked as well
+        kb.feeds.move_item_up = vec![parse_kb("x")];
+
+        assert_eq!(kb.conflicts(), vec![
+            "'o' bound to multiple actions globally (open_browser, open_image)".to_string(),
+            "'x' bound to multiple actions in feeds pane (move_item_up, cut)".to_string(),
+            "'s' bound to multiple actions in articles pane (toggle_read, toggle_star)".to_string(),
+        ]);
+    }
+
     #[test]
     fn parse_keybinding_single_char() {
         let kb = parse_kb("a");
@@ -1362,6 +2338,39 @@ feeds:
         assert!(kb.modifiers.contains(KeyModifiers::SHIFT));
     }
 
+    #[test]
+    fn any_action_accepts_one_key_or_a_list() {
+        let yaml = r#"
+feeds:
+  select: "l"
+articles:
+  select: ["Enter", "l"]
+"#;
+        let kb: KeyBindings = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(kb.feeds.select, vec![parse_kb("l")]);
+        assert_eq!(kb.articles.select, vec![parse_kb("Enter"), parse_kb("l")]);
+        assert!(matches_any(&kb.articles.select, KeyCode::Char('l'), KeyModifiers::NONE));
+        assert!(matches_any(&kb.articles.select, KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(kb.global.quit, default_quit());
+    }
+
+    #[test]
+    fn actions_can_be_unbound() {
+        let yaml = r#"
+global:
+  open_image: []
+  copy_enclosure: "none"
+articles:
+  toggle_star: ["nop", "f"]
+"#;
+        let kb: KeyBindings = serde_yaml::from_str(yaml).unwrap();
+        assert!(kb.global.open_image.is_empty());
+        assert!(kb.global.copy_enclosure.is_empty());
+        assert_eq!(kb.articles.toggle_star, vec![parse_kb("f")]);
+        assert!(!matches_any(&kb.global.open_image, KeyCode::Char('i'), KeyModifiers::NONE));
+        assert!(!parse_kb("none").matches(KeyCode::Null, KeyModifiers::NONE));
+    }
+
     #[test]
     fn uppercase_keybinding_matches_shift_key() {
         let kb = parse_kb("G");
@@ -1402,4 +2411,83 @@ feeds:
         let kb = parse_kb("BackTab");
         assert!(kb.matches(KeyCode::Tab, KeyModifiers::SHIFT));
     }
+
+    #[test]
+    fn parse_color_rgb_function() {
+        use ratatui::style::Color;
+        assert_eq!(parse_color("rgb(12, 34, 56)"), Ok(Color::Rgb(12, 34, 56)));
+        assert_eq!(parse_color("RGB(0,0,255)"), Ok(Color::Rgb(0, 0, 255)));
+        assert!(parse_color("rgb(256, 0, 0)").is_err());
+        assert!(parse_color("rgb(1, 2)").is_err());
+        assert!(parse_color("rgb(a, b, c)").is_err());
+    }
+
+    #[test]
+    fn parse_mark_read_on_values() {
+        assert_eq!(parse_mark_read_on("select"), Ok(MarkReadOn::Select));
+        assert_eq!(parse_mark_read_on("Open"), Ok(MarkReadOn::Open));
+        assert_eq!(parse_mark_read_on("scroll"), Ok(MarkReadOn::Scroll));
+        assert!(parse_mark_read_on("never").is_err());
+    }
+
+    #[test]
+    fn parse_layout_values() {
+        assert_eq!(parse_layout(&DisplayConfig::default().layout), Ok(PaneLayout::Horizontal));
+        assert_eq!(parse_layout("Vertical"), Ok(PaneLayout::Vertical));
+        assert_eq!(parse_layout("auto"), Ok(PaneLayout::Auto));
+        assert!(parse_layout("diagonal").is_err());
+    }
+
+    #[test]
+    fn parse_pane_values() {
+        assert_eq!(parse_pane("feeds"), Ok(ActivePane::Feeds));
+        assert_eq!(parse_pane("Articles"), Ok(ActivePane::Articles));
+        assert_eq!(parse_pane("article_view"), Ok(ActivePane::ArticleView));
+        assert!(parse_pane("sidebar").is_err());
+    }
+
+    #[test]
+    fn invalid_glyphs_fall_back_to_defaults() {
+        let mut glyphs = GlyphConfig { read: "-".to_string(), ..GlyphConfig::default() };
+        assert_eq!(glyphs.reset_invalid(), Ok(()));
+        assert_eq!(glyphs.read, "-");
+
+        // Multi-codepoint graphemes (flags, skin tones) count as one
+        glyphs.starred = "\u{1F1EC}\u{1F1E7}".to_string();
+        glyphs.unread = "**".to_string();
+        glyphs.error = String::new();
+        assert_eq!(
+            glyphs.reset_invalid(),
+            Err("Glyphs must be a single character, using defaults for: unread, error".to_string())
+        );
+        assert_eq!(glyphs.unread, "\u{25CF}");
+        assert_eq!(glyphs.starred, "\u{1F1EC}\u{1F1E7}");
+        assert_eq!(glyphs.error, "\u{2717}");
+    }
+
+    #[test]
+    fn parse_color_indexed() {
+        use ratatui::style::Color;
+        assert_eq!(parse_color("color0"), Ok(Color::Indexed(0)));
+        assert_eq!(parse_color("color123"), Ok(Color::Indexed(123)));
+        assert_eq!(parse_color("255"), Ok(Color::Indexed(255)));
+        assert!(parse_color("color256").is_err());
+        assert!(parse_color("300").is_err());
+        assert!(parse_color("color").is_err());
+        assert!(parse_color("colorful").is_err());
+    }
+
+    #[test]
+    fn test_feed_source_feed_url_falls_back_to_url() {
+        let mut source = FeedSource {
+            title: "Rust Blog".to_string(),
+            url: "https://blog.rust-lang.org/".to_string(),
+            feed: None,
+            ..Default::default()
+        };
+        assert_eq!(source.feed_url(), "https://blog.rust-lang.org/");
+
+        source.feed = Some("https://blog.rust-lang.org/feed.xml".to_string());
+        assert_eq!(source.feed_url(), "https://blog.rust-lang.org/feed.xml");
+    }
 }
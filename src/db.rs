@@ -1,8 +1,8 @@
 use anyhow::Context;
 use chrono::{DateTime, Utc};
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension};
 
-use crate::config::Config;
+use crate::config::{Config, FeedSource};
 
 // ---------------------------------------------------------------------------
 // Domain models
@@ -13,11 +13,20 @@ use crate::config::Config;
 pub struct Feed {
     pub id: i64,
     pub group_title: String,
+    /// Display title: the config title, or the feed's own title for
+    /// feeds with `use_feed_title` once they have been fetched.
     pub title: String,
     pub url: String,
     pub site_url: Option<String>,
     pub last_fetched: Option<DateTime<Utc>>,
     pub unread_count: u32,
+    /// Number of stored articles, read or not; 0 unless the feeds were
+    /// loaded with [`get_all_feeds_with_totals`].
+    pub total_count: u32,
+    /// Error from the most recent fetch, or `None` if it succeeded.
+    pub last_error: Option<String>,
+    /// Per-feed read policy override from the config (see `FeedSource`).
+    pub mark_read_on: Option<String>,
 }
 
 /// A single article (entry) belonging to a feed.
@@ -34,6 +43,13 @@ pub struct Article {
     pub published: Option<DateTime<Utc>>,
     pub is_read: bool,
     pub is_starred: bool,
+    /// Whether the article is in the "read later" queue.
+    pub is_queued: bool,
+    /// URL of the attached media (e.g. podcast audio), if any.
+    pub enclosure_url: Option<String>,
+    /// Whether `content` is the text extracted from the article's web page
+    /// (see [`update_article_content`]) rather than what the feed sent.
+    pub has_full_content: bool,
 }
 
 // ---------------------------------------------------------------------------
@@ -41,8 +57,18 @@ pub struct Article {
 // ---------------------------------------------------------------------------
 
 /// Parse an optional RFC 3339 timestamp string into `Option<DateTime<Utc>>`.
+///
+/// Also accepts SQLite's `datetime('now')` format (`YYYY-MM-DD HH:MM:SS`, UTC),
+/// which older versions stored in `feeds.last_fetched`.
 fn parse_optional_datetime(s: Option<String>) -> Option<DateTime<Utc>> {
-    s.and_then(|v| DateTime::parse_from_rfc3339(&v).ok().map(|dt| dt.with_timezone(&Utc)))
+    s.and_then(|v| {
+        DateTime::parse_from_rfc3339(&v)
+            .map(|dt| dt.with_timezone(&Utc))
+            .or_else(|_| {
+                chrono::NaiveDateTime::parse_from_str(&v, "%Y-%m-%d %H:%M:%S").map(|dt| dt.and_utc())
+            })
+            .ok()
+    })
 }
 
 /// Format an optional `DateTime<Utc>` as an RFC 3339 string for SQLite storage.
@@ -56,12 +82,24 @@ fn format_optional_datetime(dt: &Option<DateTime<Utc>>) -> Option<String> {
 
 /// Open (or create) the SQLite database at `$XDG_DATA_HOME/lazyrss/news.db`
 /// and ensure the schema is up to date.
-pub fn initialize() -> anyhow::Result<Connection> {
-    let data_dir = dirs::data_dir()
-        .context("Could not determine data directory")?
-        .join("lazyrss");
+///
+/// If the data directory is unusable (read-only, disk full, ...) this falls
+/// back to an in-memory database so the app can still be used for reading,
+/// and returns the error that caused the fallback alongside it.
+pub fn initialize() -> anyhow::Result<(Connection, Option<anyhow::Error>)> {
+    let on_disk = dirs::data_dir()
+        .context("Could not determine data directory")
+        .and_then(|dir| open_on_disk(&dir.join("lazyrss")));
+
+    match on_disk {
+        Ok(conn) => Ok((conn, None)),
+        Err(err) => Ok((open_in_memory()?, Some(err))),
+    }
+}
 
-    std::fs::create_dir_all(&data_dir)
+/// Open (or create) `news.db` in `data_dir`, failing if it can't be written.
+fn open_on_disk(data_dir: &std::path::Path) -> anyhow::Result<Connection> {
+    std::fs::create_dir_all(data_dir)
         .with_context(|| format!("Failed to create data directory: {}", data_dir.display()))?;
 
     let db_path = data_dir.join("news.db");
@@ -69,9 +107,58 @@ pub fn initialize() -> anyhow::Result<Connection> {
     let conn = Connection::open(&db_path)
         .with_context(|| format!("Failed to open database: {}", db_path.display()))?;
 
+    // SQLite silently opens unwritable files read-only
+    if conn.is_readonly(rusqlite::DatabaseName::Main)? {
+        anyhow::bail!("Database is read-only: {}", db_path.display());
+    }
+
     // Performance and integrity pragmas.
     conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA foreign_keys=ON;")?;
 
+    create_schema(&conn)?;
+    run_migrations(&conn)?;
+
+    Ok(conn)
+}
+
+/// Open the existing database without writing to it (for `--status`).
+///
+/// Fails if there is no database yet or it predates the current schema,
+/// since migrations can't be run on a read-only connection.
+pub fn open_read_only() -> anyhow::Result<Connection> {
+    let db_path = dirs::data_dir()
+        .context("Could not determine data directory")?
+        .join("lazyrss")
+        .join("news.db");
+    if !db_path.exists() {
+        anyhow::bail!("No database at {}; run lazyrss once first", db_path.display());
+    }
+
+    let conn = Connection::open_with_flags(&db_path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .with_context(|| format!("Failed to open database: {}", db_path.display()))?;
+
+    let version: i64 = conn
+        .query_row("SELECT version FROM schema_version", [], |row| row.get(0))
+        .unwrap_or(0);
+    if (version as usize) < MIGRATIONS.len() {
+        anyhow::bail!("Database at {} needs upgrading; run lazyrss once first", db_path.display());
+    }
+
+    Ok(conn)
+}
+
+/// Open a fresh in-memory database with the full schema.
+pub(crate) fn open_in_memory() -> anyhow::Result<Connection> {
+    let conn = Connection::open_in_memory().context("Failed to open in-memory database")?;
+    conn.execute_batch("PRAGMA foreign_keys=ON;")?;
+    create_schema(&conn)?;
+    run_migrations(&conn)?;
+    Ok(conn)
+}
+
+/// Create the base tables and indexes if they don't exist yet. Later
+/// changes are applied by [`run_migrations`].
+fn create_schema(conn: &Connection) -> anyhow::Result<()> {
     // Create tables.
     conn.execute(
         "CREATE TABLE IF NOT EXISTS feeds (
@@ -118,7 +205,69 @@ pub fn initialize() -> anyhow::Result<Connection> {
         [],
     )?;
 
-    Ok(conn)
+    Ok(())
+}
+
+/// A schema change applied by [`run_migrations`].
+type Migration = fn(&Connection) -> anyhow::Result<()>;
+
+/// Schema changes in the order they were introduced. Entry `i` takes the
+/// database from version `i` to `i + 1`; append new steps, never reorder.
+///
+/// Databases from before versioning report version 0 but may already have
+/// some of these applied, so steps must be safe to re-run.
+const MIGRATIONS: &[Migration] = &[
+    |conn| add_column_if_missing(conn, "feeds", "mark_read_on", "TEXT"),
+    |conn| add_column_if_missing(conn, "articles", "enclosure_url", "TEXT"),
+    |conn| add_column_if_missing(conn, "feeds", "position", "INTEGER NOT NULL DEFAULT 0"),
+    |conn| add_column_if_missing(conn, "articles", "content_hash", "TEXT"),
+    |conn| add_column_if_missing(conn, "feeds", "last_error", "TEXT"),
+    |conn| add_column_if_missing(conn, "feeds", "feed_title", "TEXT"),
+    |conn| add_column_if_missing(conn, "feeds", "use_feed_title", "INTEGER NOT NULL DEFAULT 0"),
+    |conn| add_column_if_missing(conn, "articles", "full_content", "TEXT"),
+    |conn| add_column_if_missing(conn, "articles", "is_queued", "INTEGER NOT NULL DEFAULT 0"),
+];
+
+/// Current schema version, or 0 for a database that predates versioning.
+fn schema_version(conn: &Connection) -> anyhow::Result<usize> {
+    conn.execute("CREATE TABLE IF NOT EXISTS schema_version (version INTEGER NOT NULL)", [])?;
+    let version: Option<i64> = conn
+        .query_row("SELECT version FROM schema_version", [], |row| row.get(0))
+        .optional()?;
+    Ok(version.unwrap_or(0) as usize)
+}
+
+/// Apply every migration newer than the database's schema version, each in
+/// its own transaction together with the version bump.
+pub fn run_migrations(conn: &Connection) -> anyhow::Result<()> {
+    let current = schema_version(conn)?;
+
+    for (version, migration) in MIGRATIONS.iter().enumerate().skip(current) {
+        let tx = conn.unchecked_transaction()?;
+        migration(&tx).with_context(|| format!("Failed to migrate database to version {}", version + 1))?;
+        tx.execute("DELETE FROM schema_version", [])?;
+        tx.execute("INSERT INTO schema_version (version) VALUES (?1)", [version as i64 + 1])?;
+        tx.commit()?;
+    }
+
+    Ok(())
+}
+
+/// Add `column` to `table` unless it already exists (for databases created
+/// by older versions).
+fn add_column_if_missing(conn: &Connection, table: &str, column: &str, decl: &str) -> anyhow::Result<()> {
+    let exists = conn
+        .prepare(&format!("PRAGMA table_info({table})"))?
+        .query_map([], |row| row.get::<_, String>(1))?
+        .collect::<Result<Vec<_>, _>>()?
+        .iter()
+        .any(|name| name == column);
+
+    if !exists {
+        conn.execute(&format!("ALTER TABLE {table} ADD COLUMN {column} {decl}"), [])?;
+    }
+
+    Ok(())
 }
 
 // ---------------------------------------------------------------------------
@@ -130,23 +279,21 @@ pub fn initialize() -> anyhow::Result<Connection> {
 ///
 /// - New feeds are inserted.
 /// - Existing feeds have their group_title and title updated if changed.
+///   The title the feed gives itself is stored separately (see
+///   [`set_feed_title`]), so the config title is never overwritten.
+/// - Every feed's `position` is set to its index in config order.
 /// - Feeds no longer in the config are deleted (along with their articles).
 pub fn sync_feeds_from_config(conn: &Connection, config: &Config) -> anyhow::Result<()> {
     // Collect all feed URLs that should exist.
     let mut config_urls: Vec<String> = Vec::new();
-    let mut feed_updates: Vec<(Option<String>, String, String, Option<String>)> = Vec::new(); // (group_title, title, feed_url, site_url)
+    let mut feed_updates: Vec<(Option<String>, String, FeedSource)> = Vec::new(); // (group_title, feed_url, source)
 
     for item in &config.feeds {
         for (group_path, feed) in item.collect_feeds() {
             // Use feed URL if provided, otherwise fall back to site URL
-            let feed_url = feed.feed.as_ref().unwrap_or(&feed.url).clone();
+            let feed_url = feed.feed_url().to_string();
             config_urls.push(feed_url.clone());
-            feed_updates.push((
-                group_path,
-                feed.title.clone(),
-                feed_url,
-                Some(feed.url.clone()),
-            ));
+            feed_updates.push((group_path, feed_url, feed));
         }
     }
 
@@ -164,41 +311,63 @@ pub fn sync_feeds_from_config(conn: &Connection, config: &Config) -> anyhow::Res
     // Upsert feeds: insert new ones, update existing ones.
     // Use empty string for standalone feeds (no group).
     let mut upsert_stmt = conn.prepare(
-        "INSERT INTO feeds (group_title, title, url, site_url) VALUES (?1, ?2, ?3, ?4)
-         ON CONFLICT(url) DO UPDATE SET group_title = excluded.group_title, title = excluded.title, site_url = excluded.site_url",
+        "INSERT INTO feeds (group_title, title, url, site_url, mark_read_on, position, use_feed_title)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+         ON CONFLICT(url) DO UPDATE SET group_title = excluded.group_title, title = excluded.title,
+             site_url = excluded.site_url, mark_read_on = excluded.mark_read_on,
+             position = excluded.position, use_feed_title = excluded.use_feed_title",
     )?;
 
-    for (group_title, title, feed_url, site_url) in feed_updates {
+    for (position, (group_title, feed_url, source)) in feed_updates.into_iter().enumerate() {
         upsert_stmt.execute(params![
             group_title.unwrap_or_default(),
-            title,
+            source.title,
             feed_url,
-            site_url
+            Some(source.url),
+            source.mark_read_on,
+            position as i64,
+            source.use_feed_title
         ])?;
     }
 
     Ok(())
 }
 
-/// Retrieve all feeds ordered by group and title, with each feed's unread
-/// article count.
+/// Retrieve all feeds in config order, with each feed's unread article
+/// count.
 pub fn get_all_feeds(conn: &Connection) -> anyhow::Result<Vec<Feed>> {
+    query_feeds(conn, false)
+}
+
+/// Like [`get_all_feeds`], also counting each feed's stored articles.
+pub fn get_all_feeds_with_totals(conn: &Connection) -> anyhow::Result<Vec<Feed>> {
+    query_feeds(conn, true)
+}
+
+/// Load all feeds; the total-count subquery only runs when `with_totals`.
+fn query_feeds(conn: &Connection, with_totals: bool) -> anyhow::Result<Vec<Feed>> {
     let mut stmt = conn.prepare(
         "SELECT
             feeds.id,
             feeds.group_title,
-            feeds.title,
+            CASE WHEN feeds.use_feed_title AND feeds.feed_title <> ''
+                THEN feeds.feed_title ELSE feeds.title END,
             feeds.url,
             feeds.site_url,
             feeds.last_fetched,
             (SELECT COUNT(*) FROM articles
-             WHERE articles.feed_id = feeds.id AND articles.is_read = 0) AS unread_count
+             WHERE articles.feed_id = feeds.id AND articles.is_read = 0) AS unread_count,
+            feeds.mark_read_on,
+            CASE WHEN ?1 THEN
+                (SELECT COUNT(*) FROM articles WHERE articles.feed_id = feeds.id)
+            ELSE 0 END AS total_count,
+            feeds.last_error
          FROM feeds
-         ORDER BY feeds.group_title, feeds.title",
+         ORDER BY feeds.position, feeds.id",
     )?;
 
     let feeds = stmt
-        .query_map([], |row| {
+        .query_map([with_totals], |row| {
             Ok(Feed {
                 id: row.get(0)?,
                 group_title: row.get(1)?,
@@ -207,6 +376,9 @@ pub fn get_all_feeds(conn: &Connection) -> anyhow::Result<Vec<Feed>> {
                 site_url: row.get(4)?,
                 last_fetched: parse_optional_datetime(row.get(5)?),
                 unread_count: row.get(6)?,
+                total_count: row.get(8)?,
+                last_error: row.get(9)?,
+                mark_read_on: row.get(7)?,
             })
         })?
         .collect::<Result<Vec<_>, _>>()?;
@@ -214,34 +386,45 @@ pub fn get_all_feeds(conn: &Connection) -> anyhow::Result<Vec<Feed>> {
     Ok(feeds)
 }
 
+/// Columns selected for an [`Article`], in the order [`row_to_article`]
+/// reads them. Qualified so queries can join `feeds`.
+const ARTICLE_COLUMNS: &str = "articles.id, articles.feed_id, articles.guid, articles.title, articles.url,
+    articles.author, articles.summary, COALESCE(articles.full_content, articles.content),
+    articles.published, articles.is_read, articles.is_starred, articles.is_queued,
+    articles.enclosure_url, articles.full_content IS NOT NULL";
+
+/// Build an [`Article`] from a row selected with [`ARTICLE_COLUMNS`].
+fn row_to_article(row: &rusqlite::Row) -> rusqlite::Result<Article> {
+    Ok(Article {
+        id: row.get(0)?,
+        feed_id: row.get(1)?,
+        guid: row.get(2)?,
+        title: row.get(3)?,
+        url: row.get(4)?,
+        author: row.get(5)?,
+        summary: row.get(6)?,
+        content: row.get(7)?,
+        published: parse_optional_datetime(row.get(8)?),
+        is_read: row.get::<_, i32>(9)? != 0,
+        is_starred: row.get::<_, i32>(10)? != 0,
+        is_queued: row.get::<_, i32>(11)? != 0,
+        enclosure_url: row.get(12)?,
+        has_full_content: row.get(13)?,
+    })
+}
+
 /// Retrieve all articles for feeds with the given group title, newest first.
 pub fn get_articles_for_group(conn: &Connection, group_title: &str) -> anyhow::Result<Vec<Article>> {
-    let mut stmt = conn.prepare(
-        "SELECT articles.id, articles.feed_id, articles.guid, articles.title, articles.url,
-                articles.author, articles.summary, articles.content,
-                articles.published, articles.is_read, articles.is_starred
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {ARTICLE_COLUMNS}
          FROM articles
          INNER JOIN feeds ON articles.feed_id = feeds.id
          WHERE feeds.group_title = ?1
          ORDER BY articles.published DESC, articles.created_at DESC",
-    )?;
+    ))?;
 
     let articles = stmt
-        .query_map(params![group_title], |row| {
-            Ok(Article {
-                id: row.get(0)?,
-                feed_id: row.get(1)?,
-                guid: row.get(2)?,
-                title: row.get(3)?,
-                url: row.get(4)?,
-                author: row.get(5)?,
-                summary: row.get(6)?,
-                content: row.get(7)?,
-                published: parse_optional_datetime(row.get(8)?),
-                is_read: row.get::<_, i32>(9)? != 0,
-                is_starred: row.get::<_, i32>(10)? != 0,
-            })
-        })?
+        .query_map(params![group_title], row_to_article)?
         .collect::<Result<Vec<_>, _>>()?;
 
     Ok(articles)
@@ -249,76 +432,184 @@ pub fn get_articles_for_group(conn: &Connection, group_title: &str) -> anyhow::R
 
 /// Retrieve all articles from all feeds, newest first.
 pub fn get_all_articles(conn: &Connection) -> anyhow::Result<Vec<Article>> {
-    let mut stmt = conn.prepare(
-        "SELECT id, feed_id, guid, title, url, author, summary, content,
-                published, is_read, is_starred
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {ARTICLE_COLUMNS}
          FROM articles
          ORDER BY published DESC, created_at DESC",
-    )?;
+    ))?;
 
     let articles = stmt
-        .query_map([], |row| {
-            Ok(Article {
-                id: row.get(0)?,
-                feed_id: row.get(1)?,
-                guid: row.get(2)?,
-                title: row.get(3)?,
-                url: row.get(4)?,
-                author: row.get(5)?,
-                summary: row.get(6)?,
-                content: row.get(7)?,
-                published: parse_optional_datetime(row.get(8)?),
-                is_read: row.get::<_, i32>(9)? != 0,
-                is_starred: row.get::<_, i32>(10)? != 0,
-            })
-        })?
+        .query_map([], row_to_article)?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(articles)
+}
+
+/// Retrieve all starred articles across feeds, newest first.
+pub fn get_starred_articles(conn: &Connection) -> anyhow::Result<Vec<Article>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {ARTICLE_COLUMNS}
+         FROM articles
+         WHERE is_starred = 1
+         ORDER BY published DESC, created_at DESC",
+    ))?;
+
+    let articles = stmt
+        .query_map([], row_to_article)?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(articles)
+}
+
+/// Retrieve articles published at or after `since` across feeds, newest
+/// first. Articles without a published date are left out.
+pub fn get_recent_articles(conn: &Connection, since: DateTime<Utc>) -> anyhow::Result<Vec<Article>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {ARTICLE_COLUMNS}
+         FROM articles
+         WHERE published >= ?1
+         ORDER BY published DESC, created_at DESC",
+    ))?;
+
+    // `published` is stored as UTC RFC 3339, so string order is time order
+    let articles = stmt
+        .query_map(params![since.to_rfc3339()], row_to_article)?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(articles)
+}
+
+/// Retrieve all articles in the "read later" queue, newest first.
+pub fn get_queued_articles(conn: &Connection) -> anyhow::Result<Vec<Article>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {ARTICLE_COLUMNS}
+         FROM articles
+         WHERE is_queued = 1
+         ORDER BY published DESC, created_at DESC",
+    ))?;
+
+    let articles = stmt
+        .query_map([], row_to_article)?
         .collect::<Result<Vec<_>, _>>()?;
 
     Ok(articles)
 }
 
+/// Count starred articles across all feeds.
+pub fn count_starred(conn: &Connection) -> anyhow::Result<u32> {
+    let count = conn.query_row(
+        "SELECT COUNT(*) FROM articles WHERE is_starred = 1",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(count)
+}
+
+/// Count articles in the "read later" queue.
+pub fn count_queued(conn: &Connection) -> anyhow::Result<u32> {
+    let count = conn.query_row(
+        "SELECT COUNT(*) FROM articles WHERE is_queued = 1",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(count)
+}
+
+/// Count the unread articles of one feed.
+pub fn unread_count_for_feed(conn: &Connection, feed_id: i64) -> anyhow::Result<u32> {
+    let count = conn.query_row(
+        "SELECT COUNT(*) FROM articles WHERE feed_id = ?1 AND is_read = 0",
+        params![feed_id],
+        |row| row.get(0),
+    )?;
+    Ok(count)
+}
+
 /// Retrieve all articles for a given feed, newest first.
 pub fn get_articles_for_feed(conn: &Connection, feed_id: i64) -> anyhow::Result<Vec<Article>> {
-    let mut stmt = conn.prepare(
-        "SELECT id, feed_id, guid, title, url, author, summary, content,
-                published, is_read, is_starred
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {ARTICLE_COLUMNS}
          FROM articles
          WHERE feed_id = ?1
          ORDER BY published DESC, created_at DESC",
-    )?;
+    ))?;
 
     let articles = stmt
-        .query_map(params![feed_id], |row| {
-            Ok(Article {
-                id: row.get(0)?,
-                feed_id: row.get(1)?,
-                guid: row.get(2)?,
-                title: row.get(3)?,
-                url: row.get(4)?,
-                author: row.get(5)?,
-                summary: row.get(6)?,
-                content: row.get(7)?,
-                published: parse_optional_datetime(row.get(8)?),
-                is_read: row.get::<_, i32>(9)? != 0,
-                is_starred: row.get::<_, i32>(10)? != 0,
-            })
-        })?
+        .query_map(params![feed_id], row_to_article)?
         .collect::<Result<Vec<_>, _>>()?;
 
     Ok(articles)
 }
 
-/// Insert articles that do not already exist (keyed on `(feed_id, guid)`).
-/// Returns the number of newly inserted rows.
-pub fn upsert_articles(conn: &Connection, articles: &[Article]) -> anyhow::Result<usize> {
+/// Row counts from [`upsert_articles`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpsertCounts {
+    /// Articles that were not stored before.
+    pub inserted: usize,
+    /// Stored articles whose title, link, content or date changed.
+    pub updated: usize,
+    /// Title of the most recently published article among the inserted ones.
+    pub newest_title: Option<String>,
+}
+
+/// Hash of an article's body text (content, else summary) with whitespace
+/// collapsed, so re-fetches that only reflow the markup don't count as edits.
+///
+/// Uses FNV-1a, whose output is stable across builds, since it is stored.
+fn content_hash(article: &Article) -> String {
+    let body = article.content.as_deref().or(article.summary.as_deref()).unwrap_or("");
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for word in body.split_whitespace() {
+        for byte in word.bytes().chain([b' ']) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{hash:016x}")
+}
+
+/// Insert new articles and update stored ones (keyed on `(feed_id, guid)`)
+/// whose fields changed, e.g. a post republished with a corrected URL.
+///
+/// Starred and queued flags of existing articles are left untouched, and so
+/// are read flags unless `unread_on_update` is set and the article's body
+/// changed.
+/// Articles stored before content hashes existed are never re-marked.
+/// Full text fetched for an article is dropped once the feed changes its
+/// content.
+pub fn upsert_articles(
+    conn: &Connection,
+    articles: &[Article],
+    unread_on_update: bool,
+) -> anyhow::Result<UpsertCounts> {
+    let mut exists = conn.prepare(
+        "SELECT EXISTS(SELECT 1 FROM articles WHERE feed_id = ?1 AND guid = ?2)",
+    )?;
     let mut stmt = conn.prepare(
-        "INSERT OR IGNORE INTO articles
-            (feed_id, guid, title, url, author, summary, content, published)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        "INSERT INTO articles
+            (feed_id, guid, title, url, author, summary, content, published, enclosure_url,
+             content_hash)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+         ON CONFLICT(feed_id, guid) DO UPDATE SET
+            title = excluded.title, url = excluded.url, author = excluded.author,
+            summary = excluded.summary, content = excluded.content,
+            published = excluded.published, enclosure_url = excluded.enclosure_url,
+            content_hash = excluded.content_hash,
+            full_content = CASE WHEN content IS NOT excluded.content THEN NULL ELSE full_content END,
+            is_read = CASE
+                WHEN ?11 AND content_hash IS NOT NULL AND content_hash != excluded.content_hash THEN 0
+                ELSE is_read
+            END
+         WHERE title IS NOT excluded.title OR url IS NOT excluded.url
+            OR author IS NOT excluded.author OR summary IS NOT excluded.summary
+            OR content IS NOT excluded.content OR published IS NOT excluded.published
+            OR enclosure_url IS NOT excluded.enclosure_url",
     )?;
 
-    let mut inserted = 0usize;
+    let mut counts = UpsertCounts::default();
+    let mut newest: Option<&Article> = None;
     for article in articles {
+        let existed: bool = exists.query_row(params![article.feed_id, article.guid], |row| row.get(0))?;
         let rows = stmt.execute(params![
             article.feed_id,
             article.guid,
@@ -328,11 +619,22 @@ pub fn upsert_articles(conn: &Connection, articles: &[Article]) -> anyhow::Resul
             article.summary,
             article.content,
             format_optional_datetime(&article.published),
+            article.enclosure_url,
+            content_hash(article),
+            unread_on_update,
         ])?;
-        inserted += rows;
+        if existed {
+            counts.updated += rows;
+        } else {
+            counts.inserted += rows;
+            if rows > 0 && newest.is_none_or(|n| article.published > n.published) {
+                newest = Some(article);
+            }
+        }
     }
+    counts.newest_title = newest.map(|a| a.title.clone());
 
-    Ok(inserted)
+    Ok(counts)
 }
 
 /// Toggle the `is_read` flag on a single article and return the new value.
@@ -351,20 +653,105 @@ pub fn toggle_read(conn: &Connection, article_id: i64) -> anyhow::Result<bool> {
     Ok(new_value)
 }
 
-/// Toggle the `is_starred` flag on a single article and return the new value.
-pub fn toggle_star(conn: &Connection, article_id: i64) -> anyhow::Result<bool> {
-    conn.execute(
-        "UPDATE articles SET is_starred = NOT is_starred WHERE id = ?1",
-        params![article_id],
-    )?;
+/// Set the `is_read` flag on an article. With `same_url`, every article
+/// sharing its normalized URL is set too, so copies merged by
+/// [`dedup_by_url`] stay in step.
+///
+/// Returns the feed ID of each article whose flag actually changed.
+pub fn set_read(conn: &Connection, article_id: i64, value: bool, same_url: bool) -> anyhow::Result<Vec<i64>> {
+    set_article_flag(conn, "is_read", article_id, value, same_url)
+}
 
-    let new_value: bool = conn.query_row(
-        "SELECT is_starred FROM articles WHERE id = ?1",
+/// Set the `is_starred` flag on an article; see [`set_read`].
+pub fn set_starred(conn: &Connection, article_id: i64, value: bool, same_url: bool) -> anyhow::Result<Vec<i64>> {
+    set_article_flag(conn, "is_starred", article_id, value, same_url)
+}
+
+/// Set the `is_queued` flag on an article; see [`set_read`].
+pub fn set_queued(conn: &Connection, article_id: i64, value: bool, same_url: bool) -> anyhow::Result<Vec<i64>> {
+    set_article_flag(conn, "is_queued", article_id, value, same_url)
+}
+
+/// Shared body of [`set_read`], [`set_starred`] and [`set_queued`]; `column`
+/// is one of the fixed flag column names.
+fn set_article_flag(
+    conn: &Connection,
+    column: &str,
+    article_id: i64,
+    value: bool,
+    same_url: bool,
+) -> anyhow::Result<Vec<i64>> {
+    let ids = if same_url {
+        same_url_article_ids(conn, article_id)?
+    } else {
+        vec![article_id]
+    };
+
+    let tx = conn.unchecked_transaction()?;
+    let mut changed_feed_ids = Vec::new();
+    {
+        let mut select = tx.prepare(&format!("SELECT feed_id FROM articles WHERE id = ?1 AND {column} != ?2"))?;
+        let mut update = tx.prepare(&format!("UPDATE articles SET {column} = ?2 WHERE id = ?1"))?;
+        for id in ids {
+            if let Some(feed_id) = select.query_row(params![id, value], |row| row.get(0)).optional()? {
+                update.execute(params![id, value])?;
+                changed_feed_ids.push(feed_id);
+            }
+        }
+    }
+    tx.commit()?;
+
+    Ok(changed_feed_ids)
+}
+
+/// IDs of every article whose normalized URL matches that of `article_id`,
+/// including the article itself (alone if it has no URL).
+fn same_url_article_ids(conn: &Connection, article_id: i64) -> anyhow::Result<Vec<i64>> {
+    let url: Option<String> = conn.query_row(
+        "SELECT url FROM articles WHERE id = ?1",
         params![article_id],
-        |row| row.get::<_, i32>(0).map(|v| v != 0),
+        |row| row.get(0),
     )?;
+    let Some(url) = url else {
+        return Ok(vec![article_id]);
+    };
+    let key = normalize_url(&url);
+
+    // Every copy starts with the normalized URL. substr() counts characters
+    // and, unlike LIKE, takes '%' and '_' literally.
+    let mut stmt = conn.prepare("SELECT id, url FROM articles WHERE substr(url, 1, ?1) = ?2")?;
+    let candidates = stmt
+        .query_map(params![key.chars().count() as i64, key], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
 
-    Ok(new_value)
+    Ok(candidates
+        .into_iter()
+        .filter(|(_, candidate)| normalize_url(candidate) == key)
+        .map(|(id, _)| id)
+        .collect())
+}
+
+/// Unread articles whose normalized URL another unread article shares, as
+/// `(feed_id, normalized URL)` pairs. [`dedup_by_url`] lists each such URL
+/// once, so unread counts over merged lists leave out the extra copies.
+pub fn unread_url_duplicates(conn: &Connection) -> anyhow::Result<Vec<(i64, String)>> {
+    let mut stmt = conn.prepare("SELECT feed_id, url FROM articles WHERE is_read = 0 AND url IS NOT NULL")?;
+    let rows = stmt
+        .query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut feeds_by_url: std::collections::HashMap<String, Vec<i64>> = std::collections::HashMap::new();
+    for (feed_id, url) in rows {
+        feeds_by_url.entry(normalize_url(&url).to_string()).or_default().push(feed_id);
+    }
+
+    Ok(feeds_by_url
+        .into_iter()
+        .filter(|(_, feed_ids)| feed_ids.len() > 1)
+        .flat_map(|(url, feed_ids)| feed_ids.into_iter().map(move |feed_id| (feed_id, url.clone())))
+        .collect())
 }
 
 /// Mark every article in the given feed as read.
@@ -382,15 +769,166 @@ pub fn mark_all_read_all(conn: &Connection) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Mark every article in a group's feeds as read, including nested groups
+/// (`"{group} > ..."`) when `recursive` is set.
+pub fn mark_all_read_for_group(conn: &Connection, group_title: &str, recursive: bool) -> anyhow::Result<()> {
+    // Prefix match via substr() rather than LIKE, so '%' and '_' in group
+    // names are taken literally
+    let nested_prefix = format!("{} > ", group_title);
+    conn.execute(
+        "UPDATE articles SET is_read = 1
+         WHERE feed_id IN (
+             SELECT id FROM feeds
+             WHERE group_title = ?1
+                OR (?2 AND substr(group_title, 1, length(?3)) = ?3)
+         )",
+        params![group_title, recursive, nested_prefix],
+    )?;
+    Ok(())
+}
+
+/// Mark the given articles as read in a single transaction, along with every
+/// article sharing one of their normalized URLs when `same_url` is set.
+/// Returns the number of rows updated.
+pub fn mark_read_by_ids(conn: &Connection, ids: &[i64], same_url: bool) -> anyhow::Result<usize> {
+    // Stay well under SQLite's host-parameter limit for large lists
+    const CHUNK: usize = 500;
+
+    let mut ids = ids.to_vec();
+    if same_url {
+        let mut expanded = Vec::with_capacity(ids.len());
+        for id in ids {
+            expanded.extend(same_url_article_ids(conn, id)?);
+        }
+        expanded.sort_unstable();
+        expanded.dedup();
+        ids = expanded;
+    }
+
+    let tx = conn.unchecked_transaction()?;
+    let mut updated = 0usize;
+    for chunk in ids.chunks(CHUNK) {
+        let placeholders = vec!["?"; chunk.len()].join(", ");
+        let sql = format!("UPDATE articles SET is_read = 1 WHERE id IN ({placeholders})");
+        updated += tx.execute(&sql, rusqlite::params_from_iter(chunk))?;
+    }
+    tx.commit()?;
+
+    Ok(updated)
+}
+
+/// Normalize an article URL for duplicate detection: drops the fragment,
+/// query string and any trailing slashes.
+pub fn normalize_url(url: &str) -> &str {
+    let end = url.find(['?', '#']).unwrap_or(url.len());
+    url[..end].trim_end_matches('/')
+}
+
+/// Collapse articles sharing the same normalized URL, keeping the
+/// earliest-published copy.
+///
+/// The kept copy is read, starred or queued if any copy was. Articles
+/// without a URL are never merged. The result stays ordered newest first.
+pub fn dedup_by_url(articles: Vec<Article>) -> Vec<Article> {
+    let mut kept: Vec<Article> = Vec::with_capacity(articles.len());
+    let mut index_by_url: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+
+    for article in articles {
+        let Some(key) = article.url.as_deref().map(|u| normalize_url(u).to_string()) else {
+            kept.push(article);
+            continue;
+        };
+
+        match index_by_url.get(&key) {
+            Some(&idx) => {
+                let existing = &mut kept[idx];
+                let is_read = existing.is_read || article.is_read;
+                let is_starred = existing.is_starred || article.is_starred;
+                let is_queued = existing.is_queued || article.is_queued;
+
+                let earlier = match (article.published, existing.published) {
+                    (Some(new), Some(old)) => new < old,
+                    (Some(_), None) => true,
+                    _ => false,
+                };
+                if earlier {
+                    *existing = article;
+                }
+                existing.is_read = is_read;
+                existing.is_starred = is_starred;
+                existing.is_queued = is_queued;
+            }
+            None => {
+                index_by_url.insert(key, kept.len());
+                kept.push(article);
+            }
+        }
+    }
+
+    // Replacing with an earlier copy can move it out of date order.
+    kept.sort_by_key(|a| std::cmp::Reverse(a.published));
+    kept
+}
+
 /// Update the `last_fetched` timestamp for a feed to the current time.
 pub fn update_last_fetched(conn: &Connection, feed_id: i64) -> anyhow::Result<()> {
     conn.execute(
-        "UPDATE feeds SET last_fetched = datetime('now') WHERE id = ?1",
-        params![feed_id],
+        "UPDATE feeds SET last_fetched = ?1 WHERE id = ?2",
+        params![Utc::now().to_rfc3339(), feed_id],
+    )?;
+    Ok(())
+}
+
+/// Record the outcome of a feed's latest fetch: the error message, or
+/// `None` to clear it after a successful fetch.
+pub fn set_last_error(conn: &Connection, feed_id: i64, error: Option<&str>) -> anyhow::Result<()> {
+    conn.execute(
+        "UPDATE feeds SET last_error = ?1 WHERE id = ?2",
+        params![error, feed_id],
+    )?;
+    Ok(())
+}
+
+/// Store the title a feed gives itself. It is only shown for feeds with
+/// `use_feed_title`; the config title stays in `feeds.title`.
+pub fn set_feed_title(conn: &Connection, feed_id: i64, title: &str) -> anyhow::Result<()> {
+    conn.execute(
+        "UPDATE feeds SET feed_title = ?1 WHERE id = ?2",
+        params![title, feed_id],
+    )?;
+    Ok(())
+}
+
+/// Store the full text fetched from an article's web page. It is returned
+/// as the article's `content` in place of the feed's own until the feed
+/// changes the article.
+pub fn update_article_content(conn: &Connection, article_id: i64, content: &str) -> anyhow::Result<()> {
+    conn.execute(
+        "UPDATE articles SET full_content = ?1 WHERE id = ?2",
+        params![content, article_id],
     )?;
     Ok(())
 }
 
+/// Snapshot of the feed counts printed by `--status`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct StatusSummary {
+    pub feeds: usize,
+    pub unread_total: u32,
+    pub feeds_with_errors: usize,
+}
+
+impl StatusSummary {
+    /// Summarise feeds loaded by [`get_all_feeds`].
+    pub fn from_feeds(feeds: &[Feed]) -> Self {
+        Self {
+            feeds: feeds.len(),
+            unread_total: feeds.iter().map(|f| f.unread_count).sum(),
+            feeds_with_errors: feeds.iter().filter(|f| f.last_error.is_some()).count(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -398,37 +936,7 @@ mod tests {
 
     /// Create an in-memory database with the production schema.
     fn test_db() -> Connection {
-        let conn = Connection::open_in_memory().unwrap();
-        conn.execute_batch(
-            "PRAGMA foreign_keys=ON;
-
-            CREATE TABLE feeds (
-                id            INTEGER PRIMARY KEY AUTOINCREMENT,
-                group_title   TEXT NOT NULL,
-                title         TEXT NOT NULL,
-                url           TEXT NOT NULL UNIQUE,
-                site_url      TEXT,
-                last_fetched  TEXT
-            );
-
-            CREATE TABLE articles (
-                id          INTEGER PRIMARY KEY AUTOINCREMENT,
-                feed_id     INTEGER NOT NULL REFERENCES feeds(id) ON DELETE CASCADE,
-                guid        TEXT NOT NULL,
-                title       TEXT NOT NULL DEFAULT '',
-                url         TEXT,
-                author      TEXT,
-                summary     TEXT,
-                content     TEXT,
-                published   TEXT,
-                is_read     INTEGER NOT NULL DEFAULT 0,
-                is_starred  INTEGER NOT NULL DEFAULT 0,
-                created_at  TEXT NOT NULL DEFAULT (datetime('now')),
-                UNIQUE(feed_id, guid)
-            );",
-        )
-        .unwrap();
-        conn
+        open_in_memory().unwrap()
     }
 
     fn sample_config() -> Config {
@@ -439,12 +947,23 @@ mod tests {
                     title: "Rust Blog".into(),
                     url: "https://blog.rust-lang.org/".into(),
                     feed: Some("https://blog.rust-lang.org/feed.xml".into()),
+                    ..Default::default()
                 })],
             })],
             ..Config::default()
         }
     }
 
+    #[test]
+    fn open_on_disk_fails_when_data_dir_cannot_be_created() {
+        // A regular file where the data directory's parent should be
+        let file = std::env::temp_dir().join(format!("lazyrss-test-{}", std::process::id()));
+        std::fs::write(&file, "").unwrap();
+        let result = open_on_disk(&file.join("lazyrss"));
+        std::fs::remove_file(&file).unwrap();
+        assert!(result.is_err());
+    }
+
     #[test]
     fn sync_feeds_inserts_new_feeds() {
         let conn = test_db();
@@ -481,6 +1000,7 @@ mod tests {
                     title: "Rust Blog".into(),
                     url: "https://blog.rust-lang.org/".into(),
                     feed: Some("https://blog.rust-lang.org/feed.xml".into()),
+                    ..Default::default()
                 })],
             })],
             ..Config::default()
@@ -495,6 +1015,7 @@ mod tests {
                     title: "Rust Blog (Updated)".into(),
                     url: "https://blog.rust-lang.org/".into(),
                     feed: Some("https://blog.rust-lang.org/feed.xml".into()),
+                    ..Default::default()
                 })],
             })],
             ..Config::default()
@@ -520,11 +1041,13 @@ mod tests {
                         title: "Rust Blog".into(),
                         url: "https://blog.rust-lang.org/".into(),
                         feed: Some("https://blog.rust-lang.org/feed.xml".into()),
+                        ..Default::default()
                     }),
                     FeedConfigItem::Standalone(FeedSource {
                         title: "Go Blog".into(),
                         url: "https://go.dev/blog/".into(),
                         feed: Some("https://go.dev/blog/feed.xml".into()),
+                        ..Default::default()
                     }),
                 ],
             })],
@@ -541,6 +1064,7 @@ mod tests {
                     title: "Rust Blog".into(),
                     url: "https://blog.rust-lang.org/".into(),
                     feed: Some("https://blog.rust-lang.org/feed.xml".into()),
+                    ..Default::default()
                 })],
             })],
             ..Config::default()
@@ -564,6 +1088,7 @@ mod tests {
                     title: "Rust Blog".into(),
                     url: "https://blog.rust-lang.org/".into(),
                     feed: Some("https://blog.rust-lang.org/feed.xml".into()),
+                    ..Default::default()
                 })],
             })],
             ..Config::default()
@@ -592,6 +1117,7 @@ mod tests {
                     title: "BAIR".into(),
                     url: "http://bair.berkeley.edu/blog/".into(),
                     feed: Some("https://bair.berkeley.edu/blog/feed.xml".into()),
+                    ..Default::default()
                 }),
                 FeedConfigItem::Group(FeedGroup {
                     title: "Tech".into(),
@@ -599,6 +1125,7 @@ mod tests {
                         title: "Rust Blog".into(),
                         url: "https://blog.rust-lang.org/".into(),
                         feed: Some("https://blog.rust-lang.org/feed.xml".into()),
+                        ..Default::default()
                     })],
                 }),
             ],
@@ -618,6 +1145,34 @@ mod tests {
         assert_eq!(rust.group_title, "Tech");
     }
 
+    #[test]
+    fn get_all_feeds_follows_config_order() {
+        let conn = test_db();
+        let source = |title: &str| FeedSource {
+            title: title.into(),
+            url: format!("https://{title}.example.com/feed"),
+            feed: None,
+            ..Default::default()
+        };
+        let mut config = Config {
+            feeds: vec![
+                FeedConfigItem::Standalone(source("zulu")),
+                FeedConfigItem::Standalone(source("alpha")),
+                FeedConfigItem::Standalone(source("mike")),
+            ],
+            ..Config::default()
+        };
+        sync_feeds_from_config(&conn, &config).unwrap();
+        let titles: Vec<String> = get_all_feeds(&conn).unwrap().into_iter().map(|f| f.title).collect();
+        assert_eq!(titles, vec!["zulu", "alpha", "mike"]);
+
+        // Reordering the config reorders existing feeds
+        config.feeds.swap(0, 2);
+        sync_feeds_from_config(&conn, &config).unwrap();
+        let titles: Vec<String> = get_all_feeds(&conn).unwrap().into_iter().map(|f| f.title).collect();
+        assert_eq!(titles, vec!["mike", "alpha", "zulu"]);
+    }
+
     #[test]
     fn sync_feeds_handles_nested_groups() {
         let conn = test_db();
@@ -629,6 +1184,7 @@ mod tests {
                     title: "BAIR".into(),
                     url: "http://bair.berkeley.edu/blog/".into(),
                     feed: Some("https://bair.berkeley.edu/blog/feed.xml".into()),
+                    ..Default::default()
                 }),
                 FeedConfigItem::Group(FeedGroup {
                     title: "News (World)".into(),
@@ -637,6 +1193,7 @@ mod tests {
                             title: "Foreign Policy".into(),
                             url: "https://foreignpolicy.com".into(),
                             feed: Some("http://foreignpolicy.com/feed".into()),
+                            ..Default::default()
                         }),
                         FeedConfigItem::Group(FeedGroup {
                             title: "Domestic".into(),
@@ -645,6 +1202,7 @@ mod tests {
                                     title: "BBC World News".into(),
                                     url: "https://www.bbc.co.uk/news/".into(),
                                     feed: Some("http://feeds.bbci.co.uk/news/world/rss.xml".into()),
+                                    ..Default::default()
                                 }),
                             ],
                         }),
@@ -692,6 +1250,9 @@ mod tests {
                 published: Some(Utc::now()),
                 is_read: false,
                 is_starred: false,
+                is_queued: false,
+                enclosure_url: None,
+                has_full_content: false,
             },
             Article {
                 id: 0,
@@ -705,31 +1266,49 @@ mod tests {
                 published: None,
                 is_read: false,
                 is_starred: false,
+                is_queued: false,
+                enclosure_url: Some("https://example.com/2.mp3".into()),
+                has_full_content: false,
             },
         ];
 
-        let inserted = upsert_articles(&conn, &articles).unwrap();
-        assert_eq!(inserted, 2);
+        let counts = upsert_articles(&conn, &articles, false).unwrap();
+        assert_eq!(counts, UpsertCounts { inserted: 2, updated: 0, newest_title: Some("First Post".into()) });
 
         // Inserting the same articles again should not duplicate.
-        let inserted_again = upsert_articles(&conn, &articles).unwrap();
-        assert_eq!(inserted_again, 0);
+        let counts_again = upsert_articles(&conn, &articles, false).unwrap();
+        assert_eq!(counts_again, UpsertCounts::default());
+
+        // The newest title comes from the inserted articles only, even when
+        // an already stored one is newer
+        let older = Article {
+            guid: "guid-3".into(),
+            title: "Older Post".into(),
+            published: Some(Utc::now() - chrono::Duration::days(3)),
+            ..articles[0].clone()
+        };
+        let counts = upsert_articles(&conn, &[articles[0].clone(), older], false).unwrap();
+        assert_eq!(counts.newest_title.as_deref(), Some("Older Post"));
 
         let stored = get_articles_for_feed(&conn, feed_id).unwrap();
-        assert_eq!(stored.len(), 2);
+        assert_eq!(stored.len(), 3);
+        let second = stored.iter().find(|a| a.guid == "guid-2").unwrap();
+        assert_eq!(second.enclosure_url.as_deref(), Some("https://example.com/2.mp3"));
     }
 
     #[test]
-    fn toggle_read_and_star() {
+    fn status_summary_counts_unread_and_errors() {
         let conn = test_db();
         let config = sample_config();
         sync_feeds_from_config(&conn, &config).unwrap();
-        let feed_id = get_all_feeds(&conn).unwrap()[0].id;
+        let feeds = get_all_feeds(&conn).unwrap();
+        let feed_id = feeds[0].id;
 
-        let articles = vec![Article {
+        set_last_error(&conn, feed_id, Some("HTTP 500")).unwrap();
+        let article = Article {
             id: 0,
             feed_id,
-            guid: "g1".into(),
+            guid: "g".into(),
             title: "Post".into(),
             url: None,
             author: None,
@@ -738,27 +1317,26 @@ mod tests {
             published: None,
             is_read: false,
             is_starred: false,
-        }];
-        upsert_articles(&conn, &articles).unwrap();
-
-        let stored = get_articles_for_feed(&conn, feed_id).unwrap();
-        let article_id = stored[0].id;
-
-        assert!(!stored[0].is_read);
-        let new_read = toggle_read(&conn, article_id).unwrap();
-        assert!(new_read);
-        let new_read = toggle_read(&conn, article_id).unwrap();
-        assert!(!new_read);
-
-        assert!(!stored[0].is_starred);
-        let new_star = toggle_star(&conn, article_id).unwrap();
-        assert!(new_star);
-        let new_star = toggle_star(&conn, article_id).unwrap();
-        assert!(!new_star);
+            is_queued: false,
+            enclosure_url: None,
+            has_full_content: false,
+        };
+        upsert_articles(&conn, &[article], false).unwrap();
+
+        let summary = StatusSummary::from_feeds(&get_all_feeds(&conn).unwrap());
+        assert_eq!(summary, StatusSummary { feeds: feeds.len(), unread_total: 1, feeds_with_errors: 1 });
+        assert_eq!(
+            serde_json::to_string(&summary).unwrap(),
+            format!(r#"{{"feeds":{},"unread_total":1,"feeds_with_errors":1}}"#, feeds.len())
+        );
+
+        // A successful fetch clears the error
+        set_last_error(&conn, feed_id, None).unwrap();
+        assert_eq!(StatusSummary::from_feeds(&get_all_feeds(&conn).unwrap()).feeds_with_errors, 0);
     }
 
     #[test]
-    fn mark_all_read_works() {
+    fn get_all_feeds_with_totals_counts_every_article() {
         let conn = test_db();
         let config = sample_config();
         sync_feeds_from_config(&conn, &config).unwrap();
@@ -777,18 +1355,649 @@ mod tests {
                 published: None,
                 is_read: false,
                 is_starred: false,
+                is_queued: false,
+                enclosure_url: None,
+                has_full_content: false,
             })
             .collect();
-        upsert_articles(&conn, &articles).unwrap();
-
-        // Verify unread count.
-        let feeds = get_all_feeds(&conn).unwrap();
-        assert_eq!(feeds[0].unread_count, 3);
+        upsert_articles(&conn, &articles, false).unwrap();
+        let id = get_articles_for_feed(&conn, feed_id).unwrap()[0].id;
+        toggle_read(&conn, id).unwrap();
+
+        let feed = &get_all_feeds_with_totals(&conn).unwrap()[0];
+        assert_eq!((feed.unread_count, feed.total_count), (2, 3));
+        // Without totals the subquery is skipped
+        assert_eq!(get_all_feeds(&conn).unwrap()[0].total_count, 0);
+    }
 
-        mark_all_read(&conn, feed_id).unwrap();
+    #[test]
+    fn upsert_updates_changed_article_and_keeps_flags() {
+        let conn = test_db();
+        let config = sample_config();
+        sync_feeds_from_config(&conn, &config).unwrap();
+        let feed_id = get_all_feeds(&conn).unwrap()[0].id;
 
-        let feeds = get_all_feeds(&conn).unwrap();
-        assert_eq!(feeds[0].unread_count, 0);
+        let mut article = Article {
+            id: 0,
+            feed_id,
+            guid: "guid-1".into(),
+            title: "Post".into(),
+            url: Some("https://example.com/old".into()),
+            author: None,
+            summary: None,
+            content: None,
+            published: None,
+            is_read: false,
+            is_starred: false,
+            is_queued: false,
+            enclosure_url: None,
+            has_full_content: false,
+        };
+        upsert_articles(&conn, std::slice::from_ref(&article), false).unwrap();
+        let id = get_articles_for_feed(&conn, feed_id).unwrap()[0].id;
+        toggle_read(&conn, id).unwrap();
+        set_starred(&conn, id, true, false).unwrap();
+
+        // Same GUID, corrected link and title
+        article.url = Some("https://example.com/new".into());
+        article.title = "Post (corrected)".into();
+        let counts = upsert_articles(&conn, std::slice::from_ref(&article), false).unwrap();
+        assert_eq!(counts, UpsertCounts { inserted: 0, updated: 1, newest_title: None });
+
+        let stored = get_articles_for_feed(&conn, feed_id).unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].id, id);
+        assert_eq!(stored[0].url.as_deref(), Some("https://example.com/new"));
+        assert_eq!(stored[0].title, "Post (corrected)");
+        assert!(stored[0].is_read);
+        assert!(stored[0].is_starred);
+    }
+
+    #[test]
+    fn upsert_marks_edited_article_unread_when_asked() {
+        let conn = test_db();
+        let config = sample_config();
+        sync_feeds_from_config(&conn, &config).unwrap();
+        let feed_id = get_all_feeds(&conn).unwrap()[0].id;
+
+        let mut article = Article {
+            id: 0,
+            feed_id,
+            guid: "changelog".into(),
+            title: "Changelog".into(),
+            url: None,
+            author: None,
+            summary: None,
+            content: Some("<p>v1.0: first release</p>".into()),
+            published: None,
+            is_read: false,
+            is_starred: false,
+            is_queued: false,
+            enclosure_url: None,
+            has_full_content: false,
+        };
+        let upsert = |article: &Article| {
+            upsert_articles(&conn, std::slice::from_ref(article), true).unwrap();
+            get_articles_for_feed(&conn, feed_id).unwrap()[0].is_read
+        };
+        upsert(&article);
+        let id = get_articles_for_feed(&conn, feed_id).unwrap()[0].id;
+        toggle_read(&conn, id).unwrap();
+
+        // Reflowed markup is not an edit
+        article.content = Some("<p>v1.0:\n  first release</p>".into());
+        assert!(upsert(&article));
+        // A title change alone isn't either
+        article.title = "Changelog (2024)".into();
+        assert!(upsert(&article));
+
+        article.content = Some("<p>v1.1: bug fixes</p><p>v1.0: first release</p>".into());
+        assert!(!upsert(&article));
+    }
+
+    #[test]
+    fn full_content_replaces_feed_content_until_the_feed_changes() {
+        let conn = test_db();
+        let config = sample_config();
+        sync_feeds_from_config(&conn, &config).unwrap();
+        let feed_id = get_all_feeds(&conn).unwrap()[0].id;
+
+        let mut article = Article {
+            id: 0,
+            feed_id,
+            guid: "post".into(),
+            title: "Post".into(),
+            url: Some("https://example.com/post".into()),
+            author: None,
+            summary: None,
+            content: Some("<p>Teaser…</p>".into()),
+            published: None,
+            is_read: false,
+            is_starred: false,
+            is_queued: false,
+            enclosure_url: None,
+            has_full_content: false,
+        };
+        upsert_articles(&conn, std::slice::from_ref(&article), false).unwrap();
+        let stored = || get_articles_for_feed(&conn, feed_id).unwrap().remove(0);
+
+        update_article_content(&conn, stored().id, "<p>The whole post.</p>").unwrap();
+        assert_eq!(stored().content.as_deref(), Some("<p>The whole post.</p>"));
+        assert!(stored().has_full_content);
+
+        // A refresh with the same teaser keeps the full text
+        article.title = "Post (updated)".into();
+        upsert_articles(&conn, std::slice::from_ref(&article), false).unwrap();
+        assert!(stored().has_full_content);
+
+        article.content = Some("<p>New teaser…</p>".into());
+        upsert_articles(&conn, std::slice::from_ref(&article), false).unwrap();
+        assert_eq!(stored().content.as_deref(), Some("<p>New teaser…</p>"));
+        assert!(!stored().has_full_content);
+    }
+
+    #[test]
+    fn migrations_upgrade_existing_database() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE articles (
+                id          INTEGER PRIMARY KEY AUTOINCREMENT,
+                feed_id     INTEGER NOT NULL,
+                guid        TEXT NOT NULL,
+                title       TEXT NOT NULL DEFAULT '',
+                url         TEXT,
+                author      TEXT,
+                summary     TEXT,
+                content     TEXT,
+                published   TEXT,
+                is_read     INTEGER NOT NULL DEFAULT 0,
+                is_starred  INTEGER NOT NULL DEFAULT 0,
+                created_at  TEXT NOT NULL DEFAULT (datetime('now')),
+                UNIQUE(feed_id, guid)
+            );",
+        )
+        .unwrap();
+
+        create_schema(&conn).unwrap();
+        run_migrations(&conn).unwrap();
+        // Running again must be a no-op.
+        create_schema(&conn).unwrap();
+        run_migrations(&conn).unwrap();
+
+        let count: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('articles') WHERE name = 'enclosure_url'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(count, 1);
+        assert_eq!(schema_version(&conn).unwrap(), MIGRATIONS.len());
+    }
+
+    #[test]
+    fn migrations_tolerate_columns_added_before_versioning() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_schema(&conn).unwrap();
+        // Older versions added columns without recording a schema version
+        add_column_if_missing(&conn, "feeds", "mark_read_on", "TEXT").unwrap();
+        add_column_if_missing(&conn, "articles", "enclosure_url", "TEXT").unwrap();
+
+        run_migrations(&conn).unwrap();
+        assert_eq!(schema_version(&conn).unwrap(), MIGRATIONS.len());
+        let rows: i64 = conn.query_row("SELECT COUNT(*) FROM schema_version", [], |row| row.get(0)).unwrap();
+        assert_eq!(rows, 1);
+    }
+
+    #[test]
+    fn last_fetched_round_trips() {
+        let conn = test_db();
+        sync_feeds_from_config(&conn, &sample_config()).unwrap();
+        let feed_id = get_all_feeds(&conn).unwrap()[0].id;
+        assert!(get_all_feeds(&conn).unwrap()[0].last_fetched.is_none());
+
+        update_last_fetched(&conn, feed_id).unwrap();
+        assert!(get_all_feeds(&conn).unwrap()[0].last_fetched.is_some());
+
+        // Timestamps written by SQLite's datetime('now') are still readable.
+        assert!(parse_optional_datetime(Some("2024-05-06 07:08:09".into())).is_some());
+    }
+
+    #[test]
+    fn normalize_url_strips_query_and_trailing_slash() {
+        assert_eq!(normalize_url("https://example.com/post/?utm_source=x"), "https://example.com/post");
+        assert_eq!(normalize_url("https://example.com/post#comments"), "https://example.com/post");
+        assert_eq!(normalize_url("https://example.com/post"), "https://example.com/post");
+    }
+
+    #[test]
+    fn dedup_by_url_keeps_earliest_and_merges_state() {
+        let article = |id: i64, url: Option<&str>, days_ago: i64, is_read: bool, is_starred: bool| Article {
+            id,
+            feed_id: 1,
+            guid: format!("g{id}"),
+            title: format!("Post {id}"),
+            url: url.map(String::from),
+            author: None,
+            summary: None,
+            content: None,
+            published: Some(Utc::now() - chrono::Duration::days(days_ago)),
+            is_read,
+            is_starred,
+            is_queued: false,
+            enclosure_url: None,
+            has_full_content: false,
+        };
+
+        let articles = vec![
+            article(1, Some("https://a.com/story?ref=agg"), 1, true, false),
+            article(2, Some("https://b.com/other"), 2, false, false),
+            article(3, Some("https://a.com/story/"), 3, false, true),
+            article(4, None, 4, false, false),
+            article(5, None, 5, false, false),
+        ];
+
+        let deduped = dedup_by_url(articles);
+        let ids: Vec<i64> = deduped.iter().map(|a| a.id).collect();
+        assert_eq!(ids, vec![2, 3, 4, 5]);
+
+        let story = &deduped[1];
+        assert!(story.is_read);
+        assert!(story.is_starred);
+    }
+
+    #[test]
+    fn set_read_with_same_url_updates_every_copy() {
+        let conn = test_db();
+        sync_feeds_from_config(&conn, &sample_config()).unwrap();
+        let feed_id = get_all_feeds(&conn).unwrap()[0].id;
+
+        let urls = ["https://example.com/story", "https://example.com/story/?utm_source=x", "https://example.com/other"];
+        let articles: Vec<Article> = urls
+            .iter()
+            .enumerate()
+            .map(|(i, url)| Article {
+                id: 0,
+                feed_id,
+                guid: format!("g{i}"),
+                title: format!("Post {i}"),
+                url: Some(url.to_string()),
+                author: None,
+                summary: None,
+                content: None,
+                published: None,
+                is_read: false,
+                is_starred: false,
+                is_queued: false,
+                enclosure_url: None,
+                has_full_content: false,
+            })
+            .collect();
+        upsert_articles(&conn, &articles, false).unwrap();
+        let id_of = |guid: &str| {
+            get_articles_for_feed(&conn, feed_id).unwrap().into_iter().find(|a| a.guid == guid).unwrap().id
+        };
+
+        let duplicates = unread_url_duplicates(&conn).unwrap();
+        assert_eq!(duplicates.len(), 2);
+        assert!(duplicates.iter().all(|(id, url)| *id == feed_id && url == "https://example.com/story"));
+
+        assert_eq!(set_read(&conn, id_of("g0"), true, true).unwrap(), vec![feed_id, feed_id]);
+        assert_eq!(unread_count_for_feed(&conn, feed_id).unwrap(), 1);
+        assert!(unread_url_duplicates(&conn).unwrap().is_empty());
+
+        // Without same_url only the article itself changes
+        set_read(&conn, id_of("g1"), false, false).unwrap();
+        assert_eq!(unread_count_for_feed(&conn, feed_id).unwrap(), 2);
+        assert_eq!(mark_read_by_ids(&conn, &[id_of("g0")], true).unwrap(), 2);
+        assert_eq!(unread_count_for_feed(&conn, feed_id).unwrap(), 1);
+    }
+
+    #[test]
+    fn sync_feeds_carries_mark_read_on() {
+        let conn = test_db();
+        let config = Config {
+            feeds: vec![FeedConfigItem::Standalone(FeedSource {
+                title: "Firehose".into(),
+                url: "https://example.com/".into(),
+                feed: Some("https://example.com/feed.xml".into()),
+                mark_read_on: Some("open".into()),
+                ..Default::default()
+            })],
+            ..Config::default()
+        };
+        sync_feeds_from_config(&conn, &config).unwrap();
+
+        let feeds = get_all_feeds(&conn).unwrap();
+        assert_eq!(feeds[0].mark_read_on.as_deref(), Some("open"));
+    }
+
+    #[test]
+    fn feed_title_shown_only_with_use_feed_title() {
+        let conn = test_db();
+        let config = |use_feed_title| Config {
+            feeds: vec![FeedConfigItem::Standalone(FeedSource {
+                title: "Example".into(),
+                url: "https://example.com/".into(),
+                feed: Some("https://example.com/feed.xml".into()),
+                use_feed_title,
+                ..Default::default()
+            })],
+            ..Config::default()
+        };
+        sync_feeds_from_config(&conn, &config(false)).unwrap();
+        let feed_id = get_all_feeds(&conn).unwrap()[0].id;
+
+        // The publisher's title never replaces the config title by default
+        set_feed_title(&conn, feed_id, "Untitled Feed 12345").unwrap();
+        sync_feeds_from_config(&conn, &config(false)).unwrap();
+        assert_eq!(get_all_feeds(&conn).unwrap()[0].title, "Example");
+
+        sync_feeds_from_config(&conn, &config(true)).unwrap();
+        assert_eq!(get_all_feeds(&conn).unwrap()[0].title, "Untitled Feed 12345");
+    }
+
+    #[test]
+    fn toggle_read_and_star() {
+        let conn = test_db();
+        let config = sample_config();
+        sync_feeds_from_config(&conn, &config).unwrap();
+        let feed_id = get_all_feeds(&conn).unwrap()[0].id;
+
+        let articles = vec![Article {
+            id: 0,
+            feed_id,
+            guid: "g1".into(),
+            title: "Post".into(),
+            url: None,
+            author: None,
+            summary: None,
+            content: None,
+            published: None,
+            is_read: false,
+            is_starred: false,
+            is_queued: false,
+            enclosure_url: None,
+            has_full_content: false,
+        }];
+        upsert_articles(&conn, &articles, false).unwrap();
+
+        let stored = get_articles_for_feed(&conn, feed_id).unwrap();
+        let article_id = stored[0].id;
+
+        assert!(!stored[0].is_read);
+        assert_eq!(unread_count_for_feed(&conn, feed_id).unwrap(), 1);
+        let new_read = toggle_read(&conn, article_id).unwrap();
+        assert!(new_read);
+        assert_eq!(unread_count_for_feed(&conn, feed_id).unwrap(), 0);
+        let new_read = toggle_read(&conn, article_id).unwrap();
+        assert!(!new_read);
+
+        assert!(!stored[0].is_starred);
+        assert_eq!(set_starred(&conn, article_id, true, false).unwrap(), vec![feed_id]);
+        // Setting the value it already has changes nothing
+        assert!(set_starred(&conn, article_id, true, false).unwrap().is_empty());
+        assert_eq!(set_starred(&conn, article_id, false, false).unwrap(), vec![feed_id]);
+    }
+
+    #[test]
+    fn starred_articles_and_count() {
+        let conn = test_db();
+        let config = sample_config();
+        sync_feeds_from_config(&conn, &config).unwrap();
+        let feed_id = get_all_feeds(&conn).unwrap()[0].id;
+
+        let articles: Vec<Article> = (0..3)
+            .map(|i| Article {
+                id: 0,
+                feed_id,
+                guid: format!("g{i}"),
+                title: format!("Post {i}"),
+                url: None,
+                author: None,
+                summary: None,
+                content: None,
+                published: None,
+                is_read: false,
+                is_starred: false,
+                is_queued: false,
+                enclosure_url: None,
+                has_full_content: false,
+            })
+            .collect();
+        upsert_articles(&conn, &articles, false).unwrap();
+        assert_eq!(count_starred(&conn).unwrap(), 0);
+
+        let stored = get_articles_for_feed(&conn, feed_id).unwrap();
+        let post1 = stored.iter().find(|a| a.guid == "g1").unwrap();
+        set_starred(&conn, post1.id, true, false).unwrap();
+
+        let starred = get_starred_articles(&conn).unwrap();
+        assert_eq!(starred.len(), 1);
+        assert_eq!(starred[0].guid, "g1");
+        assert_eq!(count_starred(&conn).unwrap(), 1);
+    }
+
+    #[test]
+    fn queue_is_separate_from_stars() {
+        let conn = test_db();
+        let config = sample_config();
+        sync_feeds_from_config(&conn, &config).unwrap();
+        let feed_id = get_all_feeds(&conn).unwrap()[0].id;
+
+        let articles: Vec<Article> = (0..3)
+            .map(|i| Article {
+                id: 0,
+                feed_id,
+                guid: format!("g{i}"),
+                title: format!("Post {i}"),
+                url: None,
+                author: None,
+                summary: None,
+                content: None,
+                published: None,
+                is_read: false,
+                is_starred: false,
+                is_queued: false,
+                enclosure_url: None,
+                has_full_content: false,
+            })
+            .collect();
+        upsert_articles(&conn, &articles, false).unwrap();
+        assert_eq!(count_queued(&conn).unwrap(), 0);
+
+        let stored = get_articles_for_feed(&conn, feed_id).unwrap();
+        let post1 = stored.iter().find(|a| a.guid == "g1").unwrap();
+        let post2 = stored.iter().find(|a| a.guid == "g2").unwrap();
+        set_queued(&conn, post1.id, true, false).unwrap();
+        set_starred(&conn, post2.id, true, false).unwrap();
+
+        let queued = get_queued_articles(&conn).unwrap();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].guid, "g1");
+        assert!(!queued[0].is_starred);
+        assert_eq!(count_queued(&conn).unwrap(), 1);
+
+        // Refreshing the feed keeps the article queued
+        upsert_articles(&conn, &articles, false).unwrap();
+        assert_eq!(count_queued(&conn).unwrap(), 1);
+
+        set_queued(&conn, post1.id, false, false).unwrap();
+        assert!(get_queued_articles(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn recent_articles_skip_old_and_undated() {
+        let conn = test_db();
+        let config = sample_config();
+        sync_feeds_from_config(&conn, &config).unwrap();
+        let feed_id = get_all_feeds(&conn).unwrap()[0].id;
+
+        let now = Utc::now();
+        let published = [
+            ("fresh", Some(now - chrono::Duration::hours(2))),
+            ("stale", Some(now - chrono::Duration::hours(30))),
+            ("undated", None),
+        ];
+        let articles: Vec<Article> = published
+            .iter()
+            .map(|(guid, published)| Article {
+                id: 0,
+                feed_id,
+                guid: guid.to_string(),
+                title: guid.to_string(),
+                url: None,
+                author: None,
+                summary: None,
+                content: None,
+                published: *published,
+                is_read: false,
+                is_starred: false,
+                is_queued: false,
+                enclosure_url: None,
+                has_full_content: false,
+            })
+            .collect();
+        upsert_articles(&conn, &articles, false).unwrap();
+
+        let recent = get_recent_articles(&conn, now - chrono::Duration::hours(24)).unwrap();
+        let guids: Vec<&str> = recent.iter().map(|a| a.guid.as_str()).collect();
+        assert_eq!(guids, vec!["fresh"]);
+    }
+
+    #[test]
+    fn mark_all_read_works() {
+        let conn = test_db();
+        let config = sample_config();
+        sync_feeds_from_config(&conn, &config).unwrap();
+        let feed_id = get_all_feeds(&conn).unwrap()[0].id;
+
+        let articles: Vec<Article> = (0..3)
+            .map(|i| Article {
+                id: 0,
+                feed_id,
+                guid: format!("g{i}"),
+                title: format!("Post {i}"),
+                url: None,
+                author: None,
+                summary: None,
+                content: None,
+                published: None,
+                is_read: false,
+                is_starred: false,
+                is_queued: false,
+                enclosure_url: None,
+                has_full_content: false,
+            })
+            .collect();
+        upsert_articles(&conn, &articles, false).unwrap();
+
+        // Verify unread count.
+        let feeds = get_all_feeds(&conn).unwrap();
+        assert_eq!(feeds[0].unread_count, 3);
+
+        mark_all_read(&conn, feed_id).unwrap();
+
+        let feeds = get_all_feeds(&conn).unwrap();
+        assert_eq!(feeds[0].unread_count, 0);
+    }
+
+    #[test]
+    fn mark_all_read_for_group_respects_recursive() {
+        let conn = test_db();
+        let config: Config = serde_yaml::from_str(
+            r#"
+feeds:
+  - title: "Tech"
+    feeds:
+      - title: "Rust Blog"
+        url: "https://blog.rust-lang.org/feed.xml"
+      - title: "Programming"
+        feeds:
+          - title: "Go Blog"
+            url: "https://go.dev/blog/feed.atom"
+  - title: "Tech_Old"
+    feeds:
+      - title: "Old Blog"
+        url: "https://old.example.com/feed.xml"
+"#,
+        )
+        .unwrap();
+        sync_feeds_from_config(&conn, &config).unwrap();
+
+        for feed in get_all_feeds(&conn).unwrap() {
+            let article = Article {
+                id: 0,
+                feed_id: feed.id,
+                guid: "g".to_string(),
+                title: "Post".to_string(),
+                url: None,
+                author: None,
+                summary: None,
+                content: None,
+                published: None,
+                is_read: false,
+                is_starred: false,
+                is_queued: false,
+                enclosure_url: None,
+                has_full_content: false,
+            };
+            upsert_articles(&conn, &[article], false).unwrap();
+        }
+        let unread = |conn: &Connection| -> Vec<(String, u32)> {
+            get_all_feeds(conn).unwrap().into_iter().map(|f| (f.title, f.unread_count)).collect()
+        };
+
+        mark_all_read_for_group(&conn, "Tech", false).unwrap();
+        let counts = unread(&conn);
+        assert!(counts.contains(&("Rust Blog".to_string(), 0)));
+        assert!(counts.contains(&("Go Blog".to_string(), 1)));
+        assert!(counts.contains(&("Old Blog".to_string(), 1)));
+
+        mark_all_read_for_group(&conn, "Tech", true).unwrap();
+        let counts = unread(&conn);
+        assert!(counts.contains(&("Go Blog".to_string(), 0)));
+        assert!(counts.contains(&("Old Blog".to_string(), 1)));
+    }
+
+    #[test]
+    fn mark_read_by_ids_marks_only_given_articles() {
+        let conn = test_db();
+        let config = sample_config();
+        sync_feeds_from_config(&conn, &config).unwrap();
+        let feed_id = get_all_feeds(&conn).unwrap()[0].id;
+
+        let articles: Vec<Article> = (0..3)
+            .map(|i| Article {
+                id: 0,
+                feed_id,
+                guid: format!("g{i}"),
+                title: format!("Post {i}"),
+                url: None,
+                author: None,
+                summary: None,
+                content: None,
+                published: None,
+                is_read: false,
+                is_starred: false,
+                is_queued: false,
+                enclosure_url: None,
+                has_full_content: false,
+            })
+            .collect();
+        upsert_articles(&conn, &articles, false).unwrap();
+
+        let ids: Vec<i64> = get_articles_for_feed(&conn, feed_id)
+            .unwrap()
+            .iter()
+            .filter(|a| a.guid != "g2")
+            .map(|a| a.id)
+            .collect();
+        assert_eq!(mark_read_by_ids(&conn, &ids, false).unwrap(), 2);
+        assert_eq!(mark_read_by_ids(&conn, &[], false).unwrap(), 0);
+
+        let feeds = get_all_feeds(&conn).unwrap();
+        assert_eq!(feeds[0].unread_count, 1);
     }
 
     #[test]
@@ -804,17 +2013,14 @@ mod tests {
 
         update_last_fetched(&conn, feed_id).unwrap();
 
-        let _feeds2 = get_all_feeds(&conn).unwrap();
-        // `datetime('now')` produces an ISO-8601 string without timezone offset,
-        // which won't parse as RFC 3339. That's acceptable; the column is mainly
-        // informational.  In production we could store RFC 3339 explicitly.
-        // For this test we just verify the column is no longer NULL by checking
-        // the raw value.
-        let raw: Option<String> = conn
+        // Stored as RFC 3339, so it parses back into a timestamp.
+        let raw: String = conn
             .query_row("SELECT last_fetched FROM feeds WHERE id = ?1", [feed_id], |row| {
                 row.get(0)
             })
             .unwrap();
-        assert!(raw.is_some());
+        assert!(DateTime::parse_from_rfc3339(&raw).is_ok());
+        let fetched = get_all_feeds(&conn).unwrap()[0].last_fetched.unwrap();
+        assert!((Utc::now() - fetched).num_seconds() < 60);
     }
 }
@@ -1,11 +1,11 @@
-//! Async database operations using tokio::spawn_blocking.
+//! Async database operations on a dedicated worker thread.
 //!
 //! rusqlite is a synchronous library, so all database operations would otherwise
-//! block the main TUI thread. This module wraps each operation in a blocking task
-//! and returns results via channels.
+//! block the main TUI thread. This module sends each operation as a
+//! [`DbCommand`] to a thread that owns the connection and returns results via
+//! channels.
 
-use std::sync::Arc;
-use tokio::sync::{mpsc, oneshot, Mutex};
+use tokio::sync::{mpsc, oneshot};
 
 use crate::db;
 
@@ -13,6 +13,7 @@ use crate::db;
 pub enum DbCommand {
     /// Get all feeds with unread counts.
     GetAllFeeds {
+        with_totals: bool,
         respond_to: oneshot::Sender<anyhow::Result<Vec<db::Feed>>>,
     },
 
@@ -33,10 +34,43 @@ pub enum DbCommand {
         respond_to: oneshot::Sender<anyhow::Result<Vec<db::Article>>>,
     },
 
+    /// Get all starred articles.
+    GetStarredArticles {
+        respond_to: oneshot::Sender<anyhow::Result<Vec<db::Article>>>,
+    },
+
+    /// Get all articles in the "read later" queue.
+    GetQueuedArticles {
+        respond_to: oneshot::Sender<anyhow::Result<Vec<db::Article>>>,
+    },
+
+    /// Get articles published since a point in time.
+    GetRecentArticles {
+        since: chrono::DateTime<chrono::Utc>,
+        respond_to: oneshot::Sender<anyhow::Result<Vec<db::Article>>>,
+    },
+
+    /// Count starred articles.
+    CountStarred {
+        respond_to: oneshot::Sender<anyhow::Result<u32>>,
+    },
+
+    /// Count articles in the "read later" queue.
+    CountQueued {
+        respond_to: oneshot::Sender<anyhow::Result<u32>>,
+    },
+
+    /// Count the unread articles of one feed.
+    UnreadCountForFeed {
+        feed_id: i64,
+        respond_to: oneshot::Sender<anyhow::Result<u32>>,
+    },
+
     /// Upsert articles (insert new ones, ignore existing by guid).
     UpsertArticles {
         articles: Vec<db::Article>,
-        respond_to: oneshot::Sender<anyhow::Result<usize>>,
+        unread_on_update: bool,
+        respond_to: oneshot::Sender<anyhow::Result<db::UpsertCounts>>,
     },
 
     /// Toggle the read status of an article.
@@ -45,10 +79,34 @@ pub enum DbCommand {
         respond_to: oneshot::Sender<anyhow::Result<bool>>,
     },
 
-    /// Toggle the starred status of an article.
-    ToggleStar {
+    /// Set the read status of an article (and of its same-URL copies).
+    SetRead {
         article_id: i64,
-        respond_to: oneshot::Sender<anyhow::Result<bool>>,
+        value: bool,
+        same_url: bool,
+        respond_to: oneshot::Sender<anyhow::Result<Vec<i64>>>,
+    },
+
+    /// Set the starred status of an article (and of its same-URL copies).
+    SetStarred {
+        article_id: i64,
+        value: bool,
+        same_url: bool,
+        respond_to: oneshot::Sender<anyhow::Result<Vec<i64>>>,
+    },
+
+    /// Add an article (and its same-URL copies) to or remove it from the
+    /// "read later" queue.
+    SetQueued {
+        article_id: i64,
+        value: bool,
+        same_url: bool,
+        respond_to: oneshot::Sender<anyhow::Result<Vec<i64>>>,
+    },
+
+    /// List unread articles that share a normalized URL.
+    UnreadUrlDuplicates {
+        respond_to: oneshot::Sender<anyhow::Result<Vec<(i64, String)>>>,
     },
 
     /// Mark all articles in a feed as read.
@@ -62,12 +120,47 @@ pub enum DbCommand {
         respond_to: oneshot::Sender<anyhow::Result<()>>,
     },
 
+    /// Mark all articles in a group's feeds as read.
+    MarkAllReadForGroup {
+        group_title: String,
+        recursive: bool,
+        respond_to: oneshot::Sender<anyhow::Result<()>>,
+    },
+
+    /// Mark the given articles as read.
+    MarkReadByIds {
+        ids: Vec<i64>,
+        same_url: bool,
+        respond_to: oneshot::Sender<anyhow::Result<usize>>,
+    },
+
     /// Update the last_fetched timestamp for a feed.
     UpdateLastFetched {
         feed_id: i64,
         respond_to: oneshot::Sender<anyhow::Result<()>>,
     },
 
+    /// Record or clear the error from a feed's latest fetch.
+    SetLastError {
+        feed_id: i64,
+        error: Option<String>,
+        respond_to: oneshot::Sender<anyhow::Result<()>>,
+    },
+
+    /// Store the title a feed gives itself.
+    SetFeedTitle {
+        feed_id: i64,
+        title: String,
+        respond_to: oneshot::Sender<anyhow::Result<()>>,
+    },
+
+    /// Store the full text fetched from an article's web page.
+    UpdateArticleContent {
+        article_id: i64,
+        content: String,
+        respond_to: oneshot::Sender<anyhow::Result<()>>,
+    },
+
     /// Sync feeds from config (add new feeds, update existing, delete removed).
     SyncFeedsFromConfig {
         config: crate::config::Config,
@@ -77,130 +170,43 @@ pub enum DbCommand {
 
 /// An async wrapper around a synchronous SQLite database connection.
 ///
-/// This type wraps a `rusqlite::Connection` and processes database commands
-/// via an unbounded channel. Each command is executed in a blocking task
-/// (using `tokio::task::spawn_blocking`) to avoid blocking the main TUI thread.
+/// The connection is owned by a single worker thread that runs commands one
+/// at a time, in the order they were sent. Writes are therefore serialized
+/// without any locking, and the TUI thread never waits on SQLite.
 #[derive(Clone)]
 pub struct AsyncDb {
     tx: mpsc::UnboundedSender<DbCommand>,
-    /// Count of in-flight operations for monitoring purposes.
-    in_flight: Arc<Mutex<usize>>,
 }
 
 impl AsyncDb {
     /// Create a new async database wrapper.
     ///
-    /// Spawns a background task that processes database commands.
-    /// The connection is moved into this task and all operations are
-    /// executed via `spawn_blocking`.
+    /// Moves the connection into a worker thread, which processes commands
+    /// until every clone of the wrapper has been dropped. A command that
+    /// panics only fails that command (its caller sees the response channel
+    /// close); the worker carries on with the next one.
     pub fn new(conn: rusqlite::Connection) -> Self {
         let (tx, mut rx) = mpsc::unbounded_channel();
-        let conn = Arc::new(Mutex::new(conn));
-        let in_flight = Arc::new(Mutex::new(0));
-
-        // Spawn the command processor task.
-        tokio::spawn(async move {
-            while let Some(cmd) = rx.recv().await {
-                match cmd {
-                    DbCommand::GetAllFeeds { respond_to } => {
-                        let conn = Arc::clone(&conn);
-                        tokio::task::spawn_blocking(move || {
-                            let conn = conn.blocking_lock();
-                            let result = db::get_all_feeds(&conn);
-                            let _ = respond_to.send(result);
-                        });
-                    }
-                    DbCommand::GetArticlesForFeed { feed_id, respond_to } => {
-                        let conn = Arc::clone(&conn);
-                        tokio::task::spawn_blocking(move || {
-                            let conn = conn.blocking_lock();
-                            let result = db::get_articles_for_feed(&conn, feed_id);
-                            let _ = respond_to.send(result);
-                        });
-                    }
-                    DbCommand::GetArticlesForGroup { group_title, respond_to } => {
-                        let conn = Arc::clone(&conn);
-                        tokio::task::spawn_blocking(move || {
-                            let conn = conn.blocking_lock();
-                            let result = db::get_articles_for_group(&conn, &group_title);
-                            let _ = respond_to.send(result);
-                        });
-                    }
-                    DbCommand::GetAllArticles { respond_to } => {
-                        let conn = Arc::clone(&conn);
-                        tokio::task::spawn_blocking(move || {
-                            let conn = conn.blocking_lock();
-                            let result = db::get_all_articles(&conn);
-                            let _ = respond_to.send(result);
-                        });
-                    }
-                    DbCommand::UpsertArticles { articles, respond_to } => {
-                        let conn = Arc::clone(&conn);
-                        tokio::task::spawn_blocking(move || {
-                            let conn = conn.blocking_lock();
-                            let result = db::upsert_articles(&conn, &articles);
-                            let _ = respond_to.send(result);
-                        });
-                    }
-                    DbCommand::ToggleRead { article_id, respond_to } => {
-                        let conn = Arc::clone(&conn);
-                        tokio::task::spawn_blocking(move || {
-                            let conn = conn.blocking_lock();
-                            let result = db::toggle_read(&conn, article_id);
-                            let _ = respond_to.send(result);
-                        });
-                    }
-                    DbCommand::ToggleStar { article_id, respond_to } => {
-                        let conn = Arc::clone(&conn);
-                        tokio::task::spawn_blocking(move || {
-                            let conn = conn.blocking_lock();
-                            let result = db::toggle_star(&conn, article_id);
-                            let _ = respond_to.send(result);
-                        });
-                    }
-                    DbCommand::MarkAllRead { feed_id, respond_to } => {
-                        let conn = Arc::clone(&conn);
-                        tokio::task::spawn_blocking(move || {
-                            let conn = conn.blocking_lock();
-                            let result = db::mark_all_read(&conn, feed_id);
-                            let _ = respond_to.send(result);
-                        });
-                    }
-                    DbCommand::MarkAllReadAll { respond_to } => {
-                        let conn = Arc::clone(&conn);
-                        tokio::task::spawn_blocking(move || {
-                            let conn = conn.blocking_lock();
-                            let result = db::mark_all_read_all(&conn);
-                            let _ = respond_to.send(result);
-                        });
-                    }
-                    DbCommand::UpdateLastFetched { feed_id, respond_to } => {
-                        let conn = Arc::clone(&conn);
-                        tokio::task::spawn_blocking(move || {
-                            let conn = conn.blocking_lock();
-                            let result = db::update_last_fetched(&conn, feed_id);
-                            let _ = respond_to.send(result);
-                        });
-                    }
-                    DbCommand::SyncFeedsFromConfig { config, respond_to } => {
-                        let conn = Arc::clone(&conn);
-                        tokio::task::spawn_blocking(move || {
-                            let conn = conn.blocking_lock();
-                            let result = db::sync_feeds_from_config(&conn, &config);
-                            let _ = respond_to.send(result);
-                        });
-                    }
+
+        std::thread::spawn(move || {
+            while let Some(cmd) = rx.blocking_recv() {
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| run_command(&conn, cmd)));
+                if let Err(panic) = result {
+                    let message = panic.downcast_ref::<&str>().copied()
+                        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+                        .unwrap_or("unknown panic");
+                    tracing::error!("database command panicked: {message}");
                 }
             }
         });
 
-        Self { tx, in_flight }
+        Self { tx }
     }
 
-    /// Get all feeds with unread counts.
-    pub async fn get_all_feeds(&self) -> anyhow::Result<Vec<db::Feed>> {
+    /// Get all feeds with unread counts, and total counts if `with_totals`.
+    pub async fn get_all_feeds(&self, with_totals: bool) -> anyhow::Result<Vec<db::Feed>> {
         let (tx, rx) = oneshot::channel();
-        self.tx.send(DbCommand::GetAllFeeds { respond_to: tx })
+        self.tx.send(DbCommand::GetAllFeeds { with_totals, respond_to: tx })
             .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
         rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
     }
@@ -232,10 +238,62 @@ impl AsyncDb {
         rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
     }
 
-    /// Upsert articles (insert new ones, ignore existing by guid).
-    pub async fn upsert_articles(&self, articles: Vec<db::Article>) -> anyhow::Result<usize> {
+    /// Get all starred articles.
+    pub async fn get_starred_articles(&self) -> anyhow::Result<Vec<db::Article>> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(DbCommand::GetStarredArticles { respond_to: tx })
+            .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
+    }
+
+    /// Get all articles in the "read later" queue.
+    pub async fn get_queued_articles(&self) -> anyhow::Result<Vec<db::Article>> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(DbCommand::GetQueuedArticles { respond_to: tx })
+            .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
+    }
+
+    /// Get articles published since a point in time.
+    pub async fn get_recent_articles(&self, since: chrono::DateTime<chrono::Utc>) -> anyhow::Result<Vec<db::Article>> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(DbCommand::GetRecentArticles { since, respond_to: tx })
+            .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
+    }
+
+    /// Count starred articles.
+    pub async fn count_starred(&self) -> anyhow::Result<u32> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(DbCommand::CountStarred { respond_to: tx })
+            .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
+    }
+
+    /// Count articles in the "read later" queue.
+    pub async fn count_queued(&self) -> anyhow::Result<u32> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(DbCommand::CountQueued { respond_to: tx })
+            .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
+    }
+
+    /// Count the unread articles of one feed.
+    pub async fn unread_count_for_feed(&self, feed_id: i64) -> anyhow::Result<u32> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(DbCommand::UnreadCountForFeed { feed_id, respond_to: tx })
+            .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
+    }
+
+    /// Upsert articles (insert new ones, update changed ones by guid).
+    pub async fn upsert_articles(
+        &self,
+        articles: Vec<db::Article>,
+        unread_on_update: bool,
+    ) -> anyhow::Result<db::UpsertCounts> {
         let (tx, rx) = oneshot::channel();
-        self.tx.send(DbCommand::UpsertArticles { articles, respond_to: tx })
+        self.tx.send(DbCommand::UpsertArticles { articles, unread_on_update, respond_to: tx })
             .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
         rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
     }
@@ -248,10 +306,37 @@ impl AsyncDb {
         rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
     }
 
-    /// Toggle the starred status of an article.
-    pub async fn toggle_star(&self, article_id: i64) -> anyhow::Result<bool> {
+    /// Set the read status of an article, and of every article sharing its
+    /// normalized URL when `same_url` is set. Returns the feed ID of each
+    /// article that changed.
+    pub async fn set_read(&self, article_id: i64, value: bool, same_url: bool) -> anyhow::Result<Vec<i64>> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(DbCommand::SetRead { article_id, value, same_url, respond_to: tx })
+            .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
+    }
+
+    /// Set the starred status of an article; see [`AsyncDb::set_read`].
+    pub async fn set_starred(&self, article_id: i64, value: bool, same_url: bool) -> anyhow::Result<Vec<i64>> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(DbCommand::SetStarred { article_id, value, same_url, respond_to: tx })
+            .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
+    }
+
+    /// Add an article to or remove it from the "read later" queue; see
+    /// [`AsyncDb::set_read`].
+    pub async fn set_queued(&self, article_id: i64, value: bool, same_url: bool) -> anyhow::Result<Vec<i64>> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(DbCommand::SetQueued { article_id, value, same_url, respond_to: tx })
+            .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
+    }
+
+    /// Unread articles that share a normalized URL, as `(feed_id, url)` pairs.
+    pub async fn unread_url_duplicates(&self) -> anyhow::Result<Vec<(i64, String)>> {
         let (tx, rx) = oneshot::channel();
-        self.tx.send(DbCommand::ToggleStar { article_id, respond_to: tx })
+        self.tx.send(DbCommand::UnreadUrlDuplicates { respond_to: tx })
             .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
         rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
     }
@@ -272,6 +357,24 @@ impl AsyncDb {
         rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
     }
 
+    /// Mark all articles in a group's feeds (and nested groups if
+    /// `recursive`) as read.
+    pub async fn mark_all_read_for_group(&self, group_title: String, recursive: bool) -> anyhow::Result<()> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(DbCommand::MarkAllReadForGroup { group_title, recursive, respond_to: tx })
+            .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
+    }
+
+    /// Mark the given articles as read, with their same-URL copies when
+    /// `same_url` is set.
+    pub async fn mark_read_by_ids(&self, ids: Vec<i64>, same_url: bool) -> anyhow::Result<usize> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(DbCommand::MarkReadByIds { ids, same_url, respond_to: tx })
+            .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
+    }
+
     /// Update the last_fetched timestamp for a feed.
     pub async fn update_last_fetched(&self, feed_id: i64) -> anyhow::Result<()> {
         let (tx, rx) = oneshot::channel();
@@ -280,9 +383,28 @@ impl AsyncDb {
         rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
     }
 
-    /// Get the number of in-flight database operations.
-    pub async fn in_flight_count(&self) -> usize {
-        *self.in_flight.lock().await
+    /// Record the error from a feed's latest fetch (`None` clears it).
+    pub async fn set_last_error(&self, feed_id: i64, error: Option<String>) -> anyhow::Result<()> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(DbCommand::SetLastError { feed_id, error, respond_to: tx })
+            .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
+    }
+
+    /// Store the title a feed gives itself (shown with `use_feed_title`).
+    pub async fn set_feed_title(&self, feed_id: i64, title: String) -> anyhow::Result<()> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(DbCommand::SetFeedTitle { feed_id, title, respond_to: tx })
+            .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
+    }
+
+    /// Store the full text fetched from an article's web page.
+    pub async fn update_article_content(&self, article_id: i64, content: String) -> anyhow::Result<()> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(DbCommand::UpdateArticleContent { article_id, content, respond_to: tx })
+            .map_err(|_| anyhow::anyhow!("Database channel closed"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
     }
 
     /// Sync feeds from config (add new feeds, update existing, delete removed).
@@ -296,3 +418,113 @@ impl AsyncDb {
         rx.await.map_err(|_| anyhow::anyhow!("Response channel closed"))?
     }
 }
+
+/// Run one command on the worker's connection and send back its result.
+fn run_command(conn: &rusqlite::Connection, cmd: DbCommand) {
+    match cmd {
+        DbCommand::GetAllFeeds { with_totals, respond_to } => {
+            let result = if with_totals {
+                db::get_all_feeds_with_totals(conn)
+            } else {
+                db::get_all_feeds(conn)
+            };
+            let _ = respond_to.send(result);
+        }
+        DbCommand::GetArticlesForFeed { feed_id, respond_to } => {
+            let result = db::get_articles_for_feed(conn, feed_id);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::GetArticlesForGroup { group_title, respond_to } => {
+            let result = db::get_articles_for_group(conn, &group_title);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::GetAllArticles { respond_to } => {
+            let result = db::get_all_articles(conn);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::UpsertArticles { articles, unread_on_update, respond_to } => {
+            let result = db::upsert_articles(conn, &articles, unread_on_update);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::ToggleRead { article_id, respond_to } => {
+            let result = db::toggle_read(conn, article_id);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::SetRead { article_id, value, same_url, respond_to } => {
+            let result = db::set_read(conn, article_id, value, same_url);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::SetStarred { article_id, value, same_url, respond_to } => {
+            let result = db::set_starred(conn, article_id, value, same_url);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::SetQueued { article_id, value, same_url, respond_to } => {
+            let result = db::set_queued(conn, article_id, value, same_url);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::UnreadUrlDuplicates { respond_to } => {
+            let result = db::unread_url_duplicates(conn);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::GetStarredArticles { respond_to } => {
+            let result = db::get_starred_articles(conn);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::GetQueuedArticles { respond_to } => {
+            let result = db::get_queued_articles(conn);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::GetRecentArticles { since, respond_to } => {
+            let result = db::get_recent_articles(conn, since);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::CountStarred { respond_to } => {
+            let result = db::count_starred(conn);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::CountQueued { respond_to } => {
+            let result = db::count_queued(conn);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::UnreadCountForFeed { feed_id, respond_to } => {
+            let result = db::unread_count_for_feed(conn, feed_id);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::MarkAllRead { feed_id, respond_to } => {
+            let result = db::mark_all_read(conn, feed_id);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::MarkAllReadAll { respond_to } => {
+            let result = db::mark_all_read_all(conn);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::MarkAllReadForGroup { group_title, recursive, respond_to } => {
+            let result = db::mark_all_read_for_group(conn, &group_title, recursive);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::MarkReadByIds { ids, same_url, respond_to } => {
+            let result = db::mark_read_by_ids(conn, &ids, same_url);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::UpdateLastFetched { feed_id, respond_to } => {
+            let result = db::update_last_fetched(conn, feed_id);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::SetLastError { feed_id, error, respond_to } => {
+            let result = db::set_last_error(conn, feed_id, error.as_deref());
+            let _ = respond_to.send(result);
+        }
+        DbCommand::SetFeedTitle { feed_id, title, respond_to } => {
+            let result = db::set_feed_title(conn, feed_id, &title);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::UpdateArticleContent { article_id, content, respond_to } => {
+            let result = db::update_article_content(conn, article_id, &content);
+            let _ = respond_to.send(result);
+        }
+        DbCommand::SyncFeedsFromConfig { config, respond_to } => {
+            let result = db::sync_feeds_from_config(conn, &config);
+            let _ = respond_to.send(result);
+        }
+    }
+}
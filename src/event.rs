@@ -102,4 +102,9 @@ impl EventHandler {
             .await
             .ok_or_else(|| anyhow::anyhow!("Event channel closed"))
     }
+
+    /// Return the next event if one is already queued, without waiting.
+    pub fn try_next(&mut self) -> Option<Event> {
+        self.rx.try_recv().ok()
+    }
 }
@@ -1,8 +1,15 @@
-use std::time::Duration;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher, RandomState};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
 
 use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::Semaphore;
 
 use crate::db::{Article, Feed};
+use crate::html::{find_tag, parse_tag_attributes};
 
 /// The result of fetching and parsing a single feed.
 ///
@@ -13,34 +20,88 @@ pub struct FeedUpdateResult {
     pub feed_id: i64,
     /// Newly parsed articles (not yet de-duplicated against the database).
     pub articles: Vec<Article>,
+    /// The title the feed document gives itself, if any.
+    pub feed_title: Option<String>,
     /// If the fetch or parse failed, the error description.
     pub error: Option<String>,
+    /// A problem that was worked around, e.g. malformed XML that parsed
+    /// once repaired.
+    pub warning: Option<String>,
+}
+
+/// Outcome of probing a URL for a feed with [`discover`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Discovery {
+    /// The URL itself serves a parseable feed.
+    Feed,
+    /// The URL is a page advertising the given feed URL.
+    Found(String),
+    /// The URL is a page with no advertised feed.
+    NotFound,
+}
+
+/// Used when [`set_max_concurrent_fetches`] was never called.
+const DEFAULT_MAX_CONCURRENT_FETCHES: usize = 16;
+
+/// Permits shared by every feed fetch, capping how many run at once.
+static FETCH_PERMITS: OnceLock<Arc<Semaphore>> = OnceLock::new();
+
+/// Set how many feeds may be fetched at the same time (at least 1).
+///
+/// Must be called before the first fetch; later calls have no effect.
+pub fn set_max_concurrent_fetches(max: usize) {
+    let _ = FETCH_PERMITS.set(Arc::new(Semaphore::new(max.max(1))));
+}
+
+fn fetch_permits() -> Arc<Semaphore> {
+    FETCH_PERMITS
+        .get_or_init(|| Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENT_FETCHES)))
+        .clone()
 }
 
 /// Spawn background tasks to refresh every feed in the provided slice.
 ///
-/// Each feed is fetched concurrently in its own Tokio task.  Results are
-/// sent back through `tx` as they complete.
-pub fn refresh_all(tx: &UnboundedSender<FeedUpdateResult>, feeds: &[Feed]) {
+/// Each feed gets its own Tokio task, but only `max_concurrent_fetches` of
+/// them fetch at once; the rest wait their turn. With a non-zero `jitter`
+/// each fetch first waits a random delay of up to that long. Results are
+/// sent back through `tx` as they complete, exactly one per feed.
+pub fn refresh_all(tx: &UnboundedSender<FeedUpdateResult>, feeds: &[Feed], jitter: Duration) {
     let client = build_client();
 
     for feed in feeds {
-        let tx = tx.clone();
-        let client = client.clone();
-        let feed = feed.clone();
-        tokio::spawn(async move {
-            let result = fetch_feed(&client, &feed).await;
-            let _ = tx.send(result);
-        });
+        spawn_fetch(tx, &client, feed, jitter_delay(jitter));
     }
 }
 
 /// Spawn a background task to refresh a single feed.
 pub fn refresh_one(tx: &UnboundedSender<FeedUpdateResult>, feed: &Feed) {
+    spawn_fetch(tx, &build_client(), feed, Duration::ZERO);
+}
+
+/// Random delay between zero and `max`, at millisecond resolution.
+fn jitter_delay(max: Duration) -> Duration {
+    if max.is_zero() {
+        return Duration::ZERO;
+    }
+    // Every RandomState is seeded differently, which is random enough here
+    let random = RandomState::new().build_hasher().finish();
+    let max_millis = u64::try_from(max.as_millis()).unwrap_or(u64::MAX);
+    Duration::from_millis(random % max_millis.saturating_add(1))
+}
+
+/// Spawn a task that fetches `feed` after `delay`, once a fetch permit is
+/// free.
+fn spawn_fetch(tx: &UnboundedSender<FeedUpdateResult>, client: &reqwest::Client, feed: &Feed, delay: Duration) {
     let tx = tx.clone();
+    let client = client.clone();
     let feed = feed.clone();
+    let permits = fetch_permits();
     tokio::spawn(async move {
-        let client = build_client();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        // The semaphore is never closed, so this only waits
+        let _permit = permits.acquire_owned().await;
         let result = fetch_feed(&client, &feed).await;
         let _ = tx.send(result);
     });
@@ -57,39 +118,231 @@ fn build_client() -> reqwest::Client {
         .expect("Failed to create HTTP client")
 }
 
+/// Fetch `url` and work out where its feed lives.
+///
+/// Used when adding a feed by its website URL: if the page is not a feed
+/// itself, its `<link rel="alternate">` tags are searched instead.
+pub async fn discover(url: &str) -> Result<Discovery, String> {
+    let client = build_client();
+    let response = client.get(url).send().await.map_err(|e| e.to_string())?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("HTTP {}", status.as_u16()));
+    }
+
+    let final_url = response.url().clone();
+    let bytes = response.bytes().await.map_err(|e| e.to_string())?;
+
+    if feed_rs::parser::parse(&bytes[..]).is_ok() {
+        return Ok(Discovery::Feed);
+    }
+
+    let text = String::from_utf8_lossy(&bytes);
+    Ok(match discover_feed_url(&text, &final_url) {
+        Some(feed_url) => Discovery::Found(feed_url),
+        None => Discovery::NotFound,
+    })
+}
+
+/// Largest image downloaded for inline display.
+const MAX_IMAGE_BYTES: usize = 5 * 1024 * 1024;
+
+/// Absolute URL of an article image, resolving a relative `src` against
+/// `base` (the article URL).
+pub fn resolve_image_url(src: &str, base: Option<&str>) -> Option<reqwest::Url> {
+    match reqwest::Url::parse(src) {
+        Ok(url) => Some(url),
+        Err(_) => base
+            .and_then(|base| reqwest::Url::parse(base).ok())
+            .and_then(|base| base.join(src).ok()),
+    }
+}
+
+/// Download an article image for inline display.
+///
+/// Relative `src` values are resolved against `base` (the article URL).
+pub async fn fetch_image(src: &str, base: Option<&str>) -> Result<Vec<u8>, String> {
+    let url = resolve_image_url(src, base).ok_or_else(|| format!("Invalid image URL: {}", src))?;
+
+    let response = build_client().get(url).send().await.map_err(|e| e.to_string())?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("HTTP {}", status.as_u16()));
+    }
+    if response.content_length().is_some_and(|len| len > MAX_IMAGE_BYTES as u64) {
+        return Err("Image too large".to_string());
+    }
+
+    let bytes = response.bytes().await.map_err(|e| e.to_string())?;
+    if bytes.len() > MAX_IMAGE_BYTES {
+        return Err("Image too large".to_string());
+    }
+    Ok(bytes.to_vec())
+}
+
+/// Largest article page downloaded for full-text extraction.
+const MAX_PAGE_BYTES: usize = 5 * 1024 * 1024;
+
+/// Download an article's web page and extract its main text as HTML (see
+/// [`extract_article`]).
+pub async fn fetch_full_content(url: &str) -> Result<String, String> {
+    let response = build_client().get(url).send().await.map_err(|e| e.to_string())?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("HTTP {}", status.as_u16()));
+    }
+    if response.content_length().is_some_and(|len| len > MAX_PAGE_BYTES as u64) {
+        return Err("Page too large".to_string());
+    }
+
+    let content_type = response
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let bytes = response.bytes().await.map_err(|e| e.to_string())?;
+    if bytes.len() > MAX_PAGE_BYTES {
+        return Err("Page too large".to_string());
+    }
+
+    // Scanning a page of up to MAX_PAGE_BYTES is CPU-bound, so keep it off
+    // the async workers that also drive the UI
+    tokio::task::spawn_blocking(move || {
+        let encoding = charset_param(&content_type)
+            .and_then(|label| encoding_rs::Encoding::for_label(label.as_bytes()))
+            .unwrap_or(encoding_rs::UTF_8);
+        let (html, _, _) = encoding.decode(&bytes);
+        extract_article(&html).ok_or_else(|| "No article text found".to_string())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// How long to leave a host alone after a 429 without a usable `Retry-After`.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Longest `Retry-After` honoured; servers can send absurd values.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(24 * 3600);
+
+/// Per-host "don't fetch before" times, set by `429 Too Many Requests`.
+static RATE_LIMITED_UNTIL: LazyLock<Mutex<HashMap<String, Instant>>> = LazyLock::new(Default::default);
+
+/// Parse a `Retry-After` header value: either a number of seconds or an
+/// HTTP date. Dates in the past mean no wait; waits are capped at
+/// [`MAX_RETRY_AFTER`].
+fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    let wait = match value.parse::<u64>() {
+        Ok(secs) => Duration::from_secs(secs),
+        Err(_) => {
+            let date = DateTime::parse_from_rfc2822(value).ok()?;
+            (date.with_timezone(&Utc) - now).to_std().unwrap_or_default()
+        }
+    };
+    Some(wait.min(MAX_RETRY_AFTER))
+}
+
+/// Remember not to fetch from `host` for `wait` (at most [`MAX_RETRY_AFTER`]).
+fn set_rate_limited(host: &str, wait: Duration) {
+    let now = Instant::now();
+    let wait = wait.min(MAX_RETRY_AFTER);
+    let deadline = now.checked_add(wait).unwrap_or(now + DEFAULT_RETRY_AFTER);
+    let mut until = RATE_LIMITED_UNTIL.lock().unwrap_or_else(|e| e.into_inner());
+    until.insert(host.to_string(), deadline);
+}
+
+/// Time left before `host` may be fetched again, if it is rate-limited.
+fn rate_limit_remaining(host: &str) -> Option<Duration> {
+    let mut until = RATE_LIMITED_UNTIL.lock().unwrap_or_else(|e| e.into_inner());
+    let remaining = until.get(host)?.checked_duration_since(Instant::now()).filter(|d| !d.is_zero());
+    if remaining.is_none() {
+        until.remove(host);
+    }
+    remaining
+}
+
+/// Error reported for a feed whose host is rate-limiting us.
+fn rate_limit_error(wait: Duration) -> String {
+    let secs = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+    format!("rate-limited, retrying in {}s", secs)
+}
+
 /// Fetch and parse a single feed, returning a `FeedUpdateResult`.
 ///
 /// Errors are captured into the result rather than propagated so that a
 /// single misbehaving feed cannot take down the entire refresh cycle.
 async fn fetch_feed(client: &reqwest::Client, feed: &Feed) -> FeedUpdateResult {
-    match fetch_feed_inner(client, feed).await {
-        Ok(articles) => FeedUpdateResult {
-            feed_id: feed.id,
-            articles,
-            error: None,
-        },
-        Err(e) => FeedUpdateResult {
-            feed_id: feed.id,
-            articles: Vec::new(),
-            error: Some(e.to_string()),
-        },
+    let started = std::time::Instant::now();
+    let result = fetch_feed_inner(client, feed).await;
+    let elapsed_ms = started.elapsed().as_millis() as u64;
+
+    match result {
+        Ok((parsed, warning)) => {
+            tracing::info!(url = %feed.url, elapsed_ms, articles = parsed.articles.len(), "fetched feed");
+            if let Some(warning) = &warning {
+                tracing::warn!(url = %feed.url, "{warning}");
+            }
+            FeedUpdateResult {
+                feed_id: feed.id,
+                articles: parsed.articles,
+                feed_title: parsed.title,
+                error: None,
+                warning,
+            }
+        }
+        Err(e) => {
+            tracing::error!(url = %feed.url, elapsed_ms, "fetch failed: {e}");
+            FeedUpdateResult {
+                feed_id: feed.id,
+                articles: Vec::new(),
+                feed_title: None,
+                error: Some(e.to_string()),
+                warning: None,
+            }
+        }
     }
 }
 
 /// Inner implementation that can use `?` for ergonomic error handling.
+///
+/// Returns the parsed feed and an optional warning.
 async fn fetch_feed_inner(
     client: &reqwest::Client,
     feed: &Feed,
-) -> Result<Vec<Article>, Box<dyn std::error::Error + Send + Sync>> {
+) -> Result<(ParsedFeed, Option<String>), Box<dyn std::error::Error + Send + Sync>> {
     let url = &feed.url;
+
+    // Leave hosts that asked us to back off alone until they're ready
+    let host = reqwest::Url::parse(url).ok().and_then(|u| u.host_str().map(str::to_string));
+    if let Some(wait) = host.as_deref().and_then(rate_limit_remaining) {
+        return Err(rate_limit_error(wait).into());
+    }
+
     let response = client
         .get(url)
-        .header("Accept", "application/rss+xml, application/rdf+xml, application/atom+xml, application/xml, text/xml, */*")
+        .header("Accept", "application/rss+xml, application/rdf+xml, application/atom+xml, application/feed+json, application/xml, text/xml, */*")
         .send()
         .await?;
 
     // Check for HTTP errors
     let status = response.status();
+    tracing::info!(%url, status = status.as_u16(), final_url = %response.url(), "response");
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        let wait = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| parse_retry_after(v, Utc::now()))
+            .unwrap_or(DEFAULT_RETRY_AFTER);
+        if let Some(host) = &host {
+            set_rate_limited(host, wait);
+        }
+        return Err(rate_limit_error(wait).into());
+    }
     if !status.is_success() {
         return Err(format!("HTTP {}", status.as_u16()).into());
     }
@@ -118,27 +371,50 @@ async fn fetch_feed_inner(
         bytes = bytes.slice(3..);
     }
 
-    // Check if the response looks like HTML (error page) instead of a feed
+    // Decode legacy charsets (e.g. Windows-1252) up front so titles don't
+    // turn into mojibake
+    let bytes = decode_to_utf8(&bytes, &content_type);
+
+    // JSON Feed (jsonfeed.org): its `content_html` may contain markup, so
+    // don't let it trip the HTML check below.
     let text = String::from_utf8_lossy(&bytes);
+    let is_json = is_json_feed(&content_type, &text);
+
+    // Check if the response looks like HTML (error page) instead of a feed
     let text_start = text.chars().take(200).collect::<String>();
-    let is_html = text_start.contains("<html>") || text_start.contains("<HTML>") ||
-                  text_start.contains("<!DOCTYPE html>") || text_start.contains("<!DOCTYPE HTML>");
+    let is_html = !is_json && (text_start.contains("<html>") || text_start.contains("<HTML>") ||
+                  text_start.contains("<!DOCTYPE html>") || text_start.contains("<!DOCTYPE HTML>"));
 
     if is_html {
+        if let Some(discovered) = discover_feed_url(&text, &final_url) {
+            return Err(format!("Not a feed \u{2014} did you mean {}?", discovered).into());
+        }
         return Err(format!(
             "Server returned HTML instead of feed (type: {}, URL: {})",
             content_type, final_url
         ).into());
     }
 
-    // Try to parse with feed-rs
-    let parsed = match feed_rs::parser::parse(&bytes[..]) {
-        Ok(p) => p,
+    // JSON Feed is parsed as-is; XML gets a second, lenient attempt
+    if !is_json {
+        return parse_xml_feed(&bytes, feed.id).map_err(|e| {
+            // Pages that don't start with a recognised doctype still may
+            // advertise their feed.
+            match discover_feed_url(&text, &final_url) {
+                Some(discovered) => format!("Not a feed \u{2014} did you mean {}?", discovered).into(),
+                None => e.into(),
+            }
+        });
+    }
+
+    // Try to parse with feed-rs (handles RSS, Atom and JSON Feed)
+    let parsed = match parse_feed(&bytes, feed.id) {
+        Ok(parsed) => parsed,
         Err(e) => {
             // On parse error, try to provide useful debug info
             let preview = text.chars().take(100).collect::<String>();
             return Err(format!(
-                "Parse error (type: {}, {} bytes, URL: {}, starts: \"{}...\"): {}",
+                "JSON Feed parse error (type: {}, {} bytes, URL: {}, starts: \"{}...\"): {}",
                 content_type,
                 bytes.len(),
                 final_url,
@@ -148,6 +424,200 @@ async fn fetch_feed_inner(
         }
     };
 
+    Ok((parsed, None))
+}
+
+/// Re-encode a feed document as UTF-8.
+///
+/// The charset comes from the `Content-Type` header, or else the XML
+/// declaration's `encoding`; without either the document is assumed to be
+/// UTF-8 already. The declaration is rewritten to match, since the feed
+/// parser would otherwise decode the converted bytes a second time.
+fn decode_to_utf8(bytes: &[u8], content_type: &str) -> Vec<u8> {
+    let declared = declared_encoding_range(bytes);
+    let label = charset_param(content_type)
+        .map(str::as_bytes)
+        .or_else(|| declared.clone().map(|range| &bytes[range]));
+    let Some(encoding) = label.and_then(encoding_rs::Encoding::for_label) else {
+        return bytes.to_vec();
+    };
+
+    let (text, _, _) = encoding.decode(bytes);
+    let mut decoded = text.into_owned().into_bytes();
+    // The declaration is ASCII, so it sits at the same place after decoding
+    // from any ASCII-compatible charset
+    if let Some(range) = declared_encoding_range(&decoded) {
+        decoded.splice(range, b"UTF-8".iter().copied());
+    }
+    decoded
+}
+
+/// The `charset` parameter of a `Content-Type` header value.
+fn charset_param(content_type: &str) -> Option<&str> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (key, value) = param.split_once('=')?;
+        key.trim().eq_ignore_ascii_case("charset").then(|| value.trim().trim_matches('"'))
+    })
+}
+
+/// Byte range of the `encoding` value in the document's XML declaration.
+fn declared_encoding_range(bytes: &[u8]) -> Option<std::ops::Range<usize>> {
+    let start = bytes.iter().position(|b| !b.is_ascii_whitespace())?;
+    if !bytes[start..].starts_with(b"<?xml") {
+        return None;
+    }
+    let end = start + bytes[start..].windows(2).position(|w| w == b"?>")?;
+    let declaration = &bytes[..end];
+
+    let attr = start + declaration[start..].windows(8).position(|w| w == b"encoding")? + 8;
+    let skip_space = |from: usize| (from..end).find(|&i| !declaration[i].is_ascii_whitespace());
+    let eq = skip_space(attr).filter(|&i| declaration[i] == b'=')?;
+    let open = skip_space(eq + 1).filter(|&i| matches!(declaration[i], b'"' | b'\''))?;
+    let close = (open + 1..end).find(|&i| declaration[i] == declaration[open])?;
+    Some(open + 1..close)
+}
+
+/// Longest parse error message reported for a feed.
+const MAX_PARSE_ERROR_CHARS: usize = 200;
+
+/// Parse an XML feed, retrying once with common defects repaired (see
+/// [`repair_xml`]).
+///
+/// On success, also returns a warning if the repair was needed. On failure,
+/// the error names the problem and its approximate byte offset.
+fn parse_xml_feed(bytes: &[u8], feed_id: i64) -> Result<(ParsedFeed, Option<String>), String> {
+    let err = match parse_feed(bytes, feed_id) {
+        Ok(parsed) => return Ok((parsed, None)),
+        Err(err) => err,
+    };
+
+    let problem = match locate_xml_error(bytes) {
+        Some((offset, what)) => format!("XML parse error near byte {}: {}", offset, what),
+        None => format!("XML parse error: {}", err),
+    };
+    let problem: String = problem.chars().take(MAX_PARSE_ERROR_CHARS).collect();
+
+    let repaired = repair_xml(bytes);
+    if repaired == bytes {
+        return Err(problem);
+    }
+    match parse_feed(&repaired, feed_id) {
+        Ok(parsed) => Ok((parsed, Some(format!("Repaired malformed XML ({})", problem)))),
+        Err(_) => Err(format!("{} (lenient re-parse also failed)", problem)),
+    }
+}
+
+/// Whether `b` is a control character XML doesn't allow. All of them are
+/// ASCII, so they never occur inside multi-byte UTF-8 sequences.
+fn is_invalid_xml_byte(b: u8) -> bool {
+    b < 0x20 && !matches!(b, b'\t' | b'\n' | b'\r')
+}
+
+/// Fix the defects real-world feeds most often have: leading whitespace or
+/// a byte order mark before the XML declaration, and stray control
+/// characters.
+fn repair_xml(bytes: &[u8]) -> Vec<u8> {
+    let start = bytes.trim_ascii_start();
+    let start = start.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(start).trim_ascii_start();
+    start.iter().copied().filter(|&b| !is_invalid_xml_byte(b)).collect()
+}
+
+/// Find the first well-formedness problem in an XML document, returning its
+/// byte offset and a short description.
+///
+/// This is a rough scan (it only checks tag nesting and characters), used to
+/// explain a parse failure; the feed parser gives no position.
+fn locate_xml_error(data: &[u8]) -> Option<(usize, String)> {
+    let find = |from: usize, needle: &[u8]| {
+        data[from..].windows(needle.len()).position(|w| w == needle).map(|p| from + p)
+    };
+    let name = |bytes: &[u8]| String::from_utf8_lossy(bytes).into_owned();
+
+    let mut open_tags: Vec<&[u8]> = Vec::new();
+    let mut i = 0;
+    while i < data.len() {
+        if is_invalid_xml_byte(data[i]) {
+            return Some((i, format!("invalid control character 0x{:02x}", data[i])));
+        }
+        if data[i] != b'<' {
+            i += 1;
+            continue;
+        }
+
+        // Comments, CDATA, processing instructions and doctypes: skip over
+        let rest = &data[i..];
+        let terminator: &[u8] = if rest.starts_with(b"<!--") {
+            b"-->"
+        } else if rest.starts_with(b"<![CDATA[") {
+            b"]]>"
+        } else if rest.starts_with(b"<?") {
+            b"?>"
+        } else if rest.starts_with(b"<!") {
+            b">"
+        } else {
+            b""
+        };
+        if !terminator.is_empty() {
+            match find(i + 2, terminator) {
+                Some(end) => i = end + terminator.len(),
+                None => return Some((i, "unterminated markup".to_string())),
+            }
+            continue;
+        }
+
+        let Some(end) = find(i, b">") else {
+            return Some((i, "unterminated tag".to_string()));
+        };
+        let tag = &data[i + 1..end];
+        if let Some(pos) = tag.iter().position(|&b| is_invalid_xml_byte(b)) {
+            let offset = i + 1 + pos;
+            return Some((offset, format!("invalid control character 0x{:02x}", data[offset])));
+        }
+        if let Some(closing) = tag.strip_prefix(b"/") {
+            let closing = closing.trim_ascii();
+            match open_tags.pop() {
+                Some(open) if open == closing => {}
+                Some(open) => {
+                    return Some((i, format!("mismatched tag </{}> (expected </{}>)", name(closing), name(open))));
+                }
+                None => return Some((i, format!("unexpected closing tag </{}>", name(closing)))),
+            }
+        } else if !tag.ends_with(b"/") {
+            let name_end = tag.iter().position(|b| b.is_ascii_whitespace()).unwrap_or(tag.len());
+            open_tags.push(&tag[..name_end]);
+        }
+        i = end + 1;
+    }
+
+    open_tags.last().map(|open| (data.len(), format!("unclosed tag <{}>", name(open))))
+}
+
+/// Whether a response is a JSON Feed, judged by content type or by a leading
+/// `{` followed by a jsonfeed.org `version` near the start.
+fn is_json_feed(content_type: &str, text: &str) -> bool {
+    if content_type.contains("application/feed+json") {
+        return true;
+    }
+    let start: String = text.trim_start().chars().take(200).collect();
+    start.starts_with('{') && start.contains("jsonfeed.org")
+}
+
+/// A feed document's own title and its articles.
+#[derive(Debug)]
+struct ParsedFeed {
+    title: Option<String>,
+    articles: Vec<Article>,
+}
+
+/// Parse a feed document (RSS, Atom or JSON Feed) into articles for `feed_id`.
+///
+/// Entries without a guid are skipped since they can't be de-duplicated.
+fn parse_feed(bytes: &[u8], feed_id: i64) -> Result<ParsedFeed, feed_rs::parser::ParseFeedError> {
+    let parsed = feed_rs::parser::parse(bytes)?;
+    let title = parsed.title
+        .map(|t| t.content.trim().to_string())
+        .filter(|t| !t.is_empty());
+
     let articles: Vec<Article> = parsed
         .entries
         .into_iter()
@@ -166,17 +636,27 @@ async fn fetch_feed_inner(
 
             let url = entry.links.first().map(|l| l.href.clone());
 
-            let author = entry.authors.first().map(|a| a.name.clone());
+            let enclosure_url = enclosure_url(&entry.media, &entry.links);
 
-            let summary = entry.summary.map(|s| s.content);
+            let author = entry.authors.first().map(|a| a.name.clone());
 
             let content = entry.content.and_then(|c| c.body);
 
+            // Feeds that only send full content get an excerpt to preview
+            let summary = entry.summary
+                .map(|s| s.content)
+                .filter(|s| !s.trim().is_empty())
+                .or_else(|| {
+                    let content = content.as_deref()?;
+                    Some(crate::render::excerpt(content, crate::render::EXCERPT_CHARS))
+                        .filter(|s| !s.is_empty())
+                });
+
             let published = entry.published.or(entry.updated);
 
             Some(Article {
                 id: 0,
-                feed_id: feed.id,
+                feed_id,
                 guid,
                 title,
                 url,
@@ -186,9 +666,569 @@ async fn fetch_feed_inner(
                 published,
                 is_read: false,
                 is_starred: false,
+                is_queued: false,
+                enclosure_url,
+                has_full_content: false,
             })
         })
         .collect();
 
-    Ok(articles)
+    Ok(ParsedFeed { title, articles })
+}
+
+/// Pick the URL of an entry's enclosure (RSS `<enclosure>`, Media RSS content
+/// or Atom `rel="enclosure"` links), preferring `audio/*` when there are several.
+fn enclosure_url(media: &[feed_rs::model::MediaObject], links: &[feed_rs::model::Link]) -> Option<String> {
+    let media = media.iter().flat_map(|m| &m.content).filter_map(|c| {
+        let url = c.url.as_ref()?.to_string();
+        let mime = c.content_type.as_ref().map(|t| t.to_string());
+        Some((url, mime))
+    });
+    let links = links
+        .iter()
+        .filter(|l| l.rel.as_deref() == Some("enclosure"))
+        .map(|l| (l.href.clone(), l.media_type.clone()));
+
+    let candidates: Vec<(String, Option<String>)> = media.chain(links).collect();
+
+    candidates
+        .iter()
+        .find(|(_, mime)| mime.as_deref().is_some_and(|m| m.starts_with("audio/")))
+        .or_else(|| candidates.first())
+        .map(|(url, _)| url.clone())
+}
+
+/// Query keys added by ad and newsletter platforms to track clicks.
+/// Any key starting with `utm_` is also removed.
+const TRACKING_PARAMS: &[&str] = &[
+    "fbclid", "gclid", "dclid", "gbraid", "wbraid", "msclkid", "yclid", "twclid",
+    "igshid", "mc_cid", "mc_eid", "_hsenc", "_hsmi", "mkt_tok",
+];
+
+/// Whether a query key is a known tracking parameter.
+fn is_tracking_param(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    key.starts_with("utm_") || TRACKING_PARAMS.contains(&key.as_str())
+}
+
+/// Remove tracking parameters (`utm_*`, `fbclid`, `gclid`, ...) from a URL's
+/// query string, leaving the rest of the URL untouched.
+pub fn strip_tracking_params(url: &str) -> String {
+    let (rest, fragment) = match url.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (url, None),
+    };
+    let Some((base, query)) = rest.split_once('?') else {
+        return url.to_string();
+    };
+
+    let kept: Vec<&str> = query
+        .split('&')
+        .filter(|pair| {
+            let key = pair.split_once('=').map_or(*pair, |(key, _)| key);
+            !is_tracking_param(key)
+        })
+        .collect();
+    if kept.len() == query.split('&').count() {
+        return url.to_string();
+    }
+
+    let mut cleaned = base.to_string();
+    if !kept.is_empty() {
+        cleaned.push('?');
+        cleaned.push_str(&kept.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        cleaned.push('#');
+        cleaned.push_str(fragment);
+    }
+    cleaned
+}
+
+/// Strip tracking parameters from the link and enclosure URL of each article.
+pub fn strip_article_tracking_params(articles: &mut [Article]) {
+    for article in articles {
+        if let Some(url) = &article.url {
+            article.url = Some(strip_tracking_params(url));
+        }
+        if let Some(url) = &article.enclosure_url {
+            article.enclosure_url = Some(strip_tracking_params(url));
+        }
+    }
+}
+
+/// Look for a feed advertised by an HTML page.
+///
+/// Scans `<link rel="alternate" type="application/rss+xml|atom+xml" href="...">`
+/// tags and returns the first matching `href`, resolved against `base`.
+pub fn discover_feed_url(html: &str, base: &reqwest::Url) -> Option<String> {
+    // ASCII lowercasing keeps byte offsets identical to `html`.
+    let lower = html.to_ascii_lowercase();
+    let mut pos = 0;
+
+    while let Some(start) = lower[pos..].find("<link") {
+        let tag_start = pos + start + "<link".len();
+        let tag_end = lower[tag_start..].find('>').map_or(lower.len(), |i| tag_start + i);
+        pos = tag_end;
+
+        let attrs = parse_tag_attributes(&html[tag_start..tag_end]);
+        let attr = |name: &str| {
+            attrs
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+        };
+
+        let is_alternate = attr("rel").is_some_and(|rel| {
+            rel.split_whitespace().any(|r| r.eq_ignore_ascii_case("alternate"))
+        });
+        let is_feed_type = attr("type").is_some_and(|ty| {
+            let ty = ty.trim().to_ascii_lowercase();
+            ty == "application/rss+xml" || ty == "application/atom+xml"
+        });
+
+        let href = attr("href").map(str::trim).filter(|h| !h.is_empty());
+        if let Some(href) = href.filter(|_| is_alternate && is_feed_type) {
+            return base.join(href).ok().map(|u| u.to_string());
+        }
+    }
+
+    None
+}
+
+/// Elements dropped from a page before looking for its article text.
+const BOILERPLATE_TAGS: &[&str] = &[
+    "script", "style", "noscript", "template", "nav", "header", "footer", "aside", "form", "button", "iframe",
+    "svg",
+];
+
+/// `class`/`id` fragments that mark an element as a page's main content.
+const CONTENT_HINTS: &[&str] = &[
+    "entry-content", "post-content", "article-content", "article-body", "post-body", "story-body", "content",
+];
+
+/// Pages with less paragraph text than this (in characters) are assumed to
+/// be something other than an article, such as a paywall or cookie wall.
+const MIN_ARTICLE_CHARS: usize = 200;
+
+/// Extract the main article from a web page, as an HTML fragment.
+///
+/// Navigation, headers, footers, scripts and the like are dropped first.
+/// The candidates are `<article>` and `<main>` elements and any `<div>` or
+/// `<section>` whose class or id looks like a content container, falling
+/// back to `<body>`. Candidates are scored by the text in their paragraphs;
+/// the smallest one holding at least 80% of the best score wins, so a page
+/// wrapper doesn't beat the article inside it. Returns `None` if no
+/// candidate has [`MIN_ARTICLE_CHARS`] of paragraph text.
+///
+/// This is a small hand-rolled heuristic, not a readability crate, to keep
+/// the dependency tree small for an optional, per-feed feature. It copes
+/// with ordinary blog and news markup; pages that render their text with
+/// scripts or need cleverer scoring come out with less than a browser shows.
+pub fn extract_article(html: &str) -> Option<String> {
+    let html = remove_elements(html, BOILERPLATE_TAGS);
+    // ASCII lowercasing keeps byte offsets identical to `html`.
+    let lower = html.to_ascii_lowercase();
+
+    let mut candidates = Vec::new();
+    for tag in ["article", "main", "div", "section"] {
+        let mut pos = 0;
+        while let Some(start) = find_tag(&lower, &format!("<{tag}"), pos) {
+            let attrs_start = start + 1 + tag.len();
+            let Some(content_start) = lower[attrs_start..].find('>').map(|i| attrs_start + i + 1) else {
+                break;
+            };
+            pos = content_start;
+
+            let is_content = matches!(tag, "article" | "main")
+                || parse_tag_attributes(&html[attrs_start..content_start - 1])
+                    .iter()
+                    .filter(|(k, _)| k.eq_ignore_ascii_case("class") || k.eq_ignore_ascii_case("id"))
+                    .any(|(_, v)| {
+                        let v = v.to_ascii_lowercase();
+                        CONTENT_HINTS.iter().any(|hint| v.contains(hint))
+                    });
+            if is_content {
+                candidates.push(content_start..closing_tag(&lower, tag, content_start));
+            }
+        }
+    }
+    if candidates.is_empty() {
+        let body = find_tag(&lower, "<body", 0)
+            .and_then(|start| lower[start..].find('>').map(|i| start + i + 1))
+            .unwrap_or(0);
+        candidates.push(body..closing_tag(&lower, "body", body));
+    }
+
+    let scored: Vec<(usize, &str)> = candidates
+        .iter()
+        .map(|range| &html[range.clone()])
+        .map(|fragment| (paragraph_text_len(fragment), fragment))
+        .collect();
+    let best = scored.iter().map(|(score, _)| *score).max()?;
+    if best < MIN_ARTICLE_CHARS {
+        return None;
+    }
+    scored
+        .into_iter()
+        .filter(|(score, _)| score * 5 >= best * 4)
+        .map(|(_, fragment)| fragment)
+        .min_by_key(|fragment| fragment.len())
+        .map(|fragment| fragment.trim().to_string())
+}
+
+/// Start of the `</tag>` closing an element whose content begins at `from`,
+/// skipping nested elements of the same name. Unclosed elements run to the
+/// end of the document.
+fn closing_tag(lower: &str, tag: &str, from: usize) -> usize {
+    let (open, close) = (format!("<{tag}"), format!("</{tag}"));
+    let mut depth = 0;
+    let mut pos = from;
+
+    while let Some(close_at) = find_tag(lower, &close, pos) {
+        match find_tag(lower, &open, pos) {
+            Some(open_at) if open_at < close_at => {
+                depth += 1;
+                pos = open_at + open.len();
+            }
+            _ if depth == 0 => return close_at,
+            _ => {
+                depth -= 1;
+                pos = close_at + close.len();
+            }
+        }
+    }
+    lower.len()
+}
+
+/// `html` with every element named in `tags` removed, contents included.
+fn remove_elements(html: &str, tags: &[&str]) -> String {
+    let mut html = html.to_string();
+    for tag in tags {
+        let lower = html.to_ascii_lowercase();
+        let open = format!("<{tag}");
+        let mut kept = String::with_capacity(html.len());
+        let mut pos = 0;
+
+        while let Some(start) = find_tag(&lower, &open, pos) {
+            kept.push_str(&html[pos..start]);
+            let end = closing_tag(&lower, tag, start + open.len());
+            pos = lower[end..].find('>').map_or(lower.len(), |i| end + i + 1);
+        }
+        kept.push_str(&html[pos..]);
+        html = kept;
+    }
+    html
+}
+
+/// Number of non-whitespace text characters inside the `<p>` elements of an
+/// HTML fragment. A paragraph without `</p>` ends at the next `<p>`.
+fn paragraph_text_len(fragment: &str) -> usize {
+    let lower = fragment.to_ascii_lowercase();
+    let mut total = 0;
+    let mut pos = 0;
+
+    while let Some(start) = find_tag(&lower, "<p", pos) {
+        let end = [find_tag(&lower, "</p", start + 2), find_tag(&lower, "<p", start + 2)]
+            .into_iter()
+            .flatten()
+            .min()
+            .unwrap_or(lower.len());
+        let mut in_tag = false;
+        total += fragment[start..end]
+            .chars()
+            .filter(|&c| match c {
+                '<' => {
+                    in_tag = true;
+                    false
+                }
+                '>' => {
+                    in_tag = false;
+                    false
+                }
+                _ => !in_tag && !c.is_whitespace(),
+            })
+            .count();
+        pos = end;
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base() -> reqwest::Url {
+        reqwest::Url::parse("https://example.com/blog/").unwrap()
+    }
+
+    const RSS: &str = r#"<?xml version="1.0"?>
+<rss version="2.0"><channel><title>Example</title>
+<item><guid>post-1</guid><title>Hello</title></item>
+</channel></rss>"#;
+
+    #[test]
+    fn decode_latin1_feed() {
+        let latin1 = |xml: &str| -> Vec<u8> { xml.chars().map(|c| c as u8).collect() };
+        let doc = RSS.replace("Hello", "Caf\u{e9} cr\u{e8}me");
+
+        // Charset from the Content-Type header
+        let bytes = decode_to_utf8(&latin1(&doc), "application/rss+xml; charset=ISO-8859-1");
+        assert_eq!(parse_feed(&bytes, 1).unwrap().articles[0].title, "Café crème");
+
+        // Charset from the XML declaration, which is rewritten to match
+        let doc = doc.replace("<?xml version=\"1.0\"?>", "<?xml version=\"1.0\" encoding='windows-1252'?>");
+        let bytes = decode_to_utf8(&latin1(&doc), "text/xml");
+        assert!(bytes.starts_with(b"<?xml version=\"1.0\" encoding='UTF-8'?>"));
+        assert_eq!(parse_feed(&bytes, 1).unwrap().articles[0].title, "Café crème");
+
+        // The header wins over the declaration
+        let bytes = decode_to_utf8(doc.as_bytes(), "text/xml; charset=utf-8");
+        assert_eq!(parse_feed(&bytes, 1).unwrap().articles[0].title, "Café crème");
+
+        // Without a charset, UTF-8 is assumed
+        assert_eq!(decode_to_utf8(RSS.as_bytes(), "text/xml"), RSS.as_bytes());
+    }
+
+    #[test]
+    fn charset_param_is_case_insensitive() {
+        assert_eq!(charset_param("text/xml; Charset=\"windows-1252\""), Some("windows-1252"));
+        assert_eq!(charset_param("text/xml"), None);
+    }
+
+    #[test]
+    fn locate_xml_error_finds_offset() {
+        assert_eq!(locate_xml_error(RSS.as_bytes()), None);
+
+        let broken = RSS.replace("<title>Hello</title>", "<title>Hello</item>");
+        let offset = broken.find("</item>").unwrap();
+        assert_eq!(
+            locate_xml_error(broken.as_bytes()),
+            Some((offset, "mismatched tag </item> (expected </title>)".to_string()))
+        );
+
+        let truncated = &RSS[..RSS.find("</channel>").unwrap()];
+        assert_eq!(
+            locate_xml_error(truncated.as_bytes()),
+            Some((truncated.len(), "unclosed tag <channel>".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_xml_feed_repairs_control_characters() {
+        // A control character in the root element hides the feed entirely
+        let dirty = RSS.replace("version=\"2.0\"", "version=\"2\x0c.0\"");
+        let offset = dirty.find('\x0c').unwrap();
+        let (parsed, warning) = parse_xml_feed(dirty.as_bytes(), 1).unwrap();
+        assert_eq!(parsed.articles[0].title, "Hello");
+        assert_eq!(
+            warning.unwrap(),
+            format!("Repaired malformed XML (XML parse error near byte {offset}: invalid control character 0x0c)")
+        );
+
+        let (_, warning) = parse_xml_feed(RSS.as_bytes(), 1).unwrap();
+        assert_eq!(warning, None);
+
+        let broken = RSS.replace("</channel>", "");
+        let err = parse_xml_feed(broken.as_bytes(), 1).unwrap_err();
+        assert!(err.starts_with("XML parse error near byte"), "{err}");
+    }
+
+    #[test]
+    fn parse_retry_after_seconds_and_dates() {
+        let now = DateTime::parse_from_rfc3339("2024-05-06T08:00:00Z").unwrap().with_timezone(&Utc);
+        assert_eq!(parse_retry_after("120", now), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after("Mon, 06 May 2024 08:01:30 GMT", now), Some(Duration::from_secs(90)));
+        // Already passed
+        assert_eq!(parse_retry_after("Mon, 06 May 2024 07:00:00 GMT", now), Some(Duration::ZERO));
+        assert_eq!(parse_retry_after("soon", now), None);
+        // Absurd values are capped rather than overflowing the deadline
+        assert_eq!(parse_retry_after("18446744073709551615", now), Some(MAX_RETRY_AFTER));
+        assert_eq!(parse_retry_after("Fri, 31 Dec 9999 23:59:59 GMT", now), Some(MAX_RETRY_AFTER));
+    }
+
+    #[test]
+    fn rate_limit_is_per_host() {
+        set_rate_limited("throttled.example.com", Duration::from_secs(60));
+        set_rate_limited("expired.example.com", Duration::ZERO);
+
+        let remaining = rate_limit_remaining("throttled.example.com").unwrap();
+        assert!(remaining > Duration::from_secs(59));
+        assert_eq!(rate_limit_error(remaining), "rate-limited, retrying in 60s");
+        assert_eq!(rate_limit_remaining("expired.example.com"), None);
+        assert_eq!(rate_limit_remaining("other.example.com"), None);
+
+        set_rate_limited("forever.example.com", Duration::MAX);
+        assert!(rate_limit_remaining("forever.example.com").unwrap() <= MAX_RETRY_AFTER);
+    }
+
+    #[test]
+    fn parse_articles_json_feed() {
+        let json = br#"{
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": "Example",
+            "items": [
+                {
+                    "id": "post-1",
+                    "title": "Hello JSON",
+                    "url": "https://example.com/hello",
+                    "content_html": "<p>Hi</p>",
+                    "date_published": "2024-01-02T03:04:05Z",
+                    "authors": [{ "name": "Ada" }]
+                }
+            ]
+        }"#;
+        assert!(is_json_feed("application/json", std::str::from_utf8(json).unwrap()));
+
+        let articles = parse_feed(json, 7).unwrap().articles;
+        assert_eq!(articles.len(), 1);
+        let a = &articles[0];
+        assert_eq!(a.feed_id, 7);
+        assert_eq!(a.guid, "post-1");
+        assert_eq!(a.title, "Hello JSON");
+        assert_eq!(a.url.as_deref(), Some("https://example.com/hello"));
+        assert_eq!(a.content.as_deref(), Some("<p>Hi</p>"));
+        // No summary in the feed, so one is derived from the content
+        assert_eq!(a.summary.as_deref(), Some("Hi"));
+        assert_eq!(a.author.as_deref(), Some("Ada"));
+        assert_eq!(a.published.map(|d| d.to_rfc3339()).as_deref(), Some("2024-01-02T03:04:05+00:00"));
+    }
+
+    #[test]
+    fn parse_articles_rss() {
+        let rss = br#"<?xml version="1.0"?>
+            <rss version="2.0"><channel><title>Example</title>
+            <item><guid>item-1</guid><title>Hello RSS</title><link>https://example.com/rss</link></item>
+            </channel></rss>"#;
+        assert!(!is_json_feed("application/rss+xml", std::str::from_utf8(rss).unwrap()));
+
+        let parsed = parse_feed(rss, 3).unwrap();
+        assert_eq!(parsed.title.as_deref(), Some("Example"));
+        let articles = parsed.articles;
+        assert_eq!(articles.len(), 1);
+        assert_eq!(articles[0].guid, "item-1");
+        assert_eq!(articles[0].title, "Hello RSS");
+        assert_eq!(articles[0].url.as_deref(), Some("https://example.com/rss"));
+    }
+
+    #[test]
+    fn parse_articles_prefers_audio_enclosure() {
+        let rss = br#"<?xml version="1.0"?>
+            <rss version="2.0"><channel><title>Podcast</title>
+            <item><guid>ep-1</guid><title>Episode 1</title>
+              <enclosure url="https://example.com/cover.jpg" type="image/jpeg" length="1"/>
+              <enclosure url="https://example.com/ep1.mp3" type="audio/mpeg" length="2"/>
+            </item>
+            <item><guid>ep-2</guid><title>Episode 2</title></item>
+            </channel></rss>"#;
+
+        let articles = parse_feed(rss, 1).unwrap().articles;
+        assert_eq!(articles[0].enclosure_url.as_deref(), Some("https://example.com/ep1.mp3"));
+        assert_eq!(articles[1].enclosure_url, None);
+    }
+
+    #[test]
+    fn strip_tracking_params_removes_known_keys() {
+        assert_eq!(
+            strip_tracking_params("https://example.com/post?id=7&utm_source=rss&UTM_Medium=feed&fbclid=abc#comments"),
+            "https://example.com/post?id=7#comments"
+        );
+        assert_eq!(
+            strip_tracking_params("https://example.com/post?gclid=1&utm_campaign=x"),
+            "https://example.com/post"
+        );
+        // Untracked URLs come back unchanged, including odd query strings
+        assert_eq!(strip_tracking_params("https://example.com/a?b&&c="), "https://example.com/a?b&&c=");
+        assert_eq!(strip_tracking_params("https://example.com/#utm_source=x"), "https://example.com/#utm_source=x");
+    }
+
+    #[test]
+    fn discover_feed_url_finds_rss_link() {
+        let html = r#"<html><head>
+            <link rel="stylesheet" href="/style.css">
+            <link rel="alternate" type="application/rss+xml" title="RSS" href="https://example.com/feed.xml">
+            </head></html>"#;
+        assert_eq!(
+            discover_feed_url(html, &base()).as_deref(),
+            Some("https://example.com/feed.xml")
+        );
+    }
+
+    #[test]
+    fn discover_feed_url_resolves_relative_atom_link() {
+        let html = "<HEAD><LINK TYPE='application/atom+xml' REL='alternate' HREF='atom.xml' /></HEAD>";
+        assert_eq!(
+            discover_feed_url(html, &base()).as_deref(),
+            Some("https://example.com/blog/atom.xml")
+        );
+    }
+
+    #[test]
+    fn discover_feed_url_ignores_non_feed_links() {
+        let html = r#"<link rel="alternate" hreflang="fr" href="/fr/"><link rel="icon" href="/favicon.ico">"#;
+        assert_eq!(discover_feed_url(html, &base()), None);
+    }
+
+    #[test]
+    fn extract_article_picks_the_post_body() {
+        let paragraph = "<p>".to_string() + &"All work and no play. ".repeat(12) + "</p>";
+        let html = format!(
+            r#"<html><head><title>Post</title><script>var s = "<div>";</script></head><body>
+            <header><nav><a href="/">Home</a></nav></header>
+            <div class="page-content">
+              <article>
+                <h1>Post</h1>
+                <div class="entry-content">{paragraph}<div><img src="/a.png"></div>{paragraph}</div>
+              </article>
+              <aside><p>Related posts</p></aside>
+            </div>
+            <footer><p>Copyright</p></footer>
+            </body></html>"#
+        );
+
+        let extracted = extract_article(&html).unwrap();
+        assert!(extracted.starts_with("<p>All work"));
+        assert!(extracted.ends_with("</p>"));
+        assert!(extracted.contains(r#"<img src="/a.png">"#));
+        assert!(!extracted.contains("<h1>"));
+    }
+
+    #[test]
+    fn extract_article_falls_back_to_body() {
+        let paragraph = format!("<p>{}</p>", "Text. ".repeat(50));
+        let html = format!("<body><nav>Menu</nav>{paragraph}</body>");
+        assert_eq!(extract_article(&html), Some(paragraph));
+
+        // A cookie wall is not an article
+        assert_eq!(extract_article("<body><p>Please accept cookies.</p></body>"), None);
+    }
+
+    #[test]
+    fn jitter_delays_stay_within_the_limit() {
+        assert_eq!(jitter_delay(Duration::ZERO), Duration::ZERO);
+
+        let max = Duration::from_secs(5);
+        let delays: Vec<Duration> = (0..100).map(|_| jitter_delay(max)).collect();
+        assert!(delays.iter().all(|d| *d <= max));
+        // Fetches are actually spread out rather than sharing one delay
+        assert!(delays.iter().any(|d| *d != delays[0]));
+    }
+
+    #[test]
+    fn relative_image_urls_resolve_against_the_article() {
+        let base = Some("https://example.com/blog/post/");
+        assert_eq!(
+            resolve_image_url("/img/a.png", base).unwrap().as_str(),
+            "https://example.com/img/a.png"
+        );
+        assert_eq!(
+            resolve_image_url("../x.jpg", base).unwrap().as_str(),
+            "https://example.com/blog/x.jpg"
+        );
+        assert_eq!(
+            resolve_image_url("https://cdn.example.org/b.png", None).unwrap().as_str(),
+            "https://cdn.example.org/b.png"
+        );
+        assert!(resolve_image_url("/img/a.png", None).is_none());
+    }
 }
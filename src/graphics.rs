@@ -0,0 +1,59 @@
+use std::fmt;
+
+use ratatui_image::picker::{Picker, ProtocolType};
+use ratatui_image::protocol::StatefulProtocol;
+
+/// A decoded lead image ready to be shown at the top of the article view.
+pub struct HeroImage {
+    /// Article the image belongs to.
+    pub article_id: i64,
+    /// The image, encoded for the terminal's graphics protocol at whatever
+    /// size it was last drawn.
+    pub protocol: StatefulProtocol,
+}
+
+impl HeroImage {
+    /// Decode downloaded bytes (PNG, JPEG, GIF or WebP) for display through
+    /// `picker`, or `None` if they are not an image in one of those formats.
+    pub fn decode(article_id: i64, data: &[u8], picker: &Picker) -> Option<Self> {
+        let image = image::load_from_memory(data).ok()?;
+        Some(Self { article_id, protocol: picker.new_resize_protocol(image) })
+    }
+}
+
+impl fmt::Debug for HeroImage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HeroImage").field("article_id", &self.article_id).finish_non_exhaustive()
+    }
+}
+
+/// Ask the terminal which graphics protocol it supports (kitty, sixel or
+/// iTerm2) and how large its cells are.
+///
+/// Returns `None` when it supports none of them. Must run after entering
+/// the alternate screen and before input events are read, since the reply
+/// arrives on stdin.
+pub fn detect_picker() -> Option<Picker> {
+    Picker::from_query_stdio()
+        .ok()
+        .filter(|picker| picker.protocol_type() != ProtocolType::Halfblocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(format: image::ImageFormat) -> Vec<u8> {
+        let mut data = std::io::Cursor::new(Vec::new());
+        image::DynamicImage::new_rgb8(8, 4).write_to(&mut data, format).unwrap();
+        data.into_inner()
+    }
+
+    #[test]
+    fn decode_accepts_png_and_jpeg() {
+        let picker = Picker::from_fontsize((8, 16));
+        assert!(HeroImage::decode(1, &encode(image::ImageFormat::Png), &picker).is_some());
+        assert!(HeroImage::decode(1, &encode(image::ImageFormat::Jpeg), &picker).is_some());
+        assert!(HeroImage::decode(1, b"<html>not an image</html>", &picker).is_none());
+    }
+}
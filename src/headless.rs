@@ -0,0 +1,225 @@
+//! Headless entry point for embedding lazyrss without the TUI.
+//!
+//! [`LazyRss`] wraps the configuration and the async database behind a small
+//! API, so other frontends can fetch feeds, list articles and track read
+//! state the same way the TUI does:
+//!
+//! ```no_run
+//! # async fn run() -> anyhow::Result<()> {
+//! let lazyrss = lazyrss::LazyRss::open(lazyrss::config::load()?).await?;
+//! let summary = lazyrss.refresh_all().await?;
+//! println!("{} new articles", summary.new_articles);
+//!
+//! for feed in lazyrss.feeds().await? {
+//!     for article in lazyrss.articles_for_feed(feed.id).await? {
+//!         println!("{}: {}", feed.title, article.title);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Everything runs on the caller's tokio runtime.
+
+use tokio::sync::mpsc;
+
+use crate::config::{Config, DisplayConfig};
+use crate::db::{self, Article, Feed, UpsertCounts};
+use crate::db_async::AsyncDb;
+use crate::feed::{self, FeedUpdateResult};
+
+/// A feed reader without a user interface.
+pub struct LazyRss {
+    config: Config,
+    db: AsyncDb,
+}
+
+/// Outcome of [`LazyRss::refresh_all`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RefreshSummary {
+    /// Feeds fetched without an error.
+    pub fetched: usize,
+    /// Articles that were not stored before.
+    pub new_articles: usize,
+    /// Stored articles that the feed republished with changes.
+    pub updated_articles: usize,
+    /// One message per feed that failed, naming the feed.
+    pub errors: Vec<String>,
+    /// Problems that were worked around, e.g. malformed XML that parsed once
+    /// repaired, naming the feed.
+    pub warnings: Vec<String>,
+}
+
+impl LazyRss {
+    /// Use an already opened database, e.g. one shared with other code.
+    ///
+    /// The database's feed list should already match `config` (see
+    /// [`AsyncDb::sync_feeds_from_config`]).
+    pub fn new(config: Config, db: AsyncDb) -> Self {
+        Self { config, db }
+    }
+
+    /// Open the database at `$XDG_DATA_HOME/lazyrss/news.db` and sync the
+    /// feeds in `config` into it.
+    ///
+    /// Unlike the TUI this doesn't fall back to an in-memory database, since
+    /// nothing fetched into one would be kept.
+    pub async fn open(config: Config) -> anyhow::Result<Self> {
+        let (conn, db_error) = db::initialize()?;
+        if let Some(err) = db_error {
+            return Err(err);
+        }
+        let db = AsyncDb::new(conn);
+        db.sync_feeds_from_config(&config).await?;
+        Ok(Self::new(config, db))
+    }
+
+    /// The configuration this reader was created with.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// The underlying database, for operations not covered here.
+    pub fn db(&self) -> &AsyncDb {
+        &self.db
+    }
+
+    /// All feeds with their unread counts.
+    pub async fn feeds(&self) -> anyhow::Result<Vec<Feed>> {
+        self.db.get_all_feeds(self.config.display.show_total_counts).await
+    }
+
+    /// Fetch every feed and store the results, waiting until all fetches
+    /// are done. A feed that fails to fetch is reported in the summary
+    /// rather than failing the whole refresh.
+    pub async fn refresh_all(&self) -> anyhow::Result<RefreshSummary> {
+        let feeds = self.db.get_all_feeds(false).await?;
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        feed::refresh_all(&tx, &feeds, std::time::Duration::ZERO);
+        // The channel closes once every fetch task has dropped its sender
+        drop(tx);
+
+        let mut summary = RefreshSummary::default();
+        while let Some(result) = rx.recv().await {
+            let title = feeds.iter()
+                .find(|f| f.id == result.feed_id)
+                .map_or("unknown feed", |f| f.title.as_str());
+            match &result.error {
+                Some(err) => summary.errors.push(format!("Failed to fetch {title}: {err}")),
+                None => summary.fetched += 1,
+            }
+            if let Some(warning) = &result.warning {
+                summary.warnings.push(format!("{title}: {warning}"));
+            }
+
+            let counts = store_feed_update(&self.db, result, &self.config.display).await?;
+            summary.new_articles += counts.inserted;
+            summary.updated_articles += counts.updated;
+        }
+
+        Ok(summary)
+    }
+
+    /// Articles of one feed, newest first.
+    pub async fn articles_for_feed(&self, feed_id: i64) -> anyhow::Result<Vec<Article>> {
+        self.db.get_articles_for_feed(feed_id).await
+    }
+
+    /// Flip an article between read and unread, returning the new state.
+    pub async fn toggle_read(&self, article_id: i64) -> anyhow::Result<bool> {
+        self.db.toggle_read(article_id).await
+    }
+}
+
+/// Store a finished fetch: upsert its articles and record when the feed was
+/// fetched, its error (if any) and the title it gives itself.
+pub async fn store_feed_update(
+    db: &AsyncDb,
+    result: FeedUpdateResult,
+    display: &DisplayConfig,
+) -> anyhow::Result<UpsertCounts> {
+    let mut articles = result.articles;
+    if display.strip_tracking_params {
+        feed::strip_article_tracking_params(&mut articles);
+    }
+
+    let counts = db.upsert_articles(articles, display.unread_on_update).await?;
+    // A failed fetch keeps the time of the last one that worked
+    if result.error.is_none() {
+        db.update_last_fetched(result.feed_id).await?;
+    }
+    db.set_last_error(result.feed_id, result.error).await?;
+    if let Some(title) = result.feed_title {
+        db.set_feed_title(result.feed_id, title).await?;
+    }
+
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{FeedConfigItem, FeedSource};
+
+    fn reader() -> LazyRss {
+        let config = Config {
+            feeds: vec![FeedConfigItem::Standalone(FeedSource {
+                title: "Rust Blog".into(),
+                url: "https://blog.rust-lang.org/".into(),
+                feed: Some("https://blog.rust-lang.org/feed.xml".into()),
+                ..Default::default()
+            })],
+            ..Config::default()
+        };
+        let conn = db::open_in_memory().unwrap();
+        db::sync_feeds_from_config(&conn, &config).unwrap();
+        LazyRss::new(config, AsyncDb::new(conn))
+    }
+
+    fn article(feed_id: i64) -> Article {
+        Article {
+            id: 0,
+            feed_id,
+            guid: "post-1".to_string(),
+            title: "Hello".to_string(),
+            url: Some("https://blog.rust-lang.org/post?utm_source=rss".to_string()),
+            author: None,
+            summary: None,
+            content: None,
+            published: None,
+            is_read: false,
+            is_starred: false,
+            is_queued: false,
+            enclosure_url: None,
+            has_full_content: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn stored_updates_can_be_read_and_toggled() {
+        let lazyrss = reader();
+        let feed_id = lazyrss.feeds().await.unwrap()[0].id;
+
+        let result = FeedUpdateResult {
+            feed_id,
+            articles: vec![article(feed_id)],
+            feed_title: None,
+            error: Some("timed out".to_string()),
+            warning: None,
+        };
+        let counts = store_feed_update(lazyrss.db(), result, &lazyrss.config().display).await.unwrap();
+        assert_eq!(counts.inserted, 1);
+
+        let feed = &lazyrss.feeds().await.unwrap()[0];
+        assert_eq!(feed.unread_count, 1);
+        assert_eq!(feed.last_error.as_deref(), Some("timed out"));
+        assert!(feed.last_fetched.is_none());
+
+        let articles = lazyrss.articles_for_feed(feed_id).await.unwrap();
+        assert_eq!(articles[0].url.as_deref(), Some("https://blog.rust-lang.org/post"));
+
+        assert!(lazyrss.toggle_read(articles[0].id).await.unwrap());
+        assert_eq!(lazyrss.feeds().await.unwrap()[0].unread_count, 0);
+    }
+}
@@ -0,0 +1,121 @@
+//! Minimal HTML scanning shared by feed discovery, article extraction and
+//! rendering.
+
+/// Start of the next `open` tag (e.g. `"<div"` or `"</div"`) at or after
+/// `from` in ASCII-lowercased HTML, skipping longer tag names that share the
+/// prefix (`<divider>`).
+pub fn find_tag(lower: &str, open: &str, from: usize) -> Option<usize> {
+    let mut pos = from;
+    while let Some(i) = lower[pos..].find(open) {
+        let start = pos + i;
+        let next = lower.as_bytes().get(start + open.len());
+        if next.is_none_or(|&b| b.is_ascii_whitespace() || b == b'>' || b == b'/') {
+            return Some(start);
+        }
+        pos = start + open.len();
+    }
+    None
+}
+
+/// Parse `name="value"` pairs from the inside of an HTML tag.
+///
+/// Handles double-quoted, single-quoted and unquoted values; attributes
+/// without a value are skipped.
+pub fn parse_tag_attributes(tag: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    let mut chars = tag.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() || c == '/' {
+            chars.next();
+            continue;
+        }
+
+        // Attribute name
+        let mut name_end = start;
+        while let Some(&(i, c)) = chars.peek() {
+            if c.is_whitespace() || c == '=' || c == '/' {
+                break;
+            }
+            name_end = i + c.len_utf8();
+            chars.next();
+        }
+        let name = &tag[start..name_end];
+
+        // Skip whitespace around '='
+        while chars.peek().is_some_and(|&(_, c)| c.is_whitespace()) {
+            chars.next();
+        }
+        if chars.peek().is_none_or(|&(_, c)| c != '=') {
+            continue;
+        }
+        chars.next();
+        while chars.peek().is_some_and(|&(_, c)| c.is_whitespace()) {
+            chars.next();
+        }
+
+        // Attribute value
+        let value = match chars.peek() {
+            Some(&(i, quote)) if quote == '"' || quote == '\'' => {
+                chars.next();
+                let value_start = i + 1;
+                let mut value_end = tag.len();
+                for (j, c) in chars.by_ref() {
+                    if c == quote {
+                        value_end = j;
+                        break;
+                    }
+                }
+                &tag[value_start..value_end]
+            }
+            Some(&(i, _)) => {
+                let mut value_end = tag.len();
+                while let Some(&(j, c)) = chars.peek() {
+                    if c.is_whitespace() {
+                        value_end = j;
+                        break;
+                    }
+                    chars.next();
+                }
+                &tag[i..value_end]
+            }
+            None => "",
+        };
+
+        attrs.push((name.to_string(), value.to_string()));
+    }
+
+    attrs
+}
+
+/// Value of attribute `name` (matched case-insensitively) inside a tag, as
+/// read by [`parse_tag_attributes`]. Entities are left encoded.
+pub fn tag_attribute(tag: &str, name: &str) -> Option<String> {
+    parse_tag_attributes(tag)
+        .into_iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_tag_skips_longer_names() {
+        let lower = "<divider><div class=x></div>";
+        assert_eq!(find_tag(lower, "<div", 0), Some(9));
+        assert_eq!(find_tag(lower, "</div", 0), Some(22));
+        assert_eq!(find_tag(lower, "<p", 0), None);
+    }
+
+    #[test]
+    fn tag_attribute_parses_quoting_styles() {
+        let tag = " data-src=\"x\" SRC=\"a.png?w=1&amp;h=2\" alt='It&#39;s' width=10 hidden";
+        assert_eq!(tag_attribute(tag, "src").as_deref(), Some("a.png?w=1&amp;h=2"));
+        assert_eq!(tag_attribute(tag, "alt").as_deref(), Some("It&#39;s"));
+        assert_eq!(tag_attribute(tag, "width").as_deref(), Some("10"));
+        assert_eq!(tag_attribute(tag, "hidden"), None);
+        assert_eq!(tag_attribute(tag, "title"), None);
+    }
+}
@@ -1,12 +1,22 @@
 pub mod action;
+pub mod atom;
+pub mod command;
 pub mod app;
 pub mod config;
 pub mod db;
 pub mod db_async;
 pub mod event;
 pub mod feed;
+pub mod graphics;
+pub mod headless;
+pub mod html;
+pub mod logging;
+pub mod notify;
+pub mod osc52;
 pub mod render;
 pub mod ui;
+pub mod ui_state;
 
 // Re-export commonly used types
 pub use app::ClipboardItem;
+pub use headless::LazyRss;
@@ -0,0 +1,134 @@
+//! Optional diagnostics log (`--log` or `logging.enabled`).
+//!
+//! Fetches and background database failures are recorded with `tracing`
+//! into `$XDG_STATE_HOME/lazyrss/lazyrss.log` (the data directory on
+//! platforms without a state directory). Nothing is written unless logging
+//! is enabled.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::Context;
+
+/// Size past which the log is rotated to `lazyrss.log.1`.
+pub const MAX_LOG_BYTES: u64 = 1024 * 1024;
+
+/// Path of the log file.
+pub fn log_path() -> anyhow::Result<PathBuf> {
+    let dir = dirs::state_dir()
+        .or_else(dirs::data_dir)
+        .context("Could not determine state directory")?;
+    Ok(dir.join("lazyrss").join("lazyrss.log"))
+}
+
+/// Start writing log records to [`log_path`], rotating the log whenever it
+/// grows past [`MAX_LOG_BYTES`].
+pub fn init() -> anyhow::Result<()> {
+    let path = log_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create log directory: {}", parent.display()))?;
+    }
+    let log = RotatingLog::open(path.clone(), MAX_LOG_BYTES)
+        .with_context(|| format!("Failed to open log file: {}", path.display()))?;
+
+    tracing_subscriber::fmt()
+        .with_writer(Mutex::new(log))
+        .with_ansi(false)
+        .with_target(false)
+        .try_init()
+        .map_err(|e| anyhow::anyhow!("Failed to start logging: {e}"))
+}
+
+/// A log file that rotates itself once it grows past `max_bytes`, checked on
+/// every write so a long-running session stays capped too.
+struct RotatingLog {
+    path: PathBuf,
+    file: File,
+    len: u64,
+    max_bytes: u64,
+}
+
+impl RotatingLog {
+    /// Open `path` for appending, rotating it first if it is already large.
+    fn open(path: PathBuf, max_bytes: u64) -> io::Result<Self> {
+        rotate_if_large(&path, max_bytes)?;
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let len = file.metadata()?.len();
+        Ok(Self { path, file, len, max_bytes })
+    }
+}
+
+impl Write for RotatingLog {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.len > self.max_bytes {
+            *self = Self::open(self.path.clone(), self.max_bytes)?;
+        }
+        let written = self.file.write(buf)?;
+        self.len += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Move `path` to `<path>.1` (replacing an older rotation) once it is larger
+/// than `max_bytes`, so at most two logs are ever kept.
+fn rotate_if_large(path: &Path, max_bytes: u64) -> std::io::Result<()> {
+    match fs::metadata(path) {
+        Ok(meta) if meta.len() > max_bytes => {
+            let mut rotated = path.as_os_str().to_owned();
+            rotated.push(".1");
+            fs::rename(path, rotated)
+        }
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn large_logs_are_rotated() {
+        let dir = std::env::temp_dir().join(format!("lazyrss-log-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("lazyrss.log");
+
+        // Missing and small logs are left alone
+        rotate_if_large(&path, 4).unwrap();
+        fs::write(&path, "1234").unwrap();
+        rotate_if_large(&path, 4).unwrap();
+        assert!(path.exists());
+
+        fs::write(&path, "12345").unwrap();
+        rotate_if_large(&path, 4).unwrap();
+        let rotated = fs::read_to_string(dir.join("lazyrss.log.1"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(rotated.unwrap(), "12345");
+    }
+
+    #[test]
+    fn logs_rotate_while_running() {
+        let dir = std::env::temp_dir().join(format!("lazyrss-rotating-log-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("lazyrss.log");
+
+        let mut log = RotatingLog::open(path.clone(), 8).unwrap();
+        log.write_all(b"first record\n").unwrap();
+        log.write_all(b"second\n").unwrap();
+        let current = fs::read_to_string(&path);
+        let rotated = fs::read_to_string(dir.join("lazyrss.log.1"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(rotated.unwrap(), "first record\n");
+        assert_eq!(current.unwrap(), "second\n");
+    }
+}
@@ -1,8 +1,11 @@
+use std::path::PathBuf;
 use std::time::Duration;
 
+use anyhow::Context;
 use clap::Parser;
 use crossterm::event::{KeyCode, KeyModifiers};
-use lazyrss::{action, app::App, config, db, db_async::AsyncDb, event, ui};
+use lazyrss::headless::{LazyRss, RefreshSummary};
+use lazyrss::{action, app::App, atom, config, db, db_async::AsyncDb, event, feed, graphics, logging, ui};
 
 const LONG_HELP: &str = r#"
 CONFIGURATION
@@ -12,13 +15,21 @@ CONFIGURATION
     Article database:  $XDG_DATA_HOME/lazyrss/news.db
                        (typically ~/.local/share/lazyrss/news.db)
 
+    UI state:          $XDG_DATA_HOME/lazyrss/ui_state.json
+                       (collapsed groups and last selection, remembered between runs)
+
+    Log file:          $XDG_STATE_HOME/lazyrss/lazyrss.log
+                       (only with --log or logging.enabled)
+
     Example configuration:
         refresh_every: 300           # Auto-refresh interval (seconds)
         display:
           format:
-            time: 12                 # 12 or 24 hour format
-            date: "D MMM YYYY"
+            time: 12                 # 12 or 24 hour clock, for every date format
+            date: "D MMM YYYY"       # D DD ddd dddd MMM MMMM YY YYYY H HH h hh mm a
+                                     # (H/h and HH/hh only set hour padding)
             title_lines: 2
+            relative_dates: false    # "15m", "3h", "2d" for recent articles
           columns:
             feeds_list: 25           # Width percentages
             articles_list: 35
@@ -28,25 +39,73 @@ CONFIGURATION
             inactive_border: "darkgray"
             border_type: "plain"     # plain, double, thick, rounded
             highlight_bg: "darkgray"
+            inactive_highlight_bg: "236"  # Selected row in unfocused panes
             unread_indicator: "cyan"
+            code_block_bg: "236"     # Background for <pre> code blocks
+            read_fg: "darkgray"      # Text of read articles and feeds
+          article_row_format: "{unread} {star} {queue} {title}"  # {unread} {star} {queue} {date} {feed} {title}
+          dedup_by_url: false        # Collapse cross-posted articles in group/All views
+          mark_read_on: "select"     # select, open, or scroll
+          recent_window_hours: 24    # Window for the "Recent" feed-list entry
+          wrap_navigation: true      # Wrap from the last row to the first (and back)
+          inline_images: false       # Show the lead image (kitty, sixel or iTerm2 terminals)
+          strip_tracking_params: true  # Drop utm_*, fbclid, gclid, ... from article links
+          unread_on_update: false    # Mark read articles unread when their text is edited
+          confirm_mark_all_read: true  # Ask before marking everything read from "All"
+          show_total_counts: false   # Show "unread/total" counts in the feeds pane
+          sort_feeds: false          # Sort feeds by title (ignoring case and accents)
+          show_preview: false        # Excerpt line under each title in the articles list
+          initial_pane: "articles"   # feeds, articles, or article_view
+          layout: "horizontal"       # horizontal, vertical, or auto (stacked when narrow)
+          article_padding: 0         # Blank columns each side of the article text
+          glyphs:                    # Single-character list indicators
+            unread: "●"
+            read: "○"
+            starred: "★"
+            queued: "⏲"
+            group_open: "▼"
+            group_closed: "▶"
+            error: "✗"               # Feed whose last fetch failed
+        notifications:
+          enabled: false             # Desktop notifications (D-Bus on Linux)
+        logging:
+          enabled: false             # Write $XDG_STATE_HOME/lazyrss/lazyrss.log
+        network:
+          max_concurrent_fetches: 16 # Feeds fetched at the same time
+          refresh_jitter_secs: 0     # Spread automatic refreshes over this many seconds
         feeds:
           - title: "Tech"
             feeds:
               - title: "Rust Blog"
                 url: "https://blog.rust-lang.org/"
                 feed: "https://blog.rust-lang.org/feed.xml"
+                notify: true         # Notify on new articles (optional)
+                use_feed_title: false  # Show the feed's own title (optional)
+                fetch_full_content: false  # Fetch the full text when opened (optional)
         keybindings:
           global:
-            quit: ["q", "Ctrl-c"]
+            quit: ["q", "Ctrl-c"]   # One key or a list; [] unbinds
             focus_next: "Tab"
             focus_prev: "Shift-Tab"
+            focus_feeds: "Alt-1"
+            focus_articles: "Alt-2"
+            focus_article_view: "Alt-3"
             refresh_current: "r"
             refresh_all: "R"
             open_browser: "o"
+            open_feed_site: "O"
+            toggle_time_format: "T"
             jump_top: "g"
             jump_bottom: "G"
             create_group: "Ctrl-g"
             create_feed: "Ctrl-n"
+            next_unread: "n"
+            copy_enclosure: "y"
+            open_image: "i"
+            fetch_full_article: "F"
+            command: ":"
+            grow_feeds_pane: "Alt-l"
+            shrink_feeds_pane: "Alt-h"
           feeds:
             move_down: ["j", "Down"]
             move_up: ["k", "Up"]
@@ -54,29 +113,45 @@ CONFIGURATION
             toggle_collapse: "Space"
             expand_all: "e"
             collapse_all: "E"
+            move_item_up: ["K", "Alt-Up"]
+            move_item_down: ["J", "Alt-Down"]
+            move_to_group: "m"
           articles:
             move_down: ["j", "Down"]
             move_up: ["k", "Up"]
             select: "Enter"
             toggle_read: "m"
             toggle_star: "s"
+            toggle_queue: "l"
             mark_all_read: "M"
+            mark_read_to_here: "Ctrl-r"
           article_view:
             scroll_down: ["j", "Down"]
             scroll_up: ["k", "Up"]
+            toggle_zen: "z"
 
 KEYBINDINGS
     Global (work in all panes):
         q, Ctrl+c      Quit
         Tab            Focus next pane
         Shift+Tab      Focus previous pane
-        r              Refresh current feed
+        Alt+1/2/3      Focus feeds / articles / article view
+        r              Refresh current feed (or group, with its subgroups)
         R              Refresh all feeds
         o              Open article in browser
-        g              Jump to top
-        G              Jump to bottom
+        O              Open the feed's website in browser
+        T              Toggle 12/24-hour times
+        Alt+l, Alt+h   Widen / narrow the feeds pane
+        gg, g          Jump to top (a lone g acts after a short pause)
+        G, ge          Jump to bottom
         Ctrl+g         Create new group
         Ctrl+n         Create new feed
+        n              Next unread article (moves on to the next feed)
+        y              Copy enclosure (podcast audio) URL to clipboard
+        i              Open article image in browser ([count]i picks image N)
+        F              Fetch the full article from its web page
+        :              Command prompt (refresh, mark-all-read, goto <feed>,
+                       set <option>=<value>, quit)
 
     Feeds Pane:
         j, ↓           Move down
@@ -86,6 +161,9 @@ KEYBINDINGS
         Shift+Space    Mark all read (including nested groups)
         e              Expand all groups
         E              Collapse all groups
+        K, Alt+↑       Move feed/group up within its group
+        J, Alt+↓       Move feed/group down within its group
+        m              Move feed to another group
         Ctrl+d, PgDn   Scroll half-page down
         Ctrl+u, PgUp   Scroll half-page up
         Ctrl+e         Edit feed/group
@@ -99,7 +177,9 @@ KEYBINDINGS
         Enter          Mark as read and open
         m              Toggle read status
         s              Toggle star
+        l              Toggle read-later queue
         M              Mark all as read
+        Ctrl+r         Mark all articles above as read
         Ctrl+d, PgDn   Scroll half-page down
         Ctrl+u, PgUp   Scroll half-page up
 
@@ -108,6 +188,14 @@ KEYBINDINGS
         k, ↑           Scroll up
         Ctrl+d, PgDn   Scroll half-page down
         Ctrl+u, PgUp   Scroll half-page up
+        z              Zen mode: article view fills the screen
+
+    Popups:
+        ←, →           Move the cursor
+        Home, End      Jump to the start or end of the field
+        Backspace, Del Delete before or under the cursor
+        Ctrl+v         Paste from the clipboard
+        Tab, Shift+Tab Switch fields
 
     Vim-style counts are supported (e.g., 5j, 10k).
 
@@ -115,6 +203,8 @@ VISUAL INDICATORS
     ●   Unread article
     ○   Read article
     ★   Starred article
+    ⏲   Queued to read later
+    ✗   Feed whose last fetch failed
 
 For complete documentation, see 'man lazyrss'.
 
@@ -128,18 +218,74 @@ Project homepage: https://github.com/sujaltv/lazyrss
 #[command(version)]
 #[command(about = "A terminal-based RSS/Atom feed reader inspired by lazygit", long_about = None)]
 #[command(after_help = LONG_HELP)]
-struct Args {}
+struct Args {
+    /// Fetch all feeds, print a summary and exit without starting the TUI
+    #[arg(long)]
+    refresh: bool,
+
+    /// Print feed and unread counts as JSON and exit, without fetching or
+    /// writing to the database
+    #[arg(long, conflicts_with = "refresh")]
+    status: bool,
+
+    /// Write the starred articles to PATH as an Atom feed and exit
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["refresh", "status"])]
+    export_starred: Option<PathBuf>,
+
+    /// Render without colours, marking the selection with a glyph. Also
+    /// enabled by a non-empty NO_COLOR environment variable
+    #[arg(long)]
+    no_color: bool,
+
+    /// Record fetches and errors in $XDG_STATE_HOME/lazyrss/lazyrss.log
+    #[arg(long)]
+    log: bool,
+}
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Parse CLI arguments (handles --help, --version automatically)
-    let _args = Args::parse();
+    let args = Args::parse();
 
     // 1. Load configuration from XDG config dir.
     let config = config::load()?;
+    feed::set_max_concurrent_fetches(config.network.max_concurrent_fetches);
+    if args.log || config.logging.enabled {
+        logging::init()?;
+    }
 
-    // 2. Initialize the SQLite database (creates tables if needed).
-    let conn = db::initialize()?;
+    // Status mode: report counts from the existing database and exit
+    if args.status {
+        let conn = db::open_read_only()?;
+        let summary = db::StatusSummary::from_feeds(&db::get_all_feeds(&conn)?);
+        println!("{}", serde_json::to_string(&summary)?);
+        return Ok(());
+    }
+
+    // Export mode: write the starred articles as an Atom feed and exit
+    if let Some(path) = &args.export_starred {
+        let conn = db::open_read_only()?;
+        let articles = db::get_starred_articles(&conn)?;
+        std::fs::write(path, atom::starred_feed(&articles, chrono::Utc::now()))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        println!("Exported {} starred articles to {}", articles.len(), path.display());
+        return Ok(());
+    }
+
+    // Headless mode: fetch once and exit (for cron jobs, notifiers, ...).
+    // Unlike the TUI there is no in-memory fallback: a refresh that can't be
+    // saved must fail.
+    if args.refresh {
+        return refresh_headless(&LazyRss::open(config).await?).await;
+    }
+
+    // 2. Initialize the SQLite database (creates tables if needed), falling
+    //    back to an in-memory one if the data directory can't be used.
+    let (conn, db_error) = db::initialize()?;
+    if let Some(err) = &db_error {
+        eprintln!("Database unavailable, changes won't be saved: {err:#}");
+        tracing::error!("database unavailable, using an in-memory one: {err:#}");
+    }
 
     // 3. Synchronize the config's feed list into the database.
     db::sync_feeds_from_config(&conn, &config)?;
@@ -148,11 +294,26 @@ async fn main() -> anyhow::Result<()> {
     let async_db = AsyncDb::new(conn);
 
     // 5. Build the application state and extract the receivers
-    let refresh_secs = config.refresh_every;
-    let (mut app, mut feed_update_rx, mut db_result_rx, mut render_rx) = App::new_with_receivers(config, async_db);
+    let mut refresh_secs = config.refresh_every;
+    let (mut app, mut feed_update_rx, mut db_result_rx, mut net_result_rx, mut render_rx) =
+        App::new_with_receivers(config, async_db);
+    app.no_color = args.no_color || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    if let Some(err) = &db_error {
+        app.status_message = Some(format!("DB unavailable \u{2014} running in-memory, changes won't persist ({err})"));
+    } else {
+        // Surface keys that were bound twice, where only one action would fire
+        let conflicts = app.config.keybindings.conflicts();
+        if !conflicts.is_empty() {
+            app.status_message = Some(conflicts.join("; "));
+        }
+    }
 
     // 6. Set up the terminal for TUI rendering.
     let mut terminal = ratatui::init();
+    if app.config.display.inline_images {
+        // The terminal answers on stdin, so ask before reading input events
+        app.image_picker = graphics::detect_picker();
+    }
 
     // 7. Create the async event handler (250 ms tick rate).
     let mut events = event::EventHandler::new(250);
@@ -163,77 +324,186 @@ async fn main() -> anyhow::Result<()> {
 
     // 9. Main event loop.
     loop {
-        // Draw the current state.
-        terminal.draw(|frame| ui::render(frame, &mut app))?;
+        // Draw the current state, unless nothing has changed since the
+        // last frame (e.g. an idle tick).
+        if app.needs_redraw {
+            terminal.draw(|frame| ui::render(frame, &mut app))?;
+            app.frame_drawn();
+        }
 
         // Wait for the next event using tokio::select! with owned receivers
         tokio::select! {
             // User input events
             event = events.next() => {
-                let event = event?;
-                match &event {
-                    event::Event::Key(key) if app.popup.is_some() => {
-                        // Handle popup input
-                        match key.code {
-                            KeyCode::Char(c) if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT => {
-                                app.handle_popup_char(c);
-                            }
-                            KeyCode::Backspace => {
-                                app.handle_popup_backspace();
-                            }
-                            KeyCode::Tab => {
-                                if key.modifiers.is_empty() {
-                                    app.handle_popup_tab();
-                                } else {
-                                    // Shift+Tab (BackTab) - some terminals report this way
-                                    app.handle_popup_backtab();
-                                }
-                            }
-                            KeyCode::BackTab => {
-                                // Some terminals report Shift+Tab as BackTab
-                                app.handle_popup_backtab();
-                            }
-                            KeyCode::Enter => {
-                                app.handle_popup_enter();
-                            }
-                            KeyCode::Esc => {
-                                app.handle_popup_escape();
-                            }
-                            _ => {}
-                        }
-                    }
-                    _ => {
-                        if let Some(act) = action::handle_event(&event, app.active_pane, &app.config.keybindings) {
-                            app.update(act);
-                        }
-                    }
-                }
+                handle_input_batch(&mut app, event?, &mut events);
             }
             // Feed update results (from HTTP fetching)
             Some(result) = feed_update_rx.recv() => {
                 app.handle_feed_update(result);
+                app.needs_redraw = true;
             }
             // Database operation results
             Some(db_result) = db_result_rx.recv() => {
                 app.handle_db_result(db_result);
+                app.needs_redraw = true;
+            }
+            // Network results (discovery, images, full articles)
+            Some(net_result) = net_result_rx.recv() => {
+                app.handle_net_result(net_result);
+                app.needs_redraw = true;
             }
             // Render results (HTML to text conversion)
             Some(render_result) = render_rx.recv() => {
                 app.handle_render_result(render_result);
+                app.needs_redraw = true;
             }
             // Periodic refresh tick
             _ = refresh_interval.tick() => {
-                app.start_refresh_all();
+                app.start_auto_refresh();
+                app.needs_redraw = true;
             }
         }
 
         if app.should_quit {
             break;
         }
+
+        // Pick up `:set refresh_every` changes
+        if app.config.refresh_every != refresh_secs {
+            refresh_secs = app.config.refresh_every;
+            refresh_interval = tokio::time::interval(Duration::from_secs(refresh_secs));
+            refresh_interval.tick().await;
+        }
     }
 
-    // 10. Restore the terminal to its original state.
+    // 10. Let pending database writes (e.g. a star toggled just before
+    //     quitting) finish, then restore the terminal to its original state.
+    app.wait_for_db_tasks(Duration::from_secs(2)).await;
     ratatui::restore();
 
     Ok(())
 }
+
+/// Handle `first` and every input event already queued behind it before the
+/// next frame is drawn, so holding a key doesn't back up one frame per
+/// keypress. Runs of plain up/down moves are summed into a single move.
+fn handle_input_batch(app: &mut App, first: event::Event, events: &mut event::EventHandler) {
+    let mut moves = 0;
+    let mut next = Some(first);
+    while let Some(event) = next {
+        // Ticks decide for themselves whether anything changed
+        if !matches!(event, event::Event::Tick) {
+            app.needs_redraw = true;
+        }
+        let act = match event {
+            event::Event::Key(_) | event::Event::Mouse(_) if app.popup.is_none() => {
+                action::handle_event(&event, app.active_pane, &app.config.keybindings, &app.pending_keys)
+            }
+            _ => None,
+        };
+        match act {
+            Some(action::Action::MoveDown) if app.pending_count.is_none() => moves += 1,
+            Some(action::Action::MoveUp) if app.pending_count.is_none() => moves -= 1,
+            act => {
+                app.move_selection(std::mem::take(&mut moves));
+                match act {
+                    Some(act) => app.update(act),
+                    None => handle_event(app, &event),
+                }
+            }
+        }
+        if app.should_quit {
+            return;
+        }
+        next = events.try_next();
+    }
+    app.move_selection(moves);
+}
+
+/// Dispatch one input or tick event to the popup or the app.
+fn handle_event(app: &mut App, event: &event::Event) {
+    match event {
+        event::Event::Key(key) if app.popup.is_some() => {
+            // Handle popup input
+            match key.code {
+                KeyCode::Char(c) if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT => {
+                    app.handle_popup_char(c);
+                }
+                KeyCode::Char('v') if key.modifiers == KeyModifiers::CONTROL => {
+                    app.handle_popup_paste();
+                }
+                KeyCode::Backspace => {
+                    app.handle_popup_backspace();
+                }
+                KeyCode::Delete => {
+                    app.handle_popup_delete();
+                }
+                KeyCode::Left => {
+                    app.handle_popup_left();
+                }
+                KeyCode::Right => {
+                    app.handle_popup_right();
+                }
+                KeyCode::Home => {
+                    app.handle_popup_home();
+                }
+                KeyCode::End => {
+                    app.handle_popup_end();
+                }
+                KeyCode::Tab => {
+                    if key.modifiers.is_empty() {
+                        app.handle_popup_tab();
+                    } else {
+                        // Shift+Tab (BackTab) - some terminals report this way
+                        app.handle_popup_backtab();
+                    }
+                }
+                KeyCode::BackTab => {
+                    // Some terminals report Shift+Tab as BackTab
+                    app.handle_popup_backtab();
+                }
+                KeyCode::Up => {
+                    app.handle_popup_up();
+                }
+                KeyCode::Down => {
+                    app.handle_popup_down();
+                }
+                KeyCode::Enter => {
+                    app.handle_popup_enter();
+                }
+                KeyCode::Esc => {
+                    app.handle_popup_escape();
+                }
+                _ => {}
+            }
+        }
+        event::Event::Tick => {
+            app.handle_tick();
+        }
+        _ => {
+            if let Some(act) = action::handle_event(event, app.active_pane, &app.config.keybindings, &app.pending_keys) {
+                app.update(act);
+            }
+        }
+    }
+}
+
+/// Fetch every feed once without the TUI and print a summary.
+///
+/// Fetches run concurrently as in the TUI; results are stored as they
+/// arrive and the function returns once every fetch has completed.
+async fn refresh_headless(lazyrss: &LazyRss) -> anyhow::Result<()> {
+    let summary = lazyrss.refresh_all().await?;
+    for message in summary.errors.iter().chain(&summary.warnings) {
+        eprintln!("{message}");
+    }
+
+    let RefreshSummary { fetched, new_articles, updated_articles, .. } = summary;
+    if updated_articles > 0 {
+        println!("Fetched {fetched} feeds, {new_articles} new articles, {updated_articles} updated");
+    } else {
+        println!("Fetched {fetched} feeds, {new_articles} new articles");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use notify_rust::Notification;
+
+/// Most feeds to notify about individually after one refresh; beyond this a
+/// single summary notification is sent instead.
+const MAX_FEED_NOTIFICATIONS: usize = 3;
+
+/// New articles found for one feed during a refresh.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedNews {
+    pub feed_title: String,
+    pub count: usize,
+    /// Title of the newest fetched article, shown as the notification body.
+    pub newest_title: Option<String>,
+}
+
+/// Turn a refresh's worth of news into `(summary, body)` notifications.
+///
+/// Each feed gets its own notification, unless there are too many to be
+/// useful, in which case they are collapsed into one.
+pub fn build_notifications(news: &[FeedNews]) -> Vec<(String, String)> {
+    if news.len() <= MAX_FEED_NOTIFICATIONS {
+        return news
+            .iter()
+            .map(|n| {
+                (
+                    format!("{}: {} new article(s)", n.feed_title, n.count),
+                    n.newest_title.clone().unwrap_or_default(),
+                )
+            })
+            .collect();
+    }
+
+    let total: usize = news.iter().map(|n| n.count).sum();
+    let titles: Vec<&str> = news.iter().map(|n| n.feed_title.as_str()).collect();
+    vec![(
+        format!("{} new article(s) in {} feeds", total, news.len()),
+        titles.join(", "),
+    )]
+}
+
+/// Show a desktop notification through the platform's notification service
+/// (D-Bus on Linux and the BSDs).
+///
+/// Blocks until the service accepts it, so call it off the UI thread.
+pub fn send(summary: &str, body: &str) -> notify_rust::error::Result<()> {
+    Notification::new()
+        .appname("lazyrss")
+        .summary(summary)
+        .body(body)
+        .show()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn news(title: &str, count: usize) -> FeedNews {
+        FeedNews {
+            feed_title: title.to_string(),
+            count,
+            newest_title: Some(format!("Latest from {title}")),
+        }
+    }
+
+    #[test]
+    fn one_notification_per_feed() {
+        let notifications = build_notifications(&[news("Rust Blog", 3), news("Go Blog", 1)]);
+        assert_eq!(
+            notifications,
+            vec![
+                ("Rust Blog: 3 new article(s)".to_string(), "Latest from Rust Blog".to_string()),
+                ("Go Blog: 1 new article(s)".to_string(), "Latest from Go Blog".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn many_feeds_collapse_into_a_summary() {
+        let notifications = build_notifications(&[news("A", 1), news("B", 2), news("C", 3), news("D", 4)]);
+        assert_eq!(
+            notifications,
+            vec![("10 new article(s) in 4 feeds".to_string(), "A, B, C, D".to_string())]
+        );
+    }
+}
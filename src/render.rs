@@ -4,7 +4,12 @@
 //! block the TUI. This module provides async rendering using
 //! `tokio::task::spawn_blocking`.
 
+use std::ops::Range;
+
 use tokio::sync::oneshot;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::html::{find_tag, tag_attribute};
 
 /// Request to render article content in the background.
 pub struct RenderRequest {
@@ -72,6 +77,374 @@ fn render_content_blocking(
     header + &body
 }
 
+/// Indentation applied to every line of a code block.
+const CODE_INDENT: &str = "    ";
+
+/// Marks a code block's position in the HTML handed to html2text. Private-use
+/// characters can't clash with article text.
+const CODE_MARKER: char = '\u{E000}';
+
+/// Article HTML converted to plain text.
+pub struct RenderedHtml {
+    /// The rendered text.
+    pub text: String,
+    /// Line ranges of `text` that hold `<pre>` code blocks.
+    pub code_lines: Vec<Range<usize>>,
+    /// Images referenced by the article, in document order.
+    pub images: Vec<ArticleImage>,
+}
+
+/// An `<img>` found in article HTML.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArticleImage {
+    /// Image URL, as written in the `src` attribute.
+    pub src: String,
+    /// Alternative text; empty when the image has none.
+    pub alt: String,
+}
+
+impl ArticleImage {
+    /// Short description used inline and in the "Images:" list.
+    fn label(&self) -> String {
+        if self.alt.is_empty() {
+            self.src.clone()
+        } else {
+            format!("{} \u{2192} {}", self.alt, self.src)
+        }
+    }
+}
+
+/// Convert article HTML to plain text, wrapping at `width` columns.
+///
+/// `<pre>` blocks bypass html2text (which collapses their whitespace) and are
+/// copied verbatim with an indent; their line ranges are returned so the
+/// article view can style them. Images (which html2text drops) become
+/// `[img: alt → src]` placeholders and are listed again at the end.
+pub fn render_html(html: &str, width: usize) -> RenderedHtml {
+    let (html, blocks) = extract_code_blocks(html);
+    let (html, images) = extract_images(&html);
+    let text = html2text::from_read(html.as_bytes(), width);
+
+    let mut out = String::new();
+    let mut code_lines = Vec::new();
+    let mut line_no = 0;
+
+    for line in text.lines() {
+        match code_block_index(line).and_then(|i| blocks.get(i)) {
+            Some(block) => {
+                let start = line_no;
+                for code_line in block.lines() {
+                    out.push_str(CODE_INDENT);
+                    out.push_str(code_line);
+                    out.push('\n');
+                    line_no += 1;
+                }
+                code_lines.push(start..line_no);
+            }
+            None => {
+                // html2text leaves double-escaped references ("&amp;rsquo;")
+                // and some it doesn't know encoded, so decode once more. A
+                // decoded newline would throw off `line_no`, so it and tabs
+                // become spaces
+                out.push_str(&decode_entities(line).replace(['\n', '\t'], " "));
+                out.push('\n');
+                line_no += 1;
+            }
+        }
+    }
+
+    if !images.is_empty() {
+        out.push_str("\nImages:\n");
+        for (i, image) in images.iter().enumerate() {
+            out.push_str(&format!("[{}] {}\n", i + 1, image.label()));
+        }
+    }
+
+    RenderedHtml { text: out, code_lines, images }
+}
+
+/// Replace each `<img>` with an inline text placeholder and return the
+/// rewritten HTML along with the images that have a usable `src`.
+fn extract_images(html: &str) -> (String, Vec<ArticleImage>) {
+    let lower = html.to_ascii_lowercase();
+    let mut out = String::with_capacity(html.len());
+    let mut images = Vec::new();
+    let mut pos = 0;
+
+    while let Some(start) = find_tag(&lower, "<img", pos) {
+        let tag_end = lower[start..].find('>').map_or(lower.len(), |i| start + i);
+        let attrs = &html[start + "<img".len()..tag_end];
+        let attr = |name| tag_attribute(attrs, name).map(|v| decode_entities(v.trim())).unwrap_or_default();
+        let (src, alt) = (attr("src"), attr("alt"));
+        let end = (tag_end + 1).min(html.len());
+
+        out.push_str(&html[pos..start]);
+        // Inline data: URIs are unreadable and can't be opened in a browser
+        if src.is_empty() || src.starts_with("data:") {
+            if !alt.is_empty() {
+                out.push_str(&escape_html(&format!("[img: {alt}]")));
+            }
+        } else {
+            let image = ArticleImage { src, alt };
+            out.push_str(&escape_html(&format!("[img: {}]", image.label())));
+            images.push(image);
+        }
+        pos = end;
+    }
+
+    out.push_str(&html[pos..]);
+    (out, images)
+}
+
+/// Escape text for inclusion in HTML.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Replace each `<pre>...</pre>` region with a placeholder paragraph and
+/// return the rewritten HTML along with each block's verbatim text.
+fn extract_code_blocks(html: &str) -> (String, Vec<String>) {
+    // ASCII lowercasing keeps byte offsets identical to `html`
+    let lower = html.to_ascii_lowercase();
+    let mut out = String::with_capacity(html.len());
+    let mut blocks = Vec::new();
+    let mut pos = 0;
+
+    while let Some(start) = find_tag(&lower, "<pre", pos) {
+        let Some(open_end) = lower[start..].find('>').map(|i| start + i + 1) else {
+            break;
+        };
+        let Some(close) = lower[open_end..].find("</pre").map(|i| open_end + i) else {
+            break;
+        };
+        let close_end = lower[close..].find('>').map_or(lower.len(), |i| close + i + 1);
+
+        let code = decode_entities(&strip_tags(&html[open_end..close]));
+        // A newline straight after <pre> is not part of the content
+        let code = code.strip_prefix('\n').unwrap_or(&code).trim_end().replace('\t', "    ");
+
+        out.push_str(&html[pos..start]);
+        out.push_str(&format!("<p>{CODE_MARKER}{}{CODE_MARKER}</p>", blocks.len()));
+        blocks.push(code);
+        pos = close_end;
+    }
+
+    out.push_str(&html[pos..]);
+    (out, blocks)
+}
+
+/// Index of the code block whose placeholder occupies `line`, if any.
+fn code_block_index(line: &str) -> Option<usize> {
+    let (_, rest) = line.split_once(CODE_MARKER)?;
+    let (index, _) = rest.split_once(CODE_MARKER)?;
+    index.parse().ok()
+}
+
+/// Remove all tags (e.g. syntax-highlighting `<span>`s) from an HTML fragment.
+fn strip_tags(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Decode HTML character references: the XML ones, common typographic
+/// names (curly quotes, dashes, ...) and numeric references. Unknown names
+/// and numeric references to control characters are left as they are.
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let name = &rest[1..end];
+            let c = match name {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                "lsquo" => Some('\u{2018}'),
+                "rsquo" => Some('\u{2019}'),
+                "sbquo" => Some('\u{201A}'),
+                "ldquo" => Some('\u{201C}'),
+                "rdquo" => Some('\u{201D}'),
+                "bdquo" => Some('\u{201E}'),
+                "laquo" => Some('\u{AB}'),
+                "raquo" => Some('\u{BB}'),
+                "ndash" => Some('\u{2013}'),
+                "mdash" => Some('\u{2014}'),
+                "hellip" => Some('\u{2026}'),
+                "bull" => Some('\u{2022}'),
+                "middot" => Some('\u{B7}'),
+                "copy" => Some('\u{A9}'),
+                "reg" => Some('\u{AE}'),
+                "trade" => Some('\u{2122}'),
+                "deg" => Some('\u{B0}'),
+                "times" => Some('\u{D7}'),
+                "euro" => Some('\u{20AC}'),
+                "pound" => Some('\u{A3}'),
+                "thinsp" | "ensp" | "emsp" => Some(' '),
+                _ => name
+                    .strip_prefix("#x")
+                    .or_else(|| name.strip_prefix("#X"))
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| name.strip_prefix('#').map(str::parse))
+                    .and_then(Result::ok)
+                    .and_then(char::from_u32)
+                    .filter(|c| !c.is_control() || matches!(c, '\n' | '\t')),
+            };
+            c.map(|c| (c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Length of the excerpt stored for articles that come without a summary.
+pub const EXCERPT_CHARS: usize = 200;
+
+/// Most input looked at by [`excerpt`]; enough for any sensible excerpt even
+/// when markup dominates, without stripping whole articles every frame.
+const EXCERPT_SCAN_BYTES: usize = 8 * 1024;
+
+/// Plain-text excerpt of an HTML (or plain-text) fragment: tags stripped,
+/// entities decoded and whitespace collapsed. Text wider than `max_width`
+/// columns is cut at a word boundary and ends in an ellipsis.
+pub fn excerpt(html: &str, max_width: usize) -> String {
+    let end = (0..=html.len().min(EXCERPT_SCAN_BYTES))
+        .rev()
+        .find(|&i| html.is_char_boundary(i))
+        .unwrap_or(0);
+    let text = decode_entities(&strip_tags(&html[..end]));
+
+    let mut out = String::new();
+    let mut len = 0;
+    for word in text.split_whitespace() {
+        let word_len = word.width();
+        let space = usize::from(len > 0);
+        if len + space + word_len > max_width.saturating_sub(1) {
+            if len == 0 {
+                // A single overlong word is cut mid-word
+                let mut width = 0;
+                out.extend(word.chars().take_while(|c| {
+                    width += c.width().unwrap_or(0);
+                    width < max_width
+                }));
+            }
+            out.push('\u{2026}');
+            return out;
+        }
+        if space == 1 {
+            out.push(' ');
+        }
+        out.push_str(word);
+        len += space + word_len;
+    }
+    out
+}
+
+/// Guess whether article content is HTML rather than plain text/Markdown.
+///
+/// Any `<` directly followed by a letter (an opening tag) counts as HTML.
+pub fn is_probably_html(content: &str) -> bool {
+    content
+        .as_bytes()
+        .windows(2)
+        .any(|w| w[0] == b'<' && w[1].is_ascii_alphabetic())
+}
+
+/// Render plain-text (or Markdown-ish) content verbatim, wrapping long lines
+/// to `width` columns.
+///
+/// Line breaks and blank lines are kept as-is, so paragraphs survive; wrapped
+/// list items are indented under their text rather than their marker.
+/// Character references are decoded first: feeds escape tag-less summaries
+/// ("Fish &amp; chips") just like HTML.
+pub fn render_plain_text(text: &str, width: usize) -> String {
+    let text = decode_entities(text);
+    let mut out = String::new();
+
+    for line in text.lines() {
+        let line = line.trim_end();
+        if line.chars().count() <= width {
+            out.push_str(line);
+            out.push('\n');
+            continue;
+        }
+
+        let indent = hanging_indent(line);
+        let mut current: String = line.chars().take_while(|c| c.is_whitespace()).collect();
+        let mut current_len = current.chars().count();
+        let mut at_line_start = true;
+
+        for word in line.split_whitespace() {
+            let word_len = word.chars().count();
+            if !at_line_start && current_len + 1 + word_len > width {
+                out.push_str(&current);
+                out.push('\n');
+                current = " ".repeat(indent);
+                current_len = indent;
+                at_line_start = true;
+            }
+            if !at_line_start {
+                current.push(' ');
+                current_len += 1;
+            }
+            current.push_str(word);
+            current_len += word_len;
+            at_line_start = false;
+        }
+
+        out.push_str(&current);
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Column where continuation lines of `line` should start: its leading
+/// whitespace plus any list marker ("- ", "* ", "+ ", "1. ", "1) ").
+fn hanging_indent(line: &str) -> usize {
+    let trimmed = line.trim_start();
+    let leading = line.len() - trimmed.len();
+
+    if ["- ", "* ", "+ "].iter().any(|m| trimmed.starts_with(m)) {
+        return leading + 2;
+    }
+
+    let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+    let rest = &trimmed[digits..];
+    if digits > 0 && (rest.starts_with(". ") || rest.starts_with(") ")) {
+        return leading + digits + 2;
+    }
+
+    leading
+}
+
 /// Convenience function to create a render request and return the receiver.
 ///
 /// Example:
@@ -106,3 +479,157 @@ pub fn render_article(
     });
     rx
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn excerpt_strips_markup_and_truncates() {
+        let html = "<p>Hello &amp;\n  <b>welcome</b> to the   show</p>";
+        assert_eq!(excerpt(html, 200), "Hello & welcome to the show");
+        assert_eq!(excerpt(html, 16), "Hello & welcome\u{2026}");
+        assert_eq!(excerpt("Supercalifragilistic", 6), "Super\u{2026}");
+        assert_eq!(excerpt("日本語のニュース", 6), "日本\u{2026}");
+        assert_eq!(excerpt("<img src=\"x.png\">", 200), "");
+    }
+
+    #[test]
+    fn is_probably_html_detects_tags() {
+        assert!(is_probably_html("<p>Hello</p>"));
+        assert!(is_probably_html("Intro text<br/>more"));
+        assert!(!is_probably_html("Plain text only."));
+        assert!(!is_probably_html("if a < b && b > c"));
+        assert!(!is_probably_html("# Heading\n\n- item one\n- item two"));
+    }
+
+    #[test]
+    fn render_html_keeps_code_blocks_verbatim() {
+        let html = "<p>Before</p><pre><code>fn main() {\n    let x = 1 &lt; 2;\n}</code></pre><p>After</p>";
+        let rendered = render_html(html, 80);
+
+        let lines: Vec<&str> = rendered.text.lines().collect();
+        let code = rendered.code_lines[0].clone();
+        assert_eq!(rendered.code_lines.len(), 1);
+        assert_eq!(
+            &lines[code],
+            &["    fn main() {", "        let x = 1 < 2;", "    }"]
+        );
+        assert!(lines.contains(&"Before"));
+        assert!(lines.contains(&"After"));
+    }
+
+    #[test]
+    fn extract_code_blocks_strips_highlighting() {
+        let (html, blocks) = extract_code_blocks(
+            "<PRE class=\"rust\">\n<span class=\"kw\">let</span>\tx = &#39;a&#x27;;</PRE><pre>b</pre>",
+        );
+        assert_eq!(blocks, vec!["let    x = 'a';".to_string(), "b".to_string()]);
+        assert_eq!(html, "<p>\u{E000}0\u{E000}</p><p>\u{E000}1\u{E000}</p>");
+
+        // <pre> inside other words isn't a code block
+        let (html, blocks) = extract_code_blocks("<preview>x</preview>");
+        assert!(blocks.is_empty());
+        assert_eq!(html, "<preview>x</preview>");
+    }
+
+    #[test]
+    fn render_html_lists_images() {
+        let html = "<p>Look:</p><img src=\"https://example.com/a.png\" alt=\"A cat\"><p><img src='/b.jpg'/></p>";
+        let rendered = render_html(html, 80);
+
+        assert_eq!(
+            rendered.images,
+            vec![
+                ArticleImage { src: "https://example.com/a.png".to_string(), alt: "A cat".to_string() },
+                ArticleImage { src: "/b.jpg".to_string(), alt: String::new() },
+            ]
+        );
+        assert!(rendered.text.contains("[img: A cat \u{2192} https://example.com/a.png]"));
+        assert!(rendered.text.contains("[img: /b.jpg]"));
+        assert!(rendered.text.ends_with(
+            "Images:\n[1] A cat \u{2192} https://example.com/a.png\n[2] /b.jpg\n"
+        ));
+    }
+
+    #[test]
+    fn extract_images_decodes_attributes() {
+        let (_, images) = extract_images("<img data-src=\"x\" SRC=\"a.png?w=1&amp;h=2\" alt='It&#39;s' width=10>");
+        assert_eq!(images, vec![ArticleImage { src: "a.png?w=1&h=2".into(), alt: "It's".into() }]);
+    }
+
+    #[test]
+    fn extract_images_skips_data_uris() {
+        let (html, images) = extract_images("<img src=\"data:image/png;base64,AAAA\" alt=\"dot\"><img>");
+        assert!(images.is_empty());
+        assert_eq!(html, "[img: dot]");
+    }
+
+    #[test]
+    fn decode_entities_handles_unknown_references() {
+        assert_eq!(decode_entities("a &amp;&amp; b &gt; c"), "a && b > c");
+        assert_eq!(decode_entities("&#65;&#x42;"), "AB");
+        assert_eq!(decode_entities("AT&T &bogus; &"), "AT&T &bogus; &");
+        assert_eq!(decode_entities("&#0;&#x1b;[31m"), "&#0;&#x1b;[31m");
+    }
+
+    #[test]
+    fn decode_entities_handles_typographic_names() {
+        assert_eq!(
+            decode_entities("&ldquo;It&rsquo;s&rdquo; &mdash; 1&ndash;2&hellip;"),
+            "\u{201C}It\u{2019}s\u{201D} \u{2014} 1\u{2013}2\u{2026}"
+        );
+    }
+
+    #[test]
+    fn render_html_cleans_up_entity_soup() {
+        let html = "<p>It&#8217;s &rsquo;fine&lsquo; &amp;amp; Tom &amp;rsquo;n&#x2019; Jerry&amp;#8217;s</p>";
+        let rendered = render_html(html, 80);
+        assert_eq!(rendered.text.trim_end(), "It\u{2019}s \u{2019}fine\u{2018} & Tom \u{2019}n\u{2019} Jerry\u{2019}s");
+
+        // Code is decoded exactly once, so escaped markup survives
+        let rendered = render_html("<pre>a &amp;lt; b</pre>", 80);
+        assert!(rendered.text.contains("a &lt; b"));
+    }
+
+    #[test]
+    fn render_html_keeps_encoded_newlines_on_one_line() {
+        let rendered = render_html("<p>one&amp;#10;two&amp;#9;three</p><pre>x = 1</pre>", 80);
+        assert!(rendered.text.starts_with("one two three\n"));
+        // The code block's line range still points at the code
+        let code = rendered.code_lines[0].clone();
+        let lines: Vec<&str> = rendered.text.lines().collect();
+        assert!(lines[code.start].ends_with("x = 1"));
+    }
+
+    #[test]
+    fn render_plain_text_keeps_structure() {
+        let text = "# Title\r\n\r\nFirst paragraph.\n\n- one\n- two\n";
+        assert_eq!(render_plain_text(text, 80), "# Title\n\nFirst paragraph.\n\n- one\n- two\n");
+    }
+
+    #[test]
+    fn render_plain_text_decodes_entities() {
+        assert!(!is_probably_html("Fish &amp; chips &#8211; &quot;cheap&quot;"));
+        assert_eq!(
+            render_plain_text("Fish &amp; chips &#8211; &quot;cheap&quot;", 80),
+            "Fish & chips \u{2013} \"cheap\"\n"
+        );
+    }
+
+    #[test]
+    fn render_plain_text_wraps_with_hanging_indent() {
+        assert_eq!(
+            render_plain_text("one two three four", 9),
+            "one two\nthree\nfour\n"
+        );
+        assert_eq!(
+            render_plain_text("- alpha beta gamma", 12),
+            "- alpha beta\n  gamma\n"
+        );
+        assert_eq!(
+            render_plain_text("  10. alpha beta gamma", 16),
+            "  10. alpha beta\n      gamma\n"
+        );
+    }
+}
@@ -1,7 +1,8 @@
 use ratatui::layout::{Alignment, Rect};
-use ratatui::text::Text;
-use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::text::{Line, Text};
+use ratatui::widgets::{Block, Borders, Padding, Paragraph, Wrap};
 use ratatui::Frame;
+use ratatui_image::{Resize, StatefulImage};
 
 use crate::app::{ActivePane, App};
 use crate::ui::theme;
@@ -10,7 +11,8 @@ use crate::ui::theme;
 ///
 /// When no article is selected the pane shows a placeholder message.
 /// Otherwise it displays the pre-rendered plain-text content with vertical
-/// scrolling support.
+/// scrolling support, below the hero image when one is loaded.  Text wraps
+/// inside the configured `article_padding` margins.
 pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
     let border_style = theme::get_border_style(
         app.active_pane == ActivePane::ArticleView,
@@ -19,11 +21,17 @@ pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
 
     let border_type = theme::get_border_type(&app.config.display.colours);
 
+    // Padding is clamped so at least one column is left for the text
+    let padding = app.config.display.article_padding.min(area.width.saturating_sub(3) / 2);
+
     let block = Block::default()
         .title(" Article ")
         .borders(Borders::ALL)
         .border_style(border_style)
-        .border_type(border_type);
+        .border_type(border_type)
+        .padding(Padding::horizontal(padding));
+
+    app.article_view_height = block.inner(area).height;
 
     if app.article_content.is_empty() {
         let placeholder = Paragraph::new("Select an article to read")
@@ -32,11 +40,57 @@ pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
             .style(theme::META_STYLE);
         frame.render_widget(placeholder, area);
     } else {
-        let text = Text::raw(&app.article_content);
-        let paragraph = Paragraph::new(text)
-            .block(block)
-            .wrap(Wrap { trim: false })
-            .scroll((app.article_scroll, 0));
-        frame.render_widget(paragraph, area);
+        let code_style = theme::get_code_block_style(&app.config.display.colours);
+        let text = Text::from(
+            app.article_content
+                .lines()
+                .enumerate()
+                .map(|(i, line)| {
+                    if app.article_code_lines.iter().any(|r| r.contains(&i)) {
+                        Line::styled(line, code_style)
+                    } else {
+                        Line::raw(line)
+                    }
+                })
+                .collect::<Vec<_>>(),
+        );
+        let mut text_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        // The hero image sits above the text and scrolls away with it
+        if let Some(hero) = app.hero_image.as_mut().filter(|_| app.article_scroll == 0 && text_area.height >= 4) {
+            let hero_area = hero.protocol.size_for(Resize::Fit(None), Rect { height: text_area.height / 2, ..text_area });
+            // Terminal images are drawn above text, so leave the space
+            // blank while a popup covers the screen
+            if app.popup.is_none() {
+                frame.render_stateful_widget(StatefulImage::default(), hero_area, &mut hero.protocol);
+            }
+            text_area.y += hero_area.height + 1;
+            text_area.height -= hero_area.height + 1;
+        }
+
+        let paragraph = Paragraph::new(text).wrap(Wrap { trim: false });
+        // Scrolling counts wrapped rows, so the scroll limit has to as well
+        app.article_content_lines = wrapped_rows(&paragraph, text_area.width);
+        frame.render_widget(paragraph.scroll((app.article_scroll, 0)), text_area);
+    }
+}
+
+/// Rows `paragraph` takes when wrapped to `width` columns, the unit
+/// `Paragraph::scroll` works in.
+fn wrapped_rows(paragraph: &Paragraph, width: u16) -> u16 {
+    u16::try_from(paragraph.line_count(width)).unwrap_or(u16::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapped_rows_counts_lines_longer_than_the_pane() {
+        let text = format!("Title\n\n{}", "word ".repeat(30));
+        let paragraph = Paragraph::new(text).wrap(Wrap { trim: false });
+        assert_eq!(wrapped_rows(&paragraph, 200), 3);
+        assert_eq!(wrapped_rows(&paragraph, 30), 7);
     }
 }
@@ -1,12 +1,17 @@
-use ratatui::layout::Rect;
+use chrono::{DateTime, Utc};
+use ratatui::layout::{Alignment, Rect};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
 use ratatui::Frame;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-use crate::app::{strip_day_leading_zero, to_strftime_format, ActivePane, App};
+use crate::action::format_bindings;
+use crate::app::{format_local_date, to_strftime_format, ActivePane, App};
 use crate::ui::theme;
 
-/// Wrap text to fit within a maximum width, returning a vector of lines.
+/// Wrap text to fit within a maximum display width, returning a vector of
+/// lines. Words wider than a line (such as CJK text, which has no spaces) are
+/// broken between characters.
 fn wrap_text(text: &str, max_width: usize, max_lines: usize) -> Vec<String> {
     if text.is_empty() || max_width == 0 {
         return vec![String::new()];
@@ -14,17 +19,16 @@ fn wrap_text(text: &str, max_width: usize, max_lines: usize) -> Vec<String> {
 
     let mut lines = Vec::new();
     let mut current_line = String::new();
-    let mut line_chars = 0;
+    let mut line_width = 0;
 
     for word in text.split_whitespace() {
-        let word_len = word.chars().count();
+        let word_width = word.width();
 
         // Check if adding this word would exceed the width
-        if line_chars > 0 && line_chars + 1 + word_len > max_width {
+        if line_width > 0 && line_width + 1 + word_width > max_width {
             // Start a new line
-            lines.push(current_line);
-            current_line = String::new();
-            line_chars = 0;
+            lines.push(std::mem::take(&mut current_line));
+            line_width = 0;
 
             // Stop if we've reached max lines
             if lines.len() >= max_lines {
@@ -33,12 +37,25 @@ fn wrap_text(text: &str, max_width: usize, max_lines: usize) -> Vec<String> {
         }
 
         // Add the word to the current line
-        if line_chars > 0 {
+        if line_width > 0 {
             current_line.push(' ');
-            line_chars += 1;
+            line_width += 1;
+        }
+        for c in word.chars() {
+            let char_width = c.width().unwrap_or(0);
+            if line_width > 0 && line_width + char_width > max_width {
+                lines.push(std::mem::take(&mut current_line));
+                line_width = 0;
+                if lines.len() >= max_lines {
+                    break;
+                }
+            }
+            current_line.push(c);
+            line_width += char_width;
+        }
+        if lines.len() >= max_lines {
+            break;
         }
-        current_line.push_str(word);
-        line_chars += word_len;
     }
 
     // Add the last line if it has content, but check max_lines limit
@@ -49,19 +66,179 @@ fn wrap_text(text: &str, max_width: usize, max_lines: usize) -> Vec<String> {
     // If we got no lines but have text, return at least one line
     if lines.is_empty() && !text.is_empty() {
         // Truncate to fit max_width
-        let truncated: String = text.chars().take(max_width).collect();
+        let mut width = 0;
+        let truncated: String = text
+            .chars()
+            .take_while(|c| {
+                width += c.width().unwrap_or(0);
+                width <= max_width
+            })
+            .collect();
         lines.push(truncated);
     }
 
     lines
 }
 
+/// Format `published` relative to `now` ("just now", "15m", "3h", "2d", "3w").
+///
+/// Returns `None` for dates more than 30 days old (or in the future beyond a
+/// small clock skew), which should fall back to the absolute date format.
+fn relative_date(published: DateTime<Utc>, now: DateTime<Utc>) -> Option<String> {
+    let delta = now.signed_duration_since(published);
+
+    // Allow a little clock skew between feed servers and us
+    if delta.num_minutes() < -5 || delta.num_days() > 30 {
+        return None;
+    }
+
+    let label = if delta.num_minutes() < 1 {
+        "just now".to_string()
+    } else if delta.num_hours() < 1 {
+        format!("{}m", delta.num_minutes())
+    } else if delta.num_days() < 1 {
+        format!("{}h", delta.num_hours())
+    } else if delta.num_weeks() < 1 {
+        format!("{}d", delta.num_days())
+    } else {
+        format!("{}w", delta.num_weeks())
+    };
+
+    Some(label)
+}
+
+/// A single piece of the configurable article row format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RowToken {
+    /// Text copied verbatim (including unknown `{...}` tokens).
+    Literal(String),
+    /// `{unread}` - read/unread dot.
+    Unread,
+    /// `{star}` - star glyph for starred articles, empty otherwise.
+    Star,
+    /// `{queue}` - timer glyph for articles queued to read later, empty
+    /// otherwise.
+    Queue,
+    /// `{date}` - publication date in the list date format.
+    Date,
+    /// `{feed}` - title of the article's feed.
+    Feed,
+    /// `{title}` - article title, wrapped over `title_lines` lines.
+    Title,
+}
+
+/// Parse `display.article_row_format` into a list of tokens.
+///
+/// Unknown tokens (and unterminated braces) are kept as literal text.
+fn parse_row_format(format: &str) -> Vec<RowToken> {
+    let mut tokens = Vec::new();
+    let mut literal = String::new();
+    let mut rest = format;
+
+    while let Some(open) = rest.find('{') {
+        literal.push_str(&rest[..open]);
+        let after = &rest[open..];
+        let Some(close) = after.find('}') else {
+            literal.push_str(after);
+            rest = "";
+            break;
+        };
+
+        let token = match &after[1..close] {
+            "unread" => Some(RowToken::Unread),
+            "star" => Some(RowToken::Star),
+            "queue" => Some(RowToken::Queue),
+            "date" => Some(RowToken::Date),
+            "feed" => Some(RowToken::Feed),
+            "title" => Some(RowToken::Title),
+            _ => None,
+        };
+
+        match token {
+            Some(token) => {
+                if !literal.is_empty() {
+                    tokens.push(RowToken::Literal(std::mem::take(&mut literal)));
+                }
+                tokens.push(token);
+            }
+            None => literal.push_str(&after[..=close]),
+        }
+        rest = &after[close + 1..];
+    }
+
+    literal.push_str(rest);
+    if !literal.is_empty() {
+        tokens.push(RowToken::Literal(literal));
+    }
+
+    tokens
+}
+
+/// Total display width of a list of spans.
+fn spans_width(spans: &[Span]) -> usize {
+    spans.iter().map(|s| s.content.width()).sum()
+}
+
+/// First-run hint pointing at the (possibly rebound) add-feed/group keys.
+fn onboarding_hint(kb: &crate::config::KeyBindings) -> String {
+    let keys: Vec<String> = [(&kb.global.create_feed, "add a feed"), (&kb.global.create_group, "add a group")]
+        .into_iter()
+        .filter(|(bindings, _)| !bindings.is_empty())
+        .map(|(bindings, what)| format!("{} to {what}", format_bindings(bindings)))
+        .collect();
+    if keys.is_empty() {
+        "No feeds yet. Add some to the config file.".to_string()
+    } else {
+        format!("No feeds yet. Press {}.", keys.join(" or "))
+    }
+}
+
+/// Render the articles pane as an onboarding screen when no feeds or groups
+/// are configured yet.
+pub fn render_onboarding(frame: &mut Frame, app: &App, area: Rect) {
+    let border_style = theme::get_border_style(
+        app.active_pane == ActivePane::Articles,
+        &app.config.display.colours,
+    );
+
+    let border_type = theme::get_border_type(&app.config.display.colours);
+
+    let block = Block::default()
+        .title(" Articles ")
+        .borders(Borders::ALL)
+        .border_style(border_style)
+        .border_type(border_type);
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    // Vertically centre the (wrapped) hint inside the pane
+    let hint = onboarding_hint(&app.config.keybindings);
+    let width = inner.width.max(1) as usize;
+    let height = (hint.width().div_ceil(width) as u16).min(inner.height);
+    let hint_area = Rect {
+        y: inner.y + (inner.height - height) / 2,
+        height,
+        ..inner
+    };
+
+    let paragraph = Paragraph::new(hint)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .style(theme::META_STYLE);
+    frame.render_widget(paragraph, hint_area);
+}
+
 /// Render the middle articles pane.
 ///
 /// Displays a list of articles for the currently selected feed.  Each entry
-/// is two lines:
-/// - Line 1: read/unread dot, optional star, and article title
-/// - Line 2: author (if available) and right-aligned publication date
+/// is laid out according to `display.article_row_format`:
+/// - Title line(s): relative number followed by the formatted row, with the
+///   `{title}` token wrapped over up to `title_lines` lines
+/// - Preview line: dimmed one-line excerpt, if `show_preview` is set
+/// - Date line: right-aligned publication date (unless `{date}` is used in
+///   the row format)
+/// - Separator line
 pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
     let border_style = theme::get_border_style(
         app.active_pane == ActivePane::Articles,
@@ -71,129 +248,213 @@ pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
     let border_type = theme::get_border_type(&app.config.display.colours);
 
     let block = Block::default()
-        .title(" Articles ")
+        .title(format!(" {} ", app.current_source_label()))
         .borders(Borders::ALL)
         .border_style(border_style)
         .border_type(border_type);
 
+    app.articles_pane_height = block.inner(area).height;
+
     // Inner width after borders (2 columns for left+right border).
-    let inner_width = area.width.saturating_sub(2) as usize;
+    let mut inner_width = area.width.saturating_sub(2) as usize;
+    if app.no_color {
+        // Leave room for the selection marker
+        inner_width = inner_width.saturating_sub(theme::NO_COLOR_MARKER.len());
+    }
 
     // Get date format from config
-    let (date_format, strip_day_zero) = to_strftime_format(&app.config.display.format.date);
+    let (date_format, strip_day_zero) = to_strftime_format(&app.config.display.format.date, app.config.display.format.time);
+
+    // Relative dates are computed against a single "now" per frame
+    let relative_dates = app.config.display.format.relative_dates;
+    let now = Utc::now();
 
     // Get title lines config (minimum 1, max as configured)
     let title_lines = app.config.display.format.title_lines.max(1) as usize;
+    let show_preview = app.config.display.show_preview;
+
+    // Parse the row layout once per frame
+    let row_format = parse_row_format(&app.config.display.article_row_format);
+    let inline_date = row_format.contains(&RowToken::Date);
+
+    // Title, preview, date and separator lines, for half-page scrolling
+    app.articles_row_height = (title_lines + usize::from(show_preview) + usize::from(!inline_date) + 1) as u16;
 
     // Get the currently selected article index for relative numbering
     let selected_idx = app.articles_state.selected().unwrap_or(0);
 
+    let unread_style = theme::get_unread_indicator_style(&app.config.display.colours);
+    let read_style = theme::get_read_style(&app.config.display.colours);
+    let glyphs = &app.config.display.glyphs;
+
     let items: Vec<ListItem> = app
         .articles
         .iter()
         .enumerate()
         .map(|(idx, article)| {
             let base_style = if article.is_read {
-                theme::READ_STYLE
+                read_style
             } else {
                 theme::UNREAD_STYLE
             };
 
             // Relative article number (vim-style: distance from selected article)
-            let article_num = if idx == selected_idx {
+            let article_num = if app.opened_article_id == Some(article.id) {
+                // Article shown in the article view
+                Span::styled("\u{25B6} ", unread_style)
+            } else if idx == selected_idx {
                 // Selected article - show indicator
-                Span::styled("> ", theme::get_unread_indicator_style(&app.config.display.colours))
+                Span::styled("> ", unread_style)
             } else {
                 // Calculate relative distance
                 let relative = (idx as i32 - selected_idx as i32).unsigned_abs();
                 Span::styled(format!("{} ", relative), theme::META_STYLE)
             };
 
-            // Read/unread dot.
-            let unread_style = theme::get_unread_indicator_style(&app.config.display.colours);
-            let dot = if article.is_read {
-                Span::styled("\u{25CB} ", theme::READ_STYLE)
-            } else {
-                Span::styled("\u{25CF} ", unread_style)
-            };
+            // Format date using config
+            let date_str = article.published.map(|dt| {
+                relative_dates
+                    .then(|| relative_date(dt, now))
+                    .flatten()
+                    .unwrap_or_else(|| format_local_date(dt, &date_format, strip_day_zero))
+            }).unwrap_or_default();
 
-            // Star indicator.
-            let star = if article.is_starred {
-                Span::styled("\u{2605} ", theme::STAR_STYLE)
-            } else {
-                Span::raw("")
-            };
+            // === Row tokens -> spans before and after the title ===
+            let mut before_title: Vec<Span> = Vec::new();
+            let mut after_title: Vec<Span> = Vec::new();
+            let mut seen_title = false;
+            // A token that renders empty swallows the following space so that
+            // e.g. "{unread} {star} {title}" doesn't leave a gap when unstarred.
+            let mut skip_space = false;
+
+            for token in &row_format {
+                let span = match token {
+                    RowToken::Title => {
+                        seen_title = true;
+                        skip_space = false;
+                        continue;
+                    }
+                    RowToken::Literal(text) => {
+                        let text = if skip_space {
+                            text.strip_prefix(' ').unwrap_or(text)
+                        } else {
+                            text
+                        };
+                        Span::styled(text.to_string(), base_style)
+                    }
+                    RowToken::Unread => {
+                        if article.is_read {
+                            Span::styled(glyphs.read.as_str(), read_style)
+                        } else {
+                            Span::styled(glyphs.unread.as_str(), unread_style)
+                        }
+                    }
+                    RowToken::Star => {
+                        if article.is_starred {
+                            Span::styled(glyphs.starred.as_str(), theme::STAR_STYLE)
+                        } else {
+                            Span::raw("")
+                        }
+                    }
+                    RowToken::Queue => {
+                        if article.is_queued {
+                            Span::styled(glyphs.queued.as_str(), theme::QUEUE_STYLE)
+                        } else {
+                            Span::raw("")
+                        }
+                    }
+                    RowToken::Date => Span::styled(date_str.clone(), theme::META_STYLE),
+                    RowToken::Feed => Span::styled(
+                        app.feed_title(article.feed_id).unwrap_or_default().to_string(),
+                        theme::META_STYLE,
+                    ),
+                };
+
+                skip_space = span.content.is_empty();
+                if seen_title {
+                    after_title.push(span);
+                } else {
+                    before_title.push(span);
+                }
+            }
 
             // === Title Lines (wrappable) ===
-            // Budget for title: full width minus article number, dot and star
-            let prefix_len = 2 + 2 + if article.is_starred { 2 } else { 0 };
-            let title_budget = inner_width.saturating_sub(prefix_len);
-
-            // Wrap title to fit within the configured number of lines
-            let title_lines_result = wrap_text(&article.title, title_budget, title_lines);
-
-            // Ensure we have at least 1 line for title
-            let title_lines_vec = if title_lines_result.is_empty() {
-                vec![article.title.clone()]
+            // Budget for title: full width minus article number and the rest of the row
+            let prefix_len = 2 + spans_width(&before_title);
+            let title_budget = inner_width.saturating_sub(prefix_len + spans_width(&after_title));
+
+            let title_lines_vec = if seen_title {
+                // Wrap title to fit within the configured number of lines
+                let wrapped = wrap_text(&article.title, title_budget, title_lines);
+                if wrapped.is_empty() {
+                    vec![article.title.clone()]
+                } else {
+                    wrapped
+                }
             } else {
-                title_lines_result
+                vec![String::new()]
             };
 
-            // Create title line vectors with article_num and dot repeated on first line only
+            // Create title line vectors with the row prefix on the first line only
             let mut all_lines: Vec<Line> = Vec::new();
+            let last_line_idx = title_lines_vec.len() - 1;
 
             for (line_idx, title_line) in title_lines_vec.into_iter().enumerate() {
                 let mut spans = Vec::new();
 
                 if line_idx == 0 {
-                    // First line: article number, dot, star, and title
+                    // First line: article number and everything before the title
                     spans.push(article_num.clone());
-                    spans.push(dot.clone());
-                    spans.push(star.clone());
+                    spans.extend(before_title.iter().cloned());
                 } else {
                     // Subsequent lines: indentation to align with title
-                    spans.push(Span::raw("   ")); // 2 for number, 1 for dot
-                    if article.is_starred {
-                        spans.push(Span::raw("  ")); // extra space for star
-                    }
+                    spans.push(Span::raw(" ".repeat(prefix_len)));
                 }
 
                 if !title_line.is_empty() {
                     spans.push(Span::styled(title_line, base_style));
                 }
 
+                if line_idx == last_line_idx {
+                    spans.extend(after_title.iter().cloned());
+                }
+
                 all_lines.push(Line::from(spans));
             }
 
-            // === Line: Author and Date ===
-            // Format date using config
-            let date_str = article.published.as_ref().map(|dt| {
-                let formatted = dt.format(&date_format).to_string();
-                if strip_day_zero {
-                    strip_day_leading_zero(&formatted)
-                } else {
-                    formatted
-                }
-            }).unwrap_or_default();
-
-            // Metadata line: right-aligned date only
-            let date_len = date_str.len();
-            let date_padding = inner_width.saturating_sub(date_len);
+            // === Line: Preview ===
+            // Always present (if blank) so every row with a short title has
+            // the same height
+            if show_preview && seen_title {
+                let preview_budget = inner_width.saturating_sub(prefix_len);
+                let preview = article.summary.as_deref()
+                    .map(|summary| crate::render::excerpt(summary, preview_budget))
+                    .unwrap_or_default();
+                all_lines.push(Line::from(vec![
+                    Span::raw(" ".repeat(prefix_len)),
+                    Span::styled(preview, theme::META_STYLE),
+                ]));
+            }
 
-            let meta_line = if !date_str.is_empty() {
-                vec![
-                    Span::raw(" ".repeat(date_padding)),
-                    Span::styled(date_str, theme::META_STYLE),
-                ]
-            } else {
-                vec![Span::raw("")]
-            };
+            // === Line: Date ===
+            // Metadata line: right-aligned date only, unless shown inline
+            if !inline_date {
+                let date_len = date_str.width();
+                let date_padding = inner_width.saturating_sub(date_len);
+
+                let meta_line = if !date_str.is_empty() {
+                    vec![
+                        Span::raw(" ".repeat(date_padding)),
+                        Span::styled(date_str, theme::META_STYLE),
+                    ]
+                } else {
+                    vec![Span::raw("")]
+                };
+                all_lines.push(Line::from(meta_line));
+            }
 
             // Separator line
             let separator_line = vec![Span::styled("─".repeat(inner_width.min(80)), theme::META_STYLE)];
-
-            // Add metadata and separator lines
-            all_lines.push(Line::from(meta_line));
             all_lines.push(Line::from(separator_line));
 
             ListItem::new(all_lines)
@@ -202,7 +463,107 @@ pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
 
     let list = List::new(items)
         .block(block)
-        .highlight_style(theme::get_highlight_style(&app.config.display.colours));
+        .highlight_style(theme::get_highlight_style(
+            app.active_pane == ActivePane::Articles,
+            &app.config.display.colours,
+        ));
+    // Without colours the highlight background is lost, so mark the row
+    let list = if app.no_color { list.highlight_symbol(theme::NO_COLOR_MARKER) } else { list };
 
     frame.render_stateful_widget(list, area, &mut app.articles_state);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn onboarding_hint_uses_live_bindings() {
+        let mut kb = crate::config::KeyBindings::default();
+        assert_eq!(
+            onboarding_hint(&kb),
+            "No feeds yet. Press Ctrl+n to add a feed or Ctrl+g to add a group."
+        );
+
+        kb.global.create_feed = vec![crate::config::KeyBinding {
+            code: crossterm::event::KeyCode::Char('a'),
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        }];
+        assert!(onboarding_hint(&kb).contains("Press a to add a feed"));
+
+        kb.global.create_group.clear();
+        assert_eq!(onboarding_hint(&kb), "No feeds yet. Press a to add a feed.");
+    }
+
+    #[test]
+    fn wrap_text_measures_display_width() {
+        assert_eq!(wrap_text("Rust is fun", 7, 2), vec!["Rust is", "fun"]);
+
+        // CJK has no spaces to break at, so it breaks between characters
+        let lines = wrap_text("日本語のニュース記事", 7, 3);
+        assert_eq!(lines, vec!["日本語", "のニュ", "ース記"]);
+        assert!(lines.iter().all(|line| line.width() <= 7));
+
+        assert_eq!(wrap_text("🦀 Rust 🎉 news", 8, 2), vec!["🦀 Rust", "🎉 news"]);
+    }
+
+    #[test]
+    fn relative_date_buckets() {
+        let now = Utc::now();
+        let ago = |d: chrono::Duration| relative_date(now - d, now);
+
+        assert_eq!(ago(chrono::Duration::seconds(30)).as_deref(), Some("just now"));
+        assert_eq!(ago(chrono::Duration::minutes(15)).as_deref(), Some("15m"));
+        assert_eq!(ago(chrono::Duration::hours(3)).as_deref(), Some("3h"));
+        assert_eq!(ago(chrono::Duration::days(2)).as_deref(), Some("2d"));
+        assert_eq!(ago(chrono::Duration::days(21)).as_deref(), Some("3w"));
+        assert_eq!(ago(chrono::Duration::days(45)), None);
+    }
+
+    #[test]
+    fn parse_row_format_default() {
+        assert_eq!(
+            parse_row_format("{unread} {star} {queue} {title}"),
+            vec![
+                RowToken::Unread,
+                RowToken::Literal(" ".to_string()),
+                RowToken::Star,
+                RowToken::Literal(" ".to_string()),
+                RowToken::Queue,
+                RowToken::Literal(" ".to_string()),
+                RowToken::Title,
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_row_format_all_tokens() {
+        assert_eq!(
+            parse_row_format("[{feed}] {date}: {title}"),
+            vec![
+                RowToken::Literal("[".to_string()),
+                RowToken::Feed,
+                RowToken::Literal("] ".to_string()),
+                RowToken::Date,
+                RowToken::Literal(": ".to_string()),
+                RowToken::Title,
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_row_format_unknown_token_is_literal() {
+        assert_eq!(
+            parse_row_format("{author} {title}"),
+            vec![RowToken::Literal("{author} ".to_string()), RowToken::Title]
+        );
+    }
+
+    #[test]
+    fn parse_row_format_unterminated_brace_is_literal() {
+        assert_eq!(
+            parse_row_format("{title} {oops"),
+            vec![RowToken::Title, RowToken::Literal(" {oops".to_string())]
+        );
+    }
+}
@@ -1,8 +1,10 @@
+use chrono::{DateTime, Utc};
 use ratatui::layout::Rect;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, List, ListItem};
 use ratatui::Frame;
 use ratatui::style::{Color, Style};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::app::{ActivePane, App, FeedListItem};
 use crate::ClipboardItem;
@@ -25,19 +27,110 @@ fn is_item_cut(app: &App, item: &FeedListItem) -> bool {
         FeedListItem::Feed { feed, .. } => {
             match clipboard {
                 ClipboardItem::Feed { feed_source, .. } => {
-                    feed_source.feed.as_deref() == Some(&feed.url)
+                    feed_source.feed_url() == feed.url
                 }
                 _ => false,
             }
         }
-        FeedListItem::All { .. } => false,
+        FeedListItem::All { .. } | FeedListItem::Starred { .. } | FeedListItem::Queue { .. } | FeedListItem::Recent { .. } => false,
+    }
+}
+
+/// Shorten `title` to at most `budget` characters, ending in an ellipsis
+/// when anything was cut off.
+fn truncate_title(title: &str, budget: usize) -> String {
+    if title.width() <= budget {
+        return title.to_string();
+    }
+    if budget == 0 {
+        return String::new();
+    }
+    // Leave a column for the ellipsis; a wide character that would straddle
+    // it is dropped, leaving the title a column short
+    let mut truncated = String::new();
+    let mut width = 0;
+    for c in title.chars() {
+        width += c.width().unwrap_or(0);
+        if width > budget - 1 {
+            break;
+        }
+        truncated.push(c);
+    }
+    truncated.push('\u{2026}');
+    truncated
+}
+
+/// Format how long ago a feed was fetched: "now", "5m", "3h", "2d".
+fn fetched_age(fetched: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let delta = now.signed_duration_since(fetched);
+    if delta.num_minutes() < 1 {
+        "now".to_string()
+    } else if delta.num_hours() < 1 {
+        format!("{}m", delta.num_minutes())
+    } else if delta.num_days() < 1 {
+        format!("{}h", delta.num_hours())
+    } else {
+        format!("{}d", delta.num_days())
+    }
+}
+
+/// Format a row's count as "(unread)", or "(unread/total)" when the total
+/// is shown.
+fn count_label(unread_count: u32, total_count: Option<u32>) -> String {
+    match total_count {
+        Some(total) => format!("({}/{})", unread_count, total),
+        None => format!("({})", unread_count),
+    }
+}
+
+/// Build a feeds-pane row with the count label right-aligned to `width`.
+///
+/// The title is truncated first, so the count (and the optional `detail`
+/// shown after the title) stays visible even when the pane is too narrow for
+/// the full title.
+fn build_row<'a>(
+    lead: String,
+    title: &str,
+    title_style: Style,
+    detail: Option<&str>,
+    count: String,
+    unread_style: Style,
+    width: usize,
+) -> Line<'a> {
+    let detail = detail.map(|d| format!(" \u{b7} {}", d)).unwrap_or_default();
+    let lead_width = lead.width();
+    let detail_width = detail.width();
+    let budget = width.saturating_sub(lead_width + detail_width + 1 + count.width());
+    let title = truncate_title(title, budget);
+    let padding = width
+        .saturating_sub(lead_width + title.width() + detail_width + count.width())
+        .max(1);
+
+    Line::from(vec![
+        Span::styled(lead, title_style),
+        Span::styled(title, title_style),
+        Span::styled(detail, title_style),
+        Span::raw(" ".repeat(padding)),
+        Span::styled(count, unread_style),
+    ])
+}
+
+/// Border title with the number of feeds and groups, e.g. " Feeds (42 in 9
+/// groups) ", so feeds that went missing are easy to notice.
+fn border_title(feed_count: usize, group_count: usize) -> String {
+    match (feed_count, group_count) {
+        (0, 0) => " Feeds ".to_string(),
+        (feeds, 0) => format!(" Feeds ({}) ", feeds),
+        (feeds, 1) => format!(" Feeds ({} in 1 group) ", feeds),
+        (feeds, groups) => format!(" Feeds ({} in {} groups) ", feeds, groups),
     }
 }
 
 /// Render the left-hand feeds pane.
 ///
 /// Displays a grouped list of feeds.  Group headers show a collapse/expand
-/// indicator; individual feeds show their title and unread count.
+/// indicator; individual feeds show their title and unread count.  Counts are
+/// right-aligned and long titles truncated so the counts always stay visible.
 pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
     let border_style = theme::get_border_style(
         app.active_pane == ActivePane::Feeds,
@@ -46,62 +139,129 @@ pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
 
     let border_type = theme::get_border_type(&app.config.display.colours);
 
+    let (feed_count, group_count) = app.feed_and_group_counts();
     let block = Block::default()
-        .title(" Feeds ")
+        .title(border_title(feed_count, group_count))
         .borders(Borders::ALL)
         .border_style(border_style)
         .border_type(border_type);
 
+    app.feeds_pane_height = block.inner(area).height;
+
     let unread_style = theme::get_unread_indicator_style(&app.config.display.colours);
+    let read_style = theme::get_read_style(&app.config.display.colours);
+    let glyphs = &app.config.display.glyphs;
     let cut_style = Style::default().fg(Color::Red); // Red color for cut items
 
+    // Inner width after borders (2 columns for left+right border).
+    let mut inner_width = area.width.saturating_sub(2) as usize;
+    if app.no_color {
+        // Leave room for the selection marker
+        inner_width = inner_width.saturating_sub(theme::NO_COLOR_MARKER.len());
+    }
+    let selected = app.feeds_state.selected();
+    let now = Utc::now();
+    let show_totals = app.config.display.show_total_counts;
+    let counts = |unread: u32, total: u32| count_label(unread, show_totals.then_some(total));
+
     let items: Vec<ListItem> = app
         .feed_list_items
         .iter()
-        .map(|item| {
+        .enumerate()
+        .map(|(idx, item)| {
             let is_cut = is_item_cut(app, item);
 
             match item {
-                FeedListItem::All { unread_count } => {
-                    let line = Line::from(vec![
-                        Span::styled("All", theme::HEADER_STYLE),
-                        Span::raw(" "),
-                        Span::styled(format!("({})", unread_count), unread_style),
-                    ]);
+                FeedListItem::All { unread_count, total_count } => {
+                    let line = build_row(
+                        String::new(),
+                        "All",
+                        theme::HEADER_STYLE,
+                        None,
+                        counts(*unread_count, *total_count),
+                        unread_style,
+                        inner_width,
+                    );
+                    ListItem::new(line)
+                }
+                FeedListItem::Starred { count } => {
+                    let line = build_row(
+                        String::new(),
+                        &format!("{} Starred", glyphs.starred),
+                        theme::HEADER_STYLE,
+                        None,
+                        count_label(*count, None),
+                        unread_style,
+                        inner_width,
+                    );
+                    ListItem::new(line)
+                }
+                FeedListItem::Queue { count } => {
+                    let line = build_row(
+                        String::new(),
+                        &format!("{} Queue", glyphs.queued),
+                        theme::HEADER_STYLE,
+                        None,
+                        count_label(*count, None),
+                        unread_style,
+                        inner_width,
+                    );
+                    ListItem::new(line)
+                }
+                FeedListItem::Recent { hours } => {
+                    let line = Line::from(Span::styled(
+                        truncate_title(&format!("Recent ({}h)", hours), inner_width),
+                        theme::HEADER_STYLE,
+                    ));
                     ListItem::new(line)
                 }
-                FeedListItem::GroupHeader { title, full_path: _, collapsed, unread_count, depth } => {
+                FeedListItem::GroupHeader { title, full_path: _, collapsed, unread_count, total_count, depth } => {
                     let indent = "  ".repeat(*depth as usize);
-                    let prefix = if *collapsed { "\u{25B6} " } else { "\u{25BC} " };
+                    let prefix = if *collapsed { &glyphs.group_closed } else { &glyphs.group_open };
                     let title_style = if is_cut {
                         cut_style
                     } else {
                         theme::HEADER_STYLE
                     };
                     let cut_indicator = if is_cut { " [cut]" } else { "" };
-                    let line = Line::from(vec![
-                        Span::raw(format!("{}{}", indent, prefix)),
-                        Span::styled(format!("{}{}", title.clone(), cut_indicator), title_style),
-                        Span::raw(" "),
-                        Span::styled(format!("({})", unread_count), unread_style),
-                    ]);
+                    let line = build_row(
+                        format!("{}{} ", indent, prefix),
+                        &format!("{}{}", title, cut_indicator),
+                        title_style,
+                        None,
+                        counts(*unread_count, *total_count),
+                        unread_style,
+                        inner_width,
+                    );
                     ListItem::new(line)
                 }
                 FeedListItem::Feed { feed, depth } => {
-                    let indent = "  ".repeat(*depth as usize);
+                    let mut lead = "  ".repeat(*depth as usize);
+                    if feed.last_error.is_some() {
+                        lead.push_str(&glyphs.error);
+                        lead.push(' ');
+                    }
                     let base_style = if is_cut {
                         cut_style
                     } else if feed.unread_count > 0 {
                         theme::UNREAD_STYLE
                     } else {
-                        theme::READ_STYLE
+                        read_style
                     };
                     let cut_indicator = if is_cut { " [cut]" } else { "" };
-                    let line = Line::from(vec![
-                        Span::styled(format!("{}{}{}", indent, feed.title, cut_indicator), base_style),
-                        Span::raw(" "),
-                        Span::styled(format!("({})", feed.unread_count), unread_style),
-                    ]);
+                    // The selected feed shows how long ago it was fetched
+                    let age = feed.last_fetched
+                        .filter(|_| selected == Some(idx))
+                        .map(|fetched| fetched_age(fetched, now));
+                    let line = build_row(
+                        lead,
+                        &format!("{}{}", feed.title, cut_indicator),
+                        base_style,
+                        age.as_deref(),
+                        counts(feed.unread_count, feed.total_count),
+                        unread_style,
+                        inner_width,
+                    );
                     ListItem::new(line)
                 }
             }
@@ -110,7 +270,98 @@ pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
 
     let list = List::new(items)
         .block(block)
-        .highlight_style(theme::get_highlight_style(&app.config.display.colours));
+        .highlight_style(theme::get_highlight_style(
+            app.active_pane == ActivePane::Feeds,
+            &app.config.display.colours,
+        ));
+    // Without colours the highlight background is lost, so mark the row
+    let list = if app.no_color { list.highlight_symbol(theme::NO_COLOR_MARKER) } else { list };
 
     frame.render_stateful_widget(list, area, &mut app.feeds_state);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn truncate_title_adds_ellipsis() {
+        assert_eq!(truncate_title("Rust Blog", 20), "Rust Blog");
+        assert_eq!(truncate_title("Rust Blog", 9), "Rust Blog");
+        assert_eq!(truncate_title("Rust Blog", 5), "Rust\u{2026}");
+        assert_eq!(truncate_title("Rust Blog", 1), "\u{2026}");
+        assert_eq!(truncate_title("Rust Blog", 0), "");
+    }
+
+    #[test]
+    fn build_row_right_aligns_count() {
+        let line = build_row("  ".to_string(), "Rust Blog", Style::default(), None, count_label(5, None), Style::default(), 20);
+        assert_eq!(row_text(&line), "  Rust Blog      (5)");
+        assert_eq!(row_text(&line).chars().count(), 20);
+    }
+
+    #[test]
+    fn build_row_keeps_count_when_narrow() {
+        let line = build_row("  ".to_string(), "A very long feed title", Style::default(), None, count_label(42, None), Style::default(), 12);
+        assert_eq!(row_text(&line), "  A ve\u{2026} (42)");
+
+        // Too narrow for any title: the count still shows
+        let line = build_row("  ".to_string(), "Rust Blog", Style::default(), None, count_label(42, None), Style::default(), 5);
+        assert_eq!(row_text(&line), "   (42)");
+    }
+
+    #[test]
+    fn build_row_keeps_detail_after_truncated_title() {
+        let line = build_row("".to_string(), "A very long feed title", Style::default(), Some("5m"), count_label(3, None), Style::default(), 16);
+        assert_eq!(row_text(&line), "A very\u{2026} \u{b7} 5m (3)");
+        assert_eq!(row_text(&line).chars().count(), 16);
+    }
+
+    #[test]
+    fn truncate_title_measures_display_width() {
+        // Each CJK character and emoji takes two columns
+        assert_eq!(truncate_title("日本語のブログ", 7), "日本語\u{2026}");
+        assert_eq!(truncate_title("日本語のブログ", 6), "日本\u{2026}");
+        assert_eq!(truncate_title("🦀 Rust", 7), "🦀 Rust");
+        assert_eq!(truncate_title("🦀 Rust", 3), "🦀\u{2026}");
+    }
+
+    #[test]
+    fn build_row_pads_wide_titles_to_width() {
+        for title in ["日本語のブログ", "🦀 Rust 🎉", "Café ☕ 東京"] {
+            for width in [8, 12, 20, 30] {
+                let line = build_row("  ".to_string(), title, Style::default(), None, count_label(5, None), Style::default(), width);
+                assert_eq!(row_text(&line).width(), width, "{title:?} at width {width}");
+                assert!(row_text(&line).ends_with(" (5)"));
+            }
+        }
+    }
+
+    #[test]
+    fn border_title_counts_feeds_and_groups() {
+        assert_eq!(border_title(0, 0), " Feeds ");
+        assert_eq!(border_title(3, 0), " Feeds (3) ");
+        assert_eq!(border_title(3, 1), " Feeds (3 in 1 group) ");
+        assert_eq!(border_title(42, 9), " Feeds (42 in 9 groups) ");
+    }
+
+    #[test]
+    fn count_label_shows_total_when_asked() {
+        assert_eq!(count_label(47, None), "(47)");
+        assert_eq!(count_label(47, Some(320)), "(47/320)");
+    }
+
+    #[test]
+    fn fetched_age_buckets() {
+        let now = Utc::now();
+        let ago = |d: chrono::Duration| fetched_age(now - d, now);
+        assert_eq!(ago(chrono::Duration::seconds(20)), "now");
+        assert_eq!(ago(chrono::Duration::minutes(5)), "5m");
+        assert_eq!(ago(chrono::Duration::hours(3)), "3h");
+        assert_eq!(ago(chrono::Duration::days(2)), "2d");
+    }
+}
@@ -5,39 +5,77 @@ pub mod popup;
 pub mod status_bar;
 pub mod theme;
 
-use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::Frame;
 
-use crate::app::App;
+use crate::app::{ActivePane, App};
+use crate::config::{self, PaneLayout};
+
+/// With `layout: auto`, terminals narrower than this stack the panes.
+const AUTO_LAYOUT_MIN_WIDTH: u16 = 100;
 
 /// Top-level render function.
 ///
 /// Splits the terminal frame into a main content area (fills remaining space)
-/// and a 1-row status bar at the bottom.  The main area is then split
-/// horizontally into three panes (feeds, articles, article view) whose widths
-/// are driven by the percentages in the user's config.
+/// and a 1-row status bar at the bottom.  The main area is then split into
+/// three panes (feeds, articles, article view), side by side or stacked
+/// depending on `display.layout`, whose widths (or heights) are driven by the
+/// percentages in the user's config.  In zen mode the focused article view
+/// takes the whole main area instead.  With no feeds or groups
+/// configured, the articles pane shows an onboarding hint instead.  With
+/// colours disabled, every cell is reset to the terminal defaults afterwards.
 pub fn render(frame: &mut Frame, app: &mut App) {
     let vertical = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Min(0), Constraint::Length(1)])
         .split(frame.area());
 
-    let horizontal = Layout::default()
-        .direction(Direction::Horizontal)
+    if app.zen && app.active_pane == ActivePane::ArticleView {
+        article_pane::render(frame, app, vertical[0]);
+    } else {
+        render_panes(frame, app, vertical[0]);
+    }
+    status_bar::render(frame, app, vertical[1]);
+
+    // Render popup if active
+    if let Some(popup) = app.popup.as_mut() {
+        popup::render_popup(frame, popup);
+    }
+
+    if app.no_color {
+        theme::strip_colours(frame.buffer_mut());
+    }
+}
+
+/// Split `area` into the feeds, articles and article view panes and draw
+/// them.
+fn render_panes(frame: &mut Frame, app: &mut App, area: Rect) {
+    let layout = config::parse_layout(&app.config.display.layout).unwrap_or(PaneLayout::Horizontal);
+    let panes = Layout::default()
+        .direction(pane_direction(layout, area.width))
         .constraints([
             Constraint::Percentage(app.config.display.columns.feeds_list),
             Constraint::Percentage(app.config.display.columns.articles_list),
             Constraint::Percentage(app.config.display.columns.article_view),
         ])
-        .split(vertical[0]);
+        .split(area);
 
-    feeds_pane::render(frame, app, horizontal[0]);
-    articles_pane::render(frame, app, horizontal[1]);
-    article_pane::render(frame, app, horizontal[2]);
-    status_bar::render(frame, app, vertical[1]);
+    feeds_pane::render(frame, app, panes[0]);
+    if app.config.feeds.is_empty() {
+        // Nothing configured yet: tell a new user how to get started
+        articles_pane::render_onboarding(frame, app, panes[1]);
+    } else {
+        articles_pane::render(frame, app, panes[1]);
+    }
+    article_pane::render(frame, app, panes[2]);
+}
 
-    // Render popup if active
-    if let Some(ref popup) = app.popup {
-        popup::render_popup(frame, popup);
+/// Direction the panes are laid out in for a main area `width` columns wide.
+fn pane_direction(layout: PaneLayout, width: u16) -> Direction {
+    match layout {
+        PaneLayout::Horizontal => Direction::Horizontal,
+        PaneLayout::Vertical => Direction::Vertical,
+        PaneLayout::Auto if width < AUTO_LAYOUT_MIN_WIDTH => Direction::Vertical,
+        PaneLayout::Auto => Direction::Horizontal,
     }
 }
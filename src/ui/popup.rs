@@ -1,19 +1,23 @@
 use ratatui::layout::Alignment;
-use ratatui::text::Line;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 use ratatui::Frame;
+use unicode_width::UnicodeWidthChar;
 
 /// Represents an active popup modal
 #[derive(Debug)]
 pub enum Popup {
     /// Create group popup with current input
-    CreateGroup { input: String },
+    CreateGroup { input: String, cursor: usize },
     /// Create feed popup with multi-field input
     CreateFeed {
         title: String,
         url: String,
         feed_url: String,
         selected_field: usize, // 0=title, 1=url, 2=feed_url
+        cursors: [usize; 3],      // Cursor (char index) in each field
+        view_offsets: [usize; 3], // First visible char of each field
     },
     /// Edit feed popup with pre-populated fields
     EditFeed {
@@ -22,12 +26,37 @@ pub enum Popup {
         url: String,
         feed_url: String,
         selected_field: usize, // 0=title, 1=url, 2=feed_url
+        cursors: [usize; 3],      // Cursor (char index) in each field
+        view_offsets: [usize; 3], // First visible char of each field
     },
     /// Edit group popup with pre-populated title
     EditGroup {
         original_path: String,  // Original path to identify the group
         input: String,
+        cursor: usize,
     },
+    /// `:` command line
+    Command { input: String, cursor: usize },
+    /// Pick a group to move a feed into
+    MoveToGroup {
+        feed_url: String,    // URL to identify the feed being moved
+        groups: Vec<String>, // Candidate group paths; "" is the root level
+        filter: String,
+        cursor: usize,
+        selected: usize,     // Index into the filtered list
+    },
+    /// Ask before running an action that can't be undone
+    Confirm {
+        message: String,
+        action: ConfirmAction,
+    },
+}
+
+/// Action run when a confirmation popup is accepted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmAction {
+    /// Mark every article in every feed as read
+    MarkAllReadAll,
 }
 
 impl Popup {
@@ -35,6 +64,7 @@ impl Popup {
     pub fn create_group() -> Self {
         Self::CreateGroup {
             input: String::new(),
+            cursor: 0,
         }
     }
 
@@ -45,17 +75,23 @@ impl Popup {
             url: String::new(),
             feed_url: String::new(),
             selected_field: 0,
+            cursors: [0; 3],
+            view_offsets: [0; 3],
         }
     }
 
     /// Create a new edit_feed popup with pre-populated fields
     pub fn edit_feed(original_url: String, title: String, url: String, feed_url: Option<String>) -> Self {
+        let feed_url = feed_url.unwrap_or_default();
+        let cursors = [title.chars().count(), url.chars().count(), feed_url.chars().count()];
         Self::EditFeed {
             original_url,
             title,
             url,
-            feed_url: feed_url.unwrap_or_default(),
+            feed_url,
             selected_field: 0,
+            cursors,
+            view_offsets: [0; 3],
         }
     }
 
@@ -63,10 +99,35 @@ impl Popup {
     pub fn edit_group(original_path: String, title: String) -> Self {
         Self::EditGroup {
             original_path,
+            cursor: title.chars().count(),
             input: title,
         }
     }
 
+    /// Create a new command popup
+    pub fn command() -> Self {
+        Self::Command {
+            input: String::new(),
+            cursor: 0,
+        }
+    }
+
+    /// Create a new move_to_group popup listing `groups` ("" for the root)
+    pub fn move_to_group(feed_url: String, groups: Vec<String>) -> Self {
+        Self::MoveToGroup {
+            feed_url,
+            groups,
+            filter: String::new(),
+            cursor: 0,
+            selected: 0,
+        }
+    }
+
+    /// Create a new confirmation popup asking `message`
+    pub fn confirmation(message: String, action: ConfirmAction) -> Self {
+        Self::Confirm { message, action }
+    }
+
     /// Get the title for this popup
     pub fn title(&self) -> &str {
         match self {
@@ -74,6 +135,9 @@ impl Popup {
             Popup::CreateFeed { .. } => "Create Feed",
             Popup::EditFeed { .. } => "Edit Feed",
             Popup::EditGroup { .. } => "Edit Group",
+            Popup::Command { .. } => "Command",
+            Popup::MoveToGroup { .. } => "Move to Group",
+            Popup::Confirm { .. } => "Confirm",
         }
     }
 
@@ -82,46 +146,165 @@ impl Popup {
         matches!(self, Popup::EditFeed { .. } | Popup::EditGroup { .. })
     }
 
-    /// Handle a character input event
-    pub fn handle_char(&mut self, c: char) {
+    /// Check if this is a command popup
+    pub fn is_command(&self) -> bool {
+        matches!(self, Popup::Command { .. })
+    }
+
+    /// Check if this is a move-to-group popup
+    pub fn is_move_to_group(&self) -> bool {
+        matches!(self, Popup::MoveToGroup { .. })
+    }
+
+    /// Get the action awaiting confirmation (for Confirm popups)
+    pub fn confirm_action(&self) -> Option<ConfirmAction> {
         match self {
-            Popup::CreateGroup { input } | Popup::EditGroup { input, .. } => {
-                if c != '\n' && c != '\t' && !c.is_control() {
-                    input.push(c);
-                }
-            }
-            Popup::CreateFeed { title, url, feed_url, selected_field }
-            | Popup::EditFeed { title, url, feed_url, selected_field, .. } => {
-                if c != '\n' && c != '\t' && !c.is_control() {
-                    match selected_field {
-                        0 => title.push(c),
-                        1 => url.push(c),
-                        2 => feed_url.push(c),
-                        _ => {}
-                    }
-                }
+            Popup::Confirm { action, .. } => Some(*action),
+            _ => None,
+        }
+    }
+
+    /// The text field being edited and its cursor, if the popup has one
+    fn active_field(&mut self) -> Option<(&mut String, &mut usize)> {
+        match self {
+            Popup::CreateGroup { input, cursor }
+            | Popup::EditGroup { input, cursor, .. }
+            | Popup::Command { input, cursor } => Some((input, cursor)),
+            Popup::MoveToGroup { filter, cursor, .. } => Some((filter, cursor)),
+            Popup::CreateFeed { title, url, feed_url, selected_field, cursors, .. }
+            | Popup::EditFeed { title, url, feed_url, selected_field, cursors, .. } => {
+                let field = match selected_field {
+                    0 => title,
+                    1 => url,
+                    _ => feed_url,
+                };
+                Some((field, &mut cursors[*selected_field]))
             }
+            Popup::Confirm { .. } => None,
+        }
+    }
+
+    /// Handle a character input event, inserting it at the cursor
+    pub fn handle_char(&mut self, c: char) {
+        if c == '\n' || c == '\t' || c.is_control() {
+            return;
+        }
+        if let Some((text, cursor)) = self.active_field() {
+            text.insert(byte_index(text, *cursor), c);
+            *cursor += 1;
+        }
+        if let Popup::MoveToGroup { selected, .. } = self {
+            *selected = 0;
+        }
+    }
+
+    /// Handle pasted text, inserting it at the cursor. Fields hold a single
+    /// line, so line breaks and other control characters are dropped.
+    pub fn handle_paste(&mut self, pasted: &str) {
+        let pasted: String = pasted.chars().filter(|c| !c.is_control()).collect();
+        if let Some((text, cursor)) = self.active_field() {
+            text.insert_str(byte_index(text, *cursor), &pasted);
+            *cursor += pasted.chars().count();
+        }
+        if let Popup::MoveToGroup { selected, .. } = self {
+            *selected = 0;
         }
     }
 
-    /// Handle backspace
+    /// Handle backspace, removing the character before the cursor
     pub fn handle_backspace(&mut self) {
+        if let Some((text, cursor)) = self.active_field()
+            && *cursor > 0
+        {
+            *cursor -= 1;
+            text.remove(byte_index(text, *cursor));
+        }
+        if let Popup::MoveToGroup { selected, .. } = self {
+            *selected = 0;
+        }
+    }
+
+    /// Handle delete, removing the character under the cursor
+    pub fn handle_delete(&mut self) {
+        if let Some((text, cursor)) = self.active_field()
+            && *cursor < text.chars().count()
+        {
+            text.remove(byte_index(text, *cursor));
+        }
+        if let Popup::MoveToGroup { selected, .. } = self {
+            *selected = 0;
+        }
+    }
+
+    /// Move the cursor one character left
+    pub fn handle_left(&mut self) {
+        if let Some((_, cursor)) = self.active_field() {
+            *cursor = cursor.saturating_sub(1);
+        }
+    }
+
+    /// Move the cursor one character right
+    pub fn handle_right(&mut self) {
+        if let Some((text, cursor)) = self.active_field() {
+            *cursor = (*cursor + 1).min(text.chars().count());
+        }
+    }
+
+    /// Move the cursor to the start of the field
+    pub fn handle_home(&mut self) {
+        if let Some((_, cursor)) = self.active_field() {
+            *cursor = 0;
+        }
+    }
+
+    /// Move the cursor to the end of the field
+    pub fn handle_end(&mut self) {
+        if let Some((text, cursor)) = self.active_field() {
+            *cursor = text.chars().count();
+        }
+    }
+
+    /// Move the selection up (for list popups)
+    pub fn handle_up(&mut self) {
+        if let Popup::MoveToGroup { selected, .. } = self {
+            *selected = selected.saturating_sub(1);
+        }
+    }
+
+    /// Move the selection down (for list popups)
+    pub fn handle_down(&mut self) {
+        let len = self.list_items().map_or(0, |items| items.len());
+        if let Popup::MoveToGroup { selected, .. } = self {
+            *selected = (*selected + 1).min(len.saturating_sub(1));
+        }
+    }
+
+    /// Get the entries shown by list popups, narrowed by the typed filter
+    pub fn list_items(&self) -> Option<Vec<&str>> {
         match self {
-            Popup::CreateGroup { input } | Popup::EditGroup { input, .. } => {
-                input.pop();
-            }
-            Popup::CreateFeed { title, url, feed_url, selected_field }
-            | Popup::EditFeed { title, url, feed_url, selected_field, .. } => {
-                match selected_field {
-                    0 => { title.pop(); }
-                    1 => { url.pop(); }
-                    2 => { feed_url.pop(); }
-                    _ => {}
-                }
+            Popup::MoveToGroup { groups, filter, .. } => {
+                let filter = filter.to_lowercase();
+                Some(groups
+                    .iter()
+                    .map(|group| group_label(group))
+                    .filter(|label| label.to_lowercase().contains(&filter))
+                    .collect())
             }
+            _ => None,
         }
     }
 
+    /// Get the selected feed URL and target group for a move-to-group popup.
+    /// The group is `None` for the root level.
+    pub fn move_selection(&self) -> Option<(&str, Option<&str>)> {
+        let Popup::MoveToGroup { feed_url, selected, .. } = self else {
+            return None;
+        };
+        let label = *self.list_items()?.get(*selected)?;
+        let group = (label != ROOT_LABEL).then_some(label);
+        Some((feed_url, group))
+    }
+
     /// Handle tab to switch between fields (for multi-field popups)
     pub fn handle_tab(&mut self) {
         if let Popup::CreateFeed { selected_field, .. } | Popup::EditFeed { selected_field, .. } = self {
@@ -143,8 +326,9 @@ impl Popup {
     /// Get the current input value (for single-field popups)
     pub fn input(&self) -> &str {
         match self {
-            Popup::CreateGroup { input } | Popup::EditGroup { input, .. } => input,
-            Popup::CreateFeed { .. } | Popup::EditFeed { .. } => "",
+            Popup::CreateGroup { input, .. } | Popup::EditGroup { input, .. } | Popup::Command { input, .. } => input,
+            Popup::MoveToGroup { filter, .. } => filter,
+            Popup::CreateFeed { .. } | Popup::EditFeed { .. } | Popup::Confirm { .. } => "",
         }
     }
 
@@ -169,9 +353,11 @@ impl Popup {
     /// For CreateFeed/EditFeed, feed_url is None if empty, otherwise Some(trimmed value)
     pub fn confirm(self) -> (String, String, Option<String>, Option<String>) {
         match self {
-            Popup::CreateGroup { input } | Popup::EditGroup { input, .. } => {
+            Popup::CreateGroup { input, .. } | Popup::EditGroup { input, .. } | Popup::Command { input, .. } => {
                 (input, String::new(), None, None)
             }
+            Popup::MoveToGroup { filter, .. } => (filter, String::new(), None, None),
+            Popup::Confirm { message, .. } => (message, String::new(), None, None),
             Popup::CreateFeed { title, url, feed_url, .. } => {
                 let feed = if feed_url.trim().is_empty() {
                     None
@@ -220,6 +406,40 @@ impl Popup {
         }
     }
 
+    /// Get the visible text of each field of a multi-field popup whose
+    /// fields are `width` columns wide, with the cursor's column in it.
+    /// Each field scrolls sideways to keep its cursor in view.
+    pub fn field_views(&mut self, width: usize) -> Option<Vec<(String, usize)>> {
+        let (Popup::CreateFeed { title, url, feed_url, cursors, view_offsets, .. }
+        | Popup::EditFeed { title, url, feed_url, cursors, view_offsets, .. }) = self
+        else {
+            return None;
+        };
+        let views = [title, url, feed_url]
+            .into_iter()
+            .zip(cursors.iter())
+            .zip(view_offsets.iter_mut())
+            .map(|((value, &cursor), offset)| {
+                let (view, new_offset) = field_window(value, cursor, *offset, width);
+                *offset = new_offset;
+                (view, cursor - new_offset)
+            })
+            .collect();
+        Some(views)
+    }
+
+    /// Get the cursor position (a char index) in the current input of a
+    /// single-field or list popup
+    pub fn input_cursor(&self) -> usize {
+        match self {
+            Popup::CreateGroup { cursor, .. }
+            | Popup::EditGroup { cursor, .. }
+            | Popup::Command { cursor, .. }
+            | Popup::MoveToGroup { cursor, .. } => *cursor,
+            _ => 0,
+        }
+    }
+
     /// Get currently selected field index for multi-field popups
     pub fn selected_field(&self) -> Option<usize> {
         match self {
@@ -229,14 +449,89 @@ impl Popup {
     }
 }
 
+/// Label shown for the root level in the move-to-group list
+const ROOT_LABEL: &str = "(root)";
+
+/// Display label for a group path, where "" is the root level
+fn group_label(group: &str) -> &str {
+    if group.is_empty() { ROOT_LABEL } else { group }
+}
+
+/// Byte index of the char at `cursor` in `text`, or its length past the end
+fn byte_index(text: &str, cursor: usize) -> usize {
+    text.char_indices().nth(cursor).map_or(text.len(), |(i, _)| i)
+}
+
+/// `text` with a block cursor before char `cursor`: the char under it is
+/// shown reversed, or a `█` is added when the cursor is at the end
+fn with_cursor(text: &str, cursor: usize) -> Vec<Span<'static>> {
+    let (before, rest) = text.split_at(byte_index(text, cursor));
+    let mut rest = rest.chars();
+    let under = match rest.next() {
+        Some(c) => Span::styled(c.to_string(), Style::default().add_modifier(Modifier::REVERSED)),
+        None => Span::raw("█"),
+    };
+    vec![Span::raw(before.to_string()), under, Span::raw(rest.as_str().to_string())]
+}
+
+/// Slice of a one-line text field `width` columns wide, showing the chars
+/// from `offset` on. The cell after the text counts too, as the cursor can
+/// sit there. Widths are display columns, so wide (CJK, emoji) chars take
+/// two cells.
+///
+/// The offset is first adjusted so the cursor (a char index) is visible and
+/// not under a `…` marking text cut off at either edge. Returns the visible
+/// text and the adjusted offset.
+fn field_window(value: &str, cursor: usize, offset: usize, width: usize) -> (String, usize) {
+    let widths: Vec<usize> = value.chars().map(|c| c.width().unwrap_or(0)).collect();
+    let len = widths.len();
+    let width = width.max(3);
+
+    // Columns taken by the chars from `from` on, plus the cell after them
+    let tail_width = |from: usize| widths[from..].iter().sum::<usize>() + 1;
+    // Index of the first char, from `from` on, that doesn't fit
+    let end = |from: usize| {
+        let mut used = 0;
+        from + widths[from..].iter().take_while(|&&w| { used += w; used <= width }).count()
+    };
+    let shows_cursor = |from: usize| {
+        let end = end(from);
+        let after_left_marker = from == 0 || cursor > from;
+        let before_right_marker = if end < len { cursor + 1 < end } else { cursor < len || tail_width(from) <= width };
+        after_left_marker && before_right_marker
+    };
+
+    // Don't leave empty cells on the right while text is hidden on the left
+    let mut offset = offset.min(len);
+    while offset > 0 && tail_width(offset - 1) <= width {
+        offset -= 1;
+    }
+    if cursor <= offset {
+        offset = cursor.saturating_sub(1);
+    }
+    while offset < cursor && !shows_cursor(offset) {
+        offset += 1;
+    }
+
+    let end = end(offset);
+    let mut view: Vec<char> = value.chars().skip(offset).take(end - offset).collect();
+    if offset > 0 && !view.is_empty() {
+        view[0] = '…';
+    }
+    if end < len && let Some(last) = view.last_mut() {
+        *last = '…';
+    }
+    (view.into_iter().collect(), offset)
+}
+
 /// Render a popup modal centered on screen
-pub fn render_popup(frame: &mut Frame, popup: &Popup) {
+pub fn render_popup(frame: &mut Frame, popup: &mut Popup) {
     let area = frame.area();
 
     // Calculate popup size (max 60 chars wide, 15 rows tall for multi-field)
-    let is_multi_field = popup.field_names().is_some();
+    let is_tall = popup.field_names().is_some() || popup.list_items().is_some();
     let width = area.width.min(60);
-    let height = area.height.min(if is_multi_field { 15 } else { 10 });
+    let height = area.height.min(if is_tall { 15 } else { 10 });
 
     // Center the popup
     let x = (area.width - width) / 2;
@@ -251,21 +546,30 @@ pub fn render_popup(frame: &mut Frame, popup: &Popup) {
     // Clear the area (dim background effect)
     frame.render_widget(Clear, popup_area);
 
+    // Fields hold one line, after the borders and a 4-column prefix
+    let field_width = (width as usize).saturating_sub(6);
+    let field_views = popup.field_views(field_width);
+    let popup = &*popup;
+
     // Create the popup content
     let title = popup.title();
 
-    let content = if let Some(field_names) = popup.field_names() {
-        // Multi-field popup
-        let field_values = popup.field_values().unwrap();
+    let content = if let Some(field_views) = field_views {
+        // Multi-field popup; long values scroll sideways
+        let field_names = popup.field_names().unwrap();
         let selected = popup.selected_field().unwrap();
 
         let mut lines = vec![Line::from("")];
 
-        for (i, (name, value)) in field_names.iter().zip(field_values.iter()).enumerate() {
-            let marker = if i == selected { ">" } else { " " };
-            let cursor = if i == selected { "█" } else { "" };
-            lines.push(Line::from(format!("{} {}:", marker, name)));
-            lines.push(Line::from(format!("  {} {}{}", cursor, value, cursor)));
+        for (i, (name, (value, cursor))) in field_names.iter().zip(field_views).enumerate() {
+            lines.push(Line::from(format!("{} {}:", if i == selected { ">" } else { " " }, name)));
+            if i == selected {
+                let mut spans = vec![Span::raw("  █ ")];
+                spans.extend(with_cursor(&value, cursor));
+                lines.push(Line::from(spans));
+            } else {
+                lines.push(Line::from(format!("    {}", value)));
+            }
             lines.push(Line::from(""));
         }
 
@@ -281,19 +585,68 @@ pub fn render_popup(frame: &mut Frame, popup: &Popup) {
         ]));
 
         lines
+    } else if let Some(items) = popup.list_items() {
+        // List popup (MoveToGroup): filter line, then a window of entries
+        // that keeps the selection visible
+        let selected = match popup {
+            Popup::MoveToGroup { selected, .. } => *selected,
+            _ => 0,
+        };
+        let visible = (height as usize).saturating_sub(7).max(1);
+        let first = selected.saturating_sub(visible - 1);
+
+        let mut lines = vec![
+            Line::from(""),
+            Line::from([vec![Span::raw("Filter: ")], with_cursor(popup.input(), popup.input_cursor())].concat()),
+            Line::from(""),
+        ];
+        if items.is_empty() {
+            lines.push(Line::from("  No matching groups"));
+        }
+        for (i, item) in items.iter().enumerate().skip(first).take(visible) {
+            let line = Line::from(format!("  {}", item));
+            if i == selected {
+                lines.push(line.style(Style::default().add_modifier(Modifier::REVERSED)));
+            } else {
+                lines.push(line);
+            }
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            "↑/↓".into(),
+            ": Select, ".into(),
+            "Enter".into(),
+            ": Move, ".into(),
+            "Esc".into(),
+            ": Cancel".into(),
+        ]));
+
+        lines
+    } else if let Popup::Confirm { message, .. } = popup {
+        vec![
+            Line::from(""),
+            Line::from(message.as_str()),
+            Line::from(""),
+            Line::from(vec![
+                "y/Enter".into(),
+                ": Yes, ".into(),
+                "n/Esc".into(),
+                ": No".into(),
+            ]),
+        ]
     } else {
-        // Single-field popup (CreateGroup or EditGroup)
+        // Single-field popup (CreateGroup, EditGroup or Command)
         let input = popup.input();
-        let label = if matches!(popup, Popup::EditGroup { .. }) {
-            "New name:"
-        } else {
-            "Group name:"
+        let label = match popup {
+            Popup::EditGroup { .. } => "New name:",
+            Popup::Command { .. } => "Command (refresh, mark-all-read, goto <feed>, set <option>=<value>, quit):",
+            _ => "Group name:",
         };
 
         vec![
             Line::from(""),
             Line::from(label),
-            Line::from(format!("> {}", input)),
+            Line::from([vec![Span::raw("> ")], with_cursor(input, popup.input_cursor())].concat()),
             Line::from(""),
             Line::from(vec![
                 "Enter".into(),
@@ -308,7 +661,7 @@ pub fn render_popup(frame: &mut Frame, popup: &Popup) {
     let block = Block::default()
         .title(format!(" {} ", title))
         .borders(Borders::ALL)
-        .border_style(ratatui::style::Style::default().fg(ratatui::style::Color::Cyan))
+        .border_style(Style::default().fg(Color::Cyan))
         .border_type(ratatui::widgets::BorderType::Rounded);
 
     let paragraph = Paragraph::new(content)
@@ -368,6 +721,43 @@ mod tests {
         assert_eq!(popup.input(), "");
     }
 
+    #[test]
+    fn test_command_popup() {
+        let mut popup = Popup::command();
+        assert_eq!(popup.title(), "Command");
+        assert!(popup.is_command());
+        popup.handle_char('q');
+        assert_eq!(popup.input(), "q");
+        assert_eq!(popup.confirm().0, "q");
+    }
+
+    #[test]
+    fn test_move_to_group_popup() {
+        let groups = vec!["".to_string(), "News".to_string(), "News > World".to_string(), "Tech".to_string()];
+        let mut popup = Popup::move_to_group("https://example.com/feed.xml".to_string(), groups);
+        assert_eq!(popup.title(), "Move to Group");
+        assert_eq!(popup.list_items().unwrap(), vec!["(root)", "News", "News > World", "Tech"]);
+        assert_eq!(popup.move_selection(), Some(("https://example.com/feed.xml", None)));
+
+        popup.handle_down();
+        assert_eq!(popup.move_selection(), Some(("https://example.com/feed.xml", Some("News"))));
+
+        // Filtering is case-insensitive and resets the selection
+        popup.handle_char('w');
+        popup.handle_char('O');
+        assert_eq!(popup.list_items().unwrap(), vec!["News > World"]);
+        popup.handle_down();
+        assert_eq!(popup.move_selection(), Some(("https://example.com/feed.xml", Some("News > World"))));
+
+        popup.handle_char('x');
+        assert_eq!(popup.move_selection(), None);
+        popup.handle_backspace();
+        popup.handle_backspace();
+        popup.handle_backspace();
+        popup.handle_up();
+        assert_eq!(popup.list_items().unwrap().len(), 4);
+    }
+
     // CreateFeed popup tests
     #[test]
     fn test_create_feed_popup_initial_state() {
@@ -641,4 +1031,127 @@ mod tests {
         assert!(!create_feed.is_edit());
         assert!(!create_group.is_edit());
     }
+
+    #[test]
+    fn test_confirm_popup_ignores_typing() {
+        let mut popup = Popup::confirmation("Really?".to_string(), ConfirmAction::MarkAllReadAll);
+        popup.handle_char('x');
+        popup.handle_backspace();
+        assert_eq!(popup.title(), "Confirm");
+        assert_eq!(popup.input(), "");
+        assert_eq!(popup.confirm_action(), Some(ConfirmAction::MarkAllReadAll));
+        assert_eq!(Popup::command().confirm_action(), None);
+    }
+
+    #[test]
+    fn test_field_window_scrolls_to_cursor() {
+        // Short values are shown whole
+        assert_eq!(field_window("abc", 3, 0, 10), ("abc".to_string(), 0));
+        // A cursor at the end of a long value shows its tail
+        assert_eq!(field_window("abcdefghij", 10, 0, 5), ("…hij".to_string(), 6));
+        // Moving the cursor back to the start scrolls left again
+        assert_eq!(field_window("abcdefghij", 0, 6, 5), ("abcd…".to_string(), 0));
+        // The cursor never sits under a truncation marker
+        assert_eq!(field_window("abcdefghij", 6, 6, 5), ("…ghij".to_string(), 5));
+        assert_eq!(field_window("abcdefghij", 4, 0, 5), ("…cde…".to_string(), 1));
+    }
+
+    #[test]
+    fn test_field_window_counts_display_width() {
+        // Each of these chars takes two columns
+        assert_eq!(field_window("日本語のテキスト", 8, 0, 10), ("…キスト".to_string(), 4));
+        assert_eq!(field_window("日本語のテキスト", 0, 4, 10), ("日本語の…".to_string(), 0));
+        assert_eq!(field_window("a🦀b", 3, 0, 10), ("a🦀b".to_string(), 0));
+    }
+
+    #[test]
+    fn test_long_feed_fields_keep_their_view_offsets() {
+        let mut popup = Popup::create_feed();
+        popup.handle_tab();
+        for c in "https://example.com/a/very/long/path".chars() {
+            popup.handle_char(c);
+        }
+        let views = popup.field_views(10).unwrap();
+        assert_eq!(views[1], ("…ong/path".to_string(), 9));
+        assert!(matches!(popup, Popup::CreateFeed { view_offsets: [0, 27, 0], .. }));
+
+        // Home scrolls back to the start of the field
+        popup.handle_home();
+        let views = popup.field_views(10).unwrap();
+        assert_eq!(views[1], ("https://e…".to_string(), 0));
+    }
+
+    #[test]
+    fn test_popup_edits_at_the_cursor() {
+        let mut popup = Popup::edit_group("Tech".to_string(), "Tech".to_string());
+        // The cursor starts at the end of pre-filled text
+        popup.handle_char('s');
+        assert_eq!(popup.input(), "Techs");
+
+        popup.handle_home();
+        popup.handle_right();
+        popup.handle_char('é');
+        assert_eq!(popup.input(), "Téechs");
+        assert_eq!(popup.input_cursor(), 2);
+
+        popup.handle_backspace();
+        popup.handle_delete();
+        assert_eq!(popup.input(), "Tchs");
+
+        // The cursor stays within the text
+        popup.handle_left();
+        popup.handle_left();
+        popup.handle_backspace();
+        assert_eq!(popup.input_cursor(), 0);
+        popup.handle_end();
+        popup.handle_right();
+        popup.handle_delete();
+        assert_eq!(popup.input(), "Tchs");
+        assert_eq!(popup.input_cursor(), 4);
+    }
+
+    #[test]
+    fn test_paste_inserts_one_line_at_the_cursor() {
+        let mut popup = Popup::create_feed();
+        popup.handle_tab();
+        popup.handle_paste("https://example.com/feed\r\n");
+        popup.handle_left();
+        popup.handle_left();
+        popup.handle_left();
+        popup.handle_left();
+        popup.handle_paste("atom\n");
+        assert_eq!(popup.field_values().unwrap()[1], "https://example.com/atomfeed");
+
+        let mut popup = Popup::confirmation("Really?".to_string(), ConfirmAction::MarkAllReadAll);
+        popup.handle_paste("y");
+        assert_eq!(popup.input(), "");
+    }
+
+    #[test]
+    fn test_feed_popup_fields_keep_their_own_cursor() {
+        let mut popup = Popup::edit_feed(
+            "https://a.com".to_string(),
+            "A".to_string(),
+            "https://a.com".to_string(),
+            None,
+        );
+        popup.handle_home();
+        popup.handle_char('_');
+        popup.handle_tab();
+        popup.handle_left();
+        popup.handle_char('/');
+        popup.handle_backtab();
+        popup.handle_char('_');
+
+        let (title, url, ..) = popup.confirm();
+        assert_eq!(title, "__A");
+        assert_eq!(url, "https://a.co/m");
+    }
+
+    #[test]
+    fn test_with_cursor_marks_the_char_under_it() {
+        let text = |spans: Vec<Span>| spans.iter().map(|s| s.content.to_string()).collect::<Vec<_>>();
+        assert_eq!(text(with_cursor("abc", 1)), vec!["a", "b", "c"]);
+        assert_eq!(text(with_cursor("abc", 3)), vec!["abc", "█", ""]);
+    }
 }
@@ -1,32 +1,63 @@
+use chrono::{DateTime, Utc};
 use ratatui::layout::Rect;
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 
 use crate::action;
 use crate::app::{ActivePane, App};
+use crate::config::{KeyBinding, KeyBindings};
 use crate::ui::theme;
 
+/// Braille spinner frames shown while a refresh is in progress.
+const SPINNER_FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
 /// Render the single-row status bar at the bottom of the terminal.
 ///
-/// Shows either a status message (if set), or contextual key-binding hints
-/// for the currently active pane.  A "Refreshing..." prefix is prepended
-/// while a background refresh is in progress.
+/// Shows either a status message (if set), or a summary (total unread, time
+/// since the last refresh, and a spinner while refreshing) followed by
+/// contextual key-binding hints for the currently active pane.
 pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     let content = if let Some(ref msg) = app.status_message {
         format!(" {msg}")
     } else {
-        let hints = build_hints(app);
-        if app.is_refreshing {
-            format!(" Refreshing... \u{2502}{hints}")
-        } else {
-            hints
-        }
+        format!("{} \u{2502}{}", build_summary(app), build_hints(app))
     };
 
     let bar = Paragraph::new(content).style(theme::STATUS_STYLE);
     frame.render_widget(bar, area);
 }
 
+/// Build the persistent summary segment: unread total, last update, spinner.
+fn build_summary(app: &App) -> String {
+    let mut parts = vec![format!(" {} unread", app.total_unread())];
+
+    if app.is_refreshing {
+        let spinner = SPINNER_FRAMES[app.spinner_frame as usize % SPINNER_FRAMES.len()];
+        match app.refresh_progress() {
+            Some((done, total)) => parts.push(format!("{spinner} Refreshing {done}/{total}")),
+            None => parts.push(format!("{spinner} Refreshing...")),
+        }
+    } else if let Some(fetched) = app.last_fetched() {
+        parts.push(format!("updated {}", format_since(fetched, Utc::now())));
+    }
+
+    parts.join(" \u{2502} ")
+}
+
+/// Format the time elapsed since `then` as "just now", "4m ago", "3h ago", "2d ago".
+fn format_since(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let delta = now.signed_duration_since(then);
+    if delta.num_minutes() < 1 {
+        "just now".to_string()
+    } else if delta.num_hours() < 1 {
+        format!("{}m ago", delta.num_minutes())
+    } else if delta.num_days() < 1 {
+        format!("{}h ago", delta.num_hours())
+    } else {
+        format!("{}d ago", delta.num_days())
+    }
+}
+
 /// Build keybinding hints for the currently active pane.
 fn build_hints(app: &App) -> String {
     let kb = &app.config.keybindings;
@@ -37,56 +68,87 @@ fn build_hints(app: &App) -> String {
     }
 }
 
+/// A `[keys] Label` hint, or `None` if the action is unbound.
+fn hint(bindings: &[KeyBinding], label: &str) -> Option<String> {
+    (!bindings.is_empty()).then(|| format!("[{}] {label}", action::format_bindings(bindings)))
+}
+
+/// The `[prev]/[next] Pane` hint, leaving out whichever side is unbound.
+fn pane_hint(kb: &KeyBindings) -> Option<String> {
+    let keys: Vec<String> = [&kb.global.focus_prev, &kb.global.focus_next]
+        .into_iter()
+        .filter(|bindings| !bindings.is_empty())
+        .map(|bindings| format!("[{}]", action::format_bindings(bindings)))
+        .collect();
+    (!keys.is_empty()).then(|| format!("{} Pane", keys.join("/")))
+}
+
 /// Build keybinding hints for the feeds pane.
-fn build_feeds_hints(kb: &crate::config::KeyBindings) -> String {
-    let parts = vec![
-        format!("[{}] Navigate", action::format_bindings(&kb.feeds.move_down)),
-        format!("[{}] Select", kb.feeds.select.display()),
-        format!("[{}] Collapse", kb.feeds.toggle_collapse.display()),
-        format!("[{}] Cut", "x"),
-        format!("[{}] Paste", "p"),
-        format!("[{}] Delete", "D"),
-        format!("[{}] Jump", action::format_bindings(&[kb.global.jump_top.clone(), kb.global.jump_bottom.clone()])),
-        format!("[{}] Page", action::format_bindings(&kb.feeds.scroll_half_page_down)),
-        format!("[{}]/[{}] Pane", action::format_bindings(&kb.global.focus_prev), action::format_bindings(&kb.global.focus_next)),
-        format!("[{}] Refresh", kb.global.refresh_all.display()),
-        format!("[{}] Quit", action::format_bindings(&kb.global.quit)),
+fn build_feeds_hints(kb: &KeyBindings) -> String {
+    let jump = [kb.global.jump_top.as_slice(), &kb.global.jump_bottom].concat();
+    let parts = [
+        hint(&kb.feeds.move_down, "Navigate"),
+        hint(&kb.feeds.select, "Select"),
+        hint(&kb.feeds.toggle_collapse, "Collapse"),
+        Some(format!("[{}] Cut", "x")),
+        Some(format!("[{}] Paste", "p")),
+        Some(format!("[{}] Delete", "D")),
+        hint(&jump, "Jump"),
+        hint(&kb.feeds.scroll_half_page_down, "Page"),
+        pane_hint(kb),
+        hint(&kb.global.refresh_all, "Refresh"),
+        hint(&kb.global.quit, "Quit"),
     ];
-    parts.join(" \u{2502} ")
+    parts.into_iter().flatten().collect::<Vec<_>>().join(" \u{2502} ")
 }
 
 /// Build keybinding hints for the articles pane.
-fn build_articles_hints(kb: &crate::config::KeyBindings) -> String {
-    let parts = vec![
-        format!("[{}] Navigate", action::format_bindings(&kb.articles.move_down)),
-        format!("[{}] Read", kb.articles.select.display()),
-        format!("[{}] Read/Unread", kb.articles.toggle_read.display()),
-        format!("[{}] Star", kb.articles.toggle_star.display()),
-        format!("[{}] Jump", action::format_bindings(&[kb.global.jump_top.clone(), kb.global.jump_bottom.clone()])),
-        format!("[{}] Page", action::format_bindings(&kb.articles.scroll_half_page_down)),
-        format!("[{}] Open", kb.global.open_browser.display()),
-        format!("[{}]/[{}] Pane", action::format_bindings(&kb.global.focus_prev), action::format_bindings(&kb.global.focus_next)),
-        format!("[{}] Quit", action::format_bindings(&kb.global.quit)),
+fn build_articles_hints(kb: &KeyBindings) -> String {
+    let jump = [kb.global.jump_top.as_slice(), &kb.global.jump_bottom].concat();
+    let parts = [
+        hint(&kb.articles.move_down, "Navigate"),
+        hint(&kb.articles.select, "Read"),
+        hint(&kb.articles.toggle_read, "Read/Unread"),
+        hint(&kb.articles.toggle_star, "Star"),
+        hint(&kb.articles.toggle_queue, "Later"),
+        hint(&kb.global.next_unread, "Next unread"),
+        hint(&jump, "Jump"),
+        hint(&kb.articles.scroll_half_page_down, "Page"),
+        hint(&kb.global.open_browser, "Open"),
+        hint(&kb.global.copy_enclosure, "Copy audio"),
+        pane_hint(kb),
+        hint(&kb.global.quit, "Quit"),
     ];
-    parts.join(" \u{2502} ")
+    parts.into_iter().flatten().collect::<Vec<_>>().join(" \u{2502} ")
 }
 
 /// Build keybinding hints for the article view pane.
-fn build_article_view_hints(kb: &crate::config::KeyBindings) -> String {
-    let parts = vec![
-        format!("[{}] Scroll", action::format_bindings(&kb.article_view.scroll_down)),
-        format!("[{}] Page", action::format_bindings(&kb.article_view.scroll_half_page_down)),
-        format!("[{}] Jump", action::format_bindings(&[kb.global.jump_top.clone(), kb.global.jump_bottom.clone()])),
-        format!("[{}] Open", kb.global.open_browser.display()),
-        format!("[{}]/[{}] Pane", action::format_bindings(&kb.global.focus_prev), action::format_bindings(&kb.global.focus_next)),
-        format!("[{}] Quit", action::format_bindings(&kb.global.quit)),
+fn build_article_view_hints(kb: &KeyBindings) -> String {
+    let jump = [kb.global.jump_top.as_slice(), &kb.global.jump_bottom].concat();
+    let parts = [
+        hint(&kb.article_view.scroll_down, "Scroll"),
+        hint(&kb.article_view.scroll_half_page_down, "Page"),
+        hint(&jump, "Jump"),
+        hint(&kb.global.open_browser, "Open"),
+        pane_hint(kb),
+        hint(&kb.global.quit, "Quit"),
     ];
-    parts.join(" \u{2502} ")
+    parts.into_iter().flatten().collect::<Vec<_>>().join(" \u{2502} ")
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::config::KeyBinding;
+    use crate::config::{KeyBinding, KeyBindings};
+
+    #[test]
+    fn format_since_buckets() {
+        use chrono::{Duration, Utc};
+        let now = Utc::now();
+        assert_eq!(super::format_since(now - Duration::seconds(20), now), "just now");
+        assert_eq!(super::format_since(now - Duration::minutes(4), now), "4m ago");
+        assert_eq!(super::format_since(now - Duration::hours(3), now), "3h ago");
+        assert_eq!(super::format_since(now - Duration::days(2), now), "2d ago");
+    }
 
     #[test]
     fn format_keybinding_single() {
@@ -105,4 +167,14 @@ mod tests {
         };
         assert_eq!(kb.display(), "Ctrl+d");
     }
+
+    #[test]
+    fn unbound_actions_have_no_hint() {
+        let mut kb = KeyBindings::default();
+        kb.global.copy_enclosure.clear();
+        kb.global.focus_prev.clear();
+        let hints = super::build_articles_hints(&kb);
+        assert!(!hints.contains("Copy audio"));
+        assert!(hints.contains("│ [Tab] Pane │"));
+    }
 }
@@ -1,3 +1,4 @@
+use ratatui::buffer::Buffer;
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::widgets::BorderType;
 
@@ -24,14 +25,22 @@ pub fn get_border_style(is_focused: bool, colours: &ColourConfig) -> Style {
     Style::new().fg(color)
 }
 
-/// Get highlight style for the currently selected row based on the colour config.
-pub fn get_highlight_style(colours: &ColourConfig) -> Style {
-    let color = crate::config::parse_color(&colours.highlight_bg)
-        .unwrap_or(Color::DarkGray);
+/// Get highlight style for the selected row of a list pane based on whether
+/// the pane is focused and the colour config.
+pub fn get_highlight_style(is_focused: bool, colours: &ColourConfig) -> Style {
+    if is_focused {
+        let color = crate::config::parse_color(&colours.highlight_bg)
+            .unwrap_or(Color::DarkGray);
+
+        Style::new()
+            .bg(color)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        let color = crate::config::parse_color(&colours.inactive_highlight_bg)
+            .unwrap_or(Color::Indexed(236));
 
-    Style::new()
-        .bg(color)
-        .add_modifier(Modifier::BOLD)
+        Style::new().bg(color)
+    }
 }
 
 /// Get the unread indicator style based on the colour config.
@@ -42,6 +51,34 @@ pub fn get_unread_indicator_style(colours: &ColourConfig) -> Style {
     Style::new().fg(color)
 }
 
+/// Get the style for read articles and fully read feeds based on the colour
+/// config.
+pub fn get_read_style(colours: &ColourConfig) -> Style {
+    let color = crate::config::parse_color(&colours.read_fg)
+        .unwrap_or(Color::DarkGray);
+
+    Style::new().fg(color)
+}
+
+/// Get the style for `<pre>` code block lines in the article view.
+pub fn get_code_block_style(colours: &ColourConfig) -> Style {
+    let color = crate::config::parse_color(&colours.code_block_bg)
+        .unwrap_or(Color::Indexed(236));
+
+    Style::new().bg(color)
+}
+
+/// Marker drawn before the selected list row when colours are disabled.
+pub const NO_COLOR_MARKER: &str = "> ";
+
+/// Reset every cell of `buf` to the terminal's default colours, keeping
+/// modifiers such as bold, for `--no-color` / `NO_COLOR`.
+pub fn strip_colours(buf: &mut Buffer) {
+    for cell in &mut buf.content {
+        cell.set_fg(Color::Reset).set_bg(Color::Reset);
+    }
+}
+
 /// Get border type based on the colour config.
 pub fn get_border_type(colours: &ColourConfig) -> BorderType {
     crate::config::parse_border_type(&colours.border_type)
@@ -61,12 +98,12 @@ pub const UNREAD_STYLE: Style = Style::new()
     .fg(Color::White)
     .add_modifier(Modifier::BOLD);
 
-/// Style for feeds/articles that have been fully read.
-pub const READ_STYLE: Style = Style::new().fg(Color::DarkGray);
-
 /// Style for the star indicator on starred articles.
 pub const STAR_STYLE: Style = Style::new().fg(Color::Yellow);
 
+/// Style for the queue indicator on articles queued to read later.
+pub const QUEUE_STYLE: Style = Style::new().fg(Color::Magenta);
+
 /// Style for unread-count badges.
 pub const COUNT_STYLE: Style = Style::new().fg(Color::Cyan);
 
@@ -80,3 +117,22 @@ pub const META_STYLE: Style = Style::new().fg(Color::DarkGray);
 
 /// Background style for the bottom status bar.
 pub const STATUS_STYLE: Style = Style::new().fg(Color::White).bg(Color::DarkGray);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+
+    #[test]
+    fn strip_colours_keeps_modifiers() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buf.set_string(0, 0, "ab", STATUS_STYLE.add_modifier(Modifier::BOLD));
+
+        strip_colours(&mut buf);
+
+        let cell = &buf[(0, 0)];
+        assert_eq!((cell.fg, cell.bg), (Color::Reset, Color::Reset));
+        assert!(cell.modifier.contains(Modifier::BOLD));
+        assert_eq!(cell.symbol(), "a");
+    }
+}
@@ -0,0 +1,123 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+/// UI state remembered between runs (as opposed to user configuration).
+///
+/// Stored as JSON at `$XDG_DATA_HOME/lazyrss/ui_state.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct UiState {
+    /// Full paths of collapsed groups (e.g. "News (World) > Domestic").
+    #[serde(default)]
+    pub collapsed_groups: Vec<String>,
+
+    /// Feeds-pane selection at exit; `None` means "All".
+    #[serde(default)]
+    pub selected_source: Option<SelectedSource>,
+
+    /// GUID of the selected article at exit.
+    #[serde(default)]
+    pub selected_article_guid: Option<String>,
+}
+
+/// A feeds-pane selection identified by stable keys rather than index.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelectedSource {
+    /// A feed, by its feed URL.
+    Feed(String),
+    /// A group, by its full path.
+    Group(String),
+}
+
+impl UiState {
+    /// Drop collapsed group paths that no longer exist in `group_paths`.
+    pub fn prune_collapsed_groups(&mut self, group_paths: &[String]) {
+        self.collapsed_groups.retain(|path| group_paths.contains(path));
+    }
+}
+
+/// Return the path to the UI state file.
+fn state_path() -> anyhow::Result<PathBuf> {
+    let dir = dirs::data_dir().context("Could not determine data directory")?;
+    Ok(dir.join("lazyrss").join("ui_state.json"))
+}
+
+/// Load the UI state from disk.
+///
+/// If the state file does not exist, returns `UiState::default()`.
+pub fn load() -> anyhow::Result<UiState> {
+    let path = state_path()?;
+
+    if !path.exists() {
+        return Ok(UiState::default());
+    }
+
+    let contents = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read UI state file: {}", path.display()))?;
+
+    serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse UI state file: {}", path.display()))
+}
+
+/// Save the UI state to disk, creating the data directory if needed.
+pub fn save(state: &UiState) -> anyhow::Result<()> {
+    let path = state_path()?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create data directory: {}", parent.display()))?;
+    }
+
+    let json = serde_json::to_string_pretty(state).context("Failed to serialize UI state")?;
+
+    // Write to temp file first, then rename, so a crash mid-write can't
+    // leave a truncated state file behind
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, json)
+        .with_context(|| format!("Failed to write UI state file: {}", temp_path.display()))?;
+
+    fs::rename(&temp_path, &path)
+        .with_context(|| format!("Failed to rename UI state file: {} -> {}", temp_path.display(), path.display()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_fields_default() {
+        let state: UiState = serde_json::from_str("{}").unwrap();
+        assert_eq!(state, UiState::default());
+    }
+
+    #[test]
+    fn prune_collapsed_groups_drops_stale_paths() {
+        let mut state = UiState {
+            collapsed_groups: vec![
+                "Tech".to_string(),
+                "News > Domestic".to_string(),
+                "Gone".to_string(),
+            ],
+            ..UiState::default()
+        };
+        state.prune_collapsed_groups(&["Tech".to_string(), "News".to_string(), "News > Domestic".to_string()]);
+        assert_eq!(state.collapsed_groups, vec!["Tech".to_string(), "News > Domestic".to_string()]);
+    }
+
+    #[test]
+    fn selection_round_trips() {
+        let state = UiState {
+            collapsed_groups: Vec::new(),
+            selected_source: Some(SelectedSource::Feed("https://example.com/feed.xml".to_string())),
+            selected_article_guid: Some("post-1".to_string()),
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert!(json.contains(r#""selected_source":{"feed":"https://example.com/feed.xml"}"#));
+        assert_eq!(serde_json::from_str::<UiState>(&json).unwrap(), state);
+    }
+}